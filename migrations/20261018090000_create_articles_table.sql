@@ -0,0 +1,17 @@
+-- Create Articles table
+CREATE TABLE articles(
+    id SERIAL PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    body TEXT NOT NULL,
+    tag_list TEXT[] NOT NULL DEFAULT '{}',
+    author TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_author
+        FOREIGN KEY(author)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
@@ -0,0 +1,15 @@
+-- Create Favorites table
+CREATE TABLE favorites(
+    username TEXT NOT NULL,
+    article_id INTEGER NOT NULL,
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    CONSTRAINT fk_article
+        FOREIGN KEY(article_id)
+            REFERENCES articles(id)
+            ON DELETE CASCADE,
+    UNIQUE (username, article_id)
+);
@@ -0,0 +1,2 @@
+-- Speed up the lookup of the most recent articles of given authors (feed)
+CREATE INDEX articles_author_created_at_idx ON articles (author, created_at DESC);
@@ -0,0 +1,20 @@
+-- Create Comments table
+CREATE TABLE comments(
+    id SERIAL PRIMARY KEY,
+    body TEXT NOT NULL,
+    article_id INTEGER NOT NULL,
+    author TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_article
+        FOREIGN KEY(article_id)
+            REFERENCES articles(id)
+            ON DELETE CASCADE,
+    CONSTRAINT fk_author
+        FOREIGN KEY(author)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+CREATE INDEX comments_article_id_idx ON comments (article_id);
@@ -0,0 +1,34 @@
+-- Create Tags table
+CREATE TABLE tags(
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- Create Article Tags (join) table
+CREATE TABLE article_tags(
+    article_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    CONSTRAINT fk_article
+        FOREIGN KEY(article_id)
+            REFERENCES articles(id)
+            ON DELETE CASCADE,
+    CONSTRAINT fk_tag
+        FOREIGN KEY(tag_id)
+            REFERENCES tags(id)
+            ON DELETE CASCADE,
+    PRIMARY KEY (article_id, tag_id)
+);
+
+CREATE INDEX article_tags_tag_id_idx ON article_tags (tag_id);
+
+-- Move the existing tags out of the articles table (normalized to lowercase)
+INSERT INTO tags (name)
+SELECT DISTINCT lower(tag)
+FROM articles, unnest(articles.tag_list) AS tag;
+
+INSERT INTO article_tags (article_id, tag_id)
+SELECT DISTINCT articles.id, tags.id
+FROM articles, unnest(articles.tag_list) AS tag
+JOIN tags ON tags.name = lower(tag);
+
+ALTER TABLE articles DROP COLUMN tag_list;
@@ -0,0 +1,21 @@
+-- Create Refresh Tokens table
+-- Only a hash of each refresh token is stored. The tokens obtained by
+-- rotating each other share the same family, which is revoked as a whole if
+-- an already used token is presented again.
+CREATE TABLE refresh_tokens(
+    id SERIAL PRIMARY KEY,
+    token_hash TEXT NOT NULL UNIQUE,
+    family TEXT NOT NULL,
+    username TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ NULL,
+    revoked_at TIMESTAMPTZ NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+CREATE INDEX refresh_tokens_family_idx ON refresh_tokens (family);
@@ -0,0 +1,12 @@
+-- Create Revoked Tokens table
+-- It holds the IDs (`jti` claim) of the access tokens revoked before their
+-- expiration, which is kept so that the expired ones can be cleaned up.
+CREATE TABLE revoked_tokens(
+    jti TEXT PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX revoked_tokens_expires_at_idx ON revoked_tokens (expires_at);
+
+-- Every access token of an user issued before this date is revoked
+ALTER TABLE users ADD COLUMN tokens_valid_after TIMESTAMPTZ NULL;
@@ -0,0 +1,21 @@
+-- Create Personal Access Tokens table
+-- Long-lived tokens created by the users for their scripts, only a hash of
+-- each token is stored. Their scopes restrict what they allow (e.g. 'read'
+-- for a read-only token).
+CREATE TABLE personal_access_tokens(
+    id SERIAL PRIMARY KEY,
+    username TEXT NOT NULL,
+    name TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    scopes TEXT[] NOT NULL,
+    expires_at TIMESTAMPTZ NULL,
+    last_used_at TIMESTAMPTZ NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT personal_access_tokens_username_name_key
+        UNIQUE(username, name),
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
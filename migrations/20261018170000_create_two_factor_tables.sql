@@ -0,0 +1,45 @@
+-- Create Two-Factor Authentication tables
+-- The TOTP secret of a user is pending until it is confirmed with a first
+-- valid code (enabled_at). The time step of the last code used is kept so
+-- that a code cannot be used twice.
+CREATE TABLE user_totp(
+    username TEXT PRIMARY KEY,
+    secret TEXT NOT NULL,
+    enabled_at TIMESTAMPTZ NULL,
+    last_used_step BIGINT NULL,
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+-- One-time recovery codes, only a hash of each code is stored.
+CREATE TABLE totp_recovery_codes(
+    id SERIAL PRIMARY KEY,
+    username TEXT NOT NULL,
+    code_hash TEXT NOT NULL,
+    used_at TIMESTAMPTZ NULL,
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+CREATE INDEX totp_recovery_codes_username_idx ON totp_recovery_codes (username);
+
+-- Challenges issued upon login (password verified) to users with two-factor
+-- authentication, exchanged for a session with a valid code. Only a hash of
+-- each challenge token is stored.
+CREATE TABLE login_challenges(
+    token_hash TEXT PRIMARY KEY,
+    username TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    failed_attempts INT NOT NULL DEFAULT 0,
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
@@ -0,0 +1,10 @@
+-- Create Login Attempts table
+-- It counts the consecutive failed login attempts per account
+-- ("account:<email>") and per client IP address ("ip:<address>"), and holds
+-- the date until which further attempts are refused.
+CREATE TABLE login_attempts(
+    key TEXT PRIMARY KEY,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    last_failure_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    locked_until TIMESTAMPTZ NULL
+);
@@ -0,0 +1,14 @@
+-- Create Password Reset Tokens table
+-- Only a hash of each token is stored, and an user has at most one token
+-- (the previous one is replaced when a new reset is requested).
+CREATE TABLE password_reset_tokens(
+    token_hash TEXT PRIMARY KEY,
+    username TEXT NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
@@ -0,0 +1,20 @@
+-- The users registered before the email verification are trusted
+ALTER TABLE users ADD COLUMN email_verified BOOLEAN NOT NULL DEFAULT false;
+UPDATE users SET email_verified = true;
+
+-- Create Email Verifications table
+-- It holds the (hashed) token emailed to verify an address: the one of a new
+-- user, or the new one of an user changing its email (which replaces the
+-- current one once verified). An user has at most one pending verification.
+CREATE TABLE email_verifications(
+    token_hash TEXT PRIMARY KEY,
+    username TEXT NOT NULL UNIQUE,
+    email TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_username
+        FOREIGN KEY(username)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
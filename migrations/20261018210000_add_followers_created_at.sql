@@ -0,0 +1,6 @@
+-- Add the date of each follow relationship to the Followers table
+-- The followers and following lists are ordered by it (most recent first).
+ALTER TABLE followers ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+CREATE INDEX followers_followed_created_at_idx ON followers (followed, created_at DESC);
+CREATE INDEX followers_follower_created_at_idx ON followers (follower, created_at DESC);
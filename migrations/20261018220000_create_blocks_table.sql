@@ -0,0 +1,21 @@
+-- Create Blocks table
+-- A blocked user cannot follow its blocker, nor see its profile.
+CREATE TABLE blocks(
+    blocker TEXT NOT NULL,
+    blocked TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_blocker
+        FOREIGN KEY(blocker)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    CONSTRAINT fk_blocked
+        FOREIGN KEY(blocked)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    CHECK (blocker != blocked),
+    PRIMARY KEY (blocker, blocked)
+);
+
+CREATE INDEX blocks_blocker_created_at_idx ON blocks (blocker, created_at DESC);
@@ -0,0 +1,24 @@
+-- Add private accounts
+-- Following a private account requires its approval: meanwhile, the follow
+-- request is pending.
+ALTER TABLE users ADD COLUMN private BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE follow_requests(
+    requester TEXT NOT NULL,
+    requested TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_requester
+        FOREIGN KEY(requester)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    CONSTRAINT fk_requested
+        FOREIGN KEY(requested)
+            REFERENCES users(username)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    CHECK (requester != requested),
+    PRIMARY KEY (requester, requested)
+);
+
+CREATE INDEX follow_requests_requested_created_at_idx ON follow_requests (requested, created_at DESC);
@@ -0,0 +1,189 @@
+-- Reference the users by their immutable ID rather than by their username,
+-- which is only a (mutable) handle.
+-- Each table gets the ID column(s) matching its former username column(s),
+-- which are dropped along with the constraints and indexes built on them.
+
+-- Followers
+ALTER TABLE followers ADD COLUMN follower_id INTEGER, ADD COLUMN followed_id INTEGER;
+UPDATE followers f SET follower_id = u.id FROM users u WHERE u.username = f.follower;
+UPDATE followers f SET followed_id = u.id FROM users u WHERE u.username = f.followed;
+ALTER TABLE followers
+    DROP COLUMN follower,
+    DROP COLUMN followed,
+    ALTER COLUMN follower_id SET NOT NULL,
+    ALTER COLUMN followed_id SET NOT NULL,
+    ADD CONSTRAINT fk_follower
+        FOREIGN KEY(follower_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD CONSTRAINT fk_followed
+        FOREIGN KEY(followed_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD CHECK (follower_id != followed_id),
+    ADD UNIQUE (follower_id, followed_id);
+
+CREATE INDEX followers_followed_id_created_at_idx ON followers (followed_id, created_at DESC);
+CREATE INDEX followers_follower_id_created_at_idx ON followers (follower_id, created_at DESC);
+
+-- Articles
+ALTER TABLE articles ADD COLUMN author_id INTEGER;
+UPDATE articles a SET author_id = u.id FROM users u WHERE u.username = a.author;
+ALTER TABLE articles
+    DROP COLUMN author,
+    ALTER COLUMN author_id SET NOT NULL,
+    ADD CONSTRAINT fk_author
+        FOREIGN KEY(author_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+CREATE INDEX articles_author_id_created_at_idx ON articles (author_id, created_at DESC);
+
+-- Favorites
+ALTER TABLE favorites ADD COLUMN user_id INTEGER;
+UPDATE favorites fav SET user_id = u.id FROM users u WHERE u.username = fav.username;
+ALTER TABLE favorites
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD UNIQUE (user_id, article_id);
+
+-- Comments
+ALTER TABLE comments ADD COLUMN author_id INTEGER;
+UPDATE comments c SET author_id = u.id FROM users u WHERE u.username = c.author;
+ALTER TABLE comments
+    DROP COLUMN author,
+    ALTER COLUMN author_id SET NOT NULL,
+    ADD CONSTRAINT fk_author
+        FOREIGN KEY(author_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Refresh Tokens
+ALTER TABLE refresh_tokens ADD COLUMN user_id INTEGER;
+UPDATE refresh_tokens t SET user_id = u.id FROM users u WHERE u.username = t.username;
+ALTER TABLE refresh_tokens
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Personal Access Tokens
+ALTER TABLE personal_access_tokens ADD COLUMN user_id INTEGER;
+UPDATE personal_access_tokens t SET user_id = u.id FROM users u WHERE u.username = t.username;
+ALTER TABLE personal_access_tokens
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD CONSTRAINT personal_access_tokens_user_id_name_key
+        UNIQUE(user_id, name),
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Two-Factor Authentication
+ALTER TABLE user_totp ADD COLUMN user_id INTEGER;
+UPDATE user_totp t SET user_id = u.id FROM users u WHERE u.username = t.username;
+ALTER TABLE user_totp
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD PRIMARY KEY (user_id),
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+ALTER TABLE totp_recovery_codes ADD COLUMN user_id INTEGER;
+UPDATE totp_recovery_codes c SET user_id = u.id FROM users u WHERE u.username = c.username;
+ALTER TABLE totp_recovery_codes
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+CREATE INDEX totp_recovery_codes_user_id_idx ON totp_recovery_codes (user_id);
+
+ALTER TABLE login_challenges ADD COLUMN user_id INTEGER;
+UPDATE login_challenges c SET user_id = u.id FROM users u WHERE u.username = c.username;
+ALTER TABLE login_challenges
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Password Reset Tokens
+ALTER TABLE password_reset_tokens ADD COLUMN user_id INTEGER;
+UPDATE password_reset_tokens t SET user_id = u.id FROM users u WHERE u.username = t.username;
+ALTER TABLE password_reset_tokens
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD UNIQUE (user_id),
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Email Verifications
+ALTER TABLE email_verifications ADD COLUMN user_id INTEGER;
+UPDATE email_verifications v SET user_id = u.id FROM users u WHERE u.username = v.username;
+ALTER TABLE email_verifications
+    DROP COLUMN username,
+    ALTER COLUMN user_id SET NOT NULL,
+    ADD UNIQUE (user_id),
+    ADD CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Blocks
+ALTER TABLE blocks ADD COLUMN blocker_id INTEGER, ADD COLUMN blocked_id INTEGER;
+UPDATE blocks b SET blocker_id = u.id FROM users u WHERE u.username = b.blocker;
+UPDATE blocks b SET blocked_id = u.id FROM users u WHERE u.username = b.blocked;
+ALTER TABLE blocks
+    DROP COLUMN blocker,
+    DROP COLUMN blocked,
+    ALTER COLUMN blocker_id SET NOT NULL,
+    ALTER COLUMN blocked_id SET NOT NULL,
+    ADD CONSTRAINT fk_blocker
+        FOREIGN KEY(blocker_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD CONSTRAINT fk_blocked
+        FOREIGN KEY(blocked_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD CHECK (blocker_id != blocked_id),
+    ADD PRIMARY KEY (blocker_id, blocked_id);
+
+CREATE INDEX blocks_blocker_id_created_at_idx ON blocks (blocker_id, created_at DESC);
+
+-- Follow Requests
+ALTER TABLE follow_requests ADD COLUMN requester_id INTEGER, ADD COLUMN requested_id INTEGER;
+UPDATE follow_requests r SET requester_id = u.id FROM users u WHERE u.username = r.requester;
+UPDATE follow_requests r SET requested_id = u.id FROM users u WHERE u.username = r.requested;
+ALTER TABLE follow_requests
+    DROP COLUMN requester,
+    DROP COLUMN requested,
+    ALTER COLUMN requester_id SET NOT NULL,
+    ALTER COLUMN requested_id SET NOT NULL,
+    ADD CONSTRAINT fk_requester
+        FOREIGN KEY(requester_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD CONSTRAINT fk_requested
+        FOREIGN KEY(requested_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE,
+    ADD CHECK (requester_id != requested_id),
+    ADD PRIMARY KEY (requester_id, requested_id);
+
+CREATE INDEX follow_requests_requested_id_created_at_idx ON follow_requests (requested_id, created_at DESC);
@@ -0,0 +1,5 @@
+-- Revoke every token of a user by incrementing its version instead of
+-- comparing issue dates, which only have a precision of one second
+ALTER TABLE users
+ADD COLUMN token_version INTEGER NOT NULL DEFAULT 0,
+DROP COLUMN tokens_valid_after;
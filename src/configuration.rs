@@ -10,10 +10,13 @@ use sqlx::{
     ConnectOptions,
 };
 
+use crate::domain::users::email_verification::UnverifiedEmailPolicy;
+
 #[derive(Clone, Deserialize)]
 pub struct Settings {
     pub app: AppSettings,
     pub database: DatabaseSettings,
+    pub mailer: MailerSettings,
 }
 
 #[derive(Clone, Deserialize)]
@@ -21,7 +24,74 @@ pub struct AppSettings {
     pub host: String,
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub port: u16,
-    pub jwt_secret: String,
+    /// The shared secret used to sign the JWTs (HS256), if no key is
+    /// configured in `jwt_keys`.
+    pub jwt_secret: Option<String>,
+    /// The asymmetric keys used to sign and verify the JWTs.
+    #[serde(default)]
+    pub jwt_keys: Vec<JwtKeySettings>,
+    /// The ID of the key of `jwt_keys` used to sign the JWTs (by default, the
+    /// first one with a private key). The other keys are only used for
+    /// verification.
+    pub jwt_signing_kid: Option<String>,
+    /// The issuer of the JWTs (`iss` claim), required when verifying them.
+    pub jwt_issuer: String,
+    /// The audience of the JWTs (`aud` claim), required when verifying them.
+    pub jwt_audience: String,
+    /// The clock skew tolerated when validating the expiration of the JWTs,
+    /// in seconds.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub jwt_leeway: u64,
+    /// The lifetime of the access tokens (JWT), in seconds.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub access_token_ttl: i64,
+    /// The lifetime of the refresh tokens, in seconds.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub refresh_token_ttl: i64,
+    /// The lifetime of the password reset tokens, in seconds.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub password_reset_token_ttl: i64,
+    /// The lifetime of the email verification tokens, in seconds.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub email_verification_token_ttl: i64,
+    /// What the users whose email is not verified may do.
+    pub unverified_email_policy: UnverifiedEmailPolicy,
+    /// The protection of the login against brute-force attacks.
+    pub login_lockout: LoginLockoutSettings,
+}
+
+/// The number of failed login attempts after which an account (or a client
+/// IP address) is temporarily locked, and the duration of the lock, doubled
+/// for each further failed attempt.
+#[derive(Clone, Deserialize)]
+pub struct LoginLockoutSettings {
+    /// Failed attempts on an account before it is locked.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub account_threshold: i32,
+    /// Failed attempts from an IP address (on any account) before it is
+    /// locked.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ip_threshold: i32,
+    /// The duration of the first lock, in seconds.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub base_lock_duration: i64,
+    /// The maximum duration of a lock, in seconds. The failed attempts older
+    /// than this are forgotten.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_lock_duration: i64,
+}
+
+/// An asymmetric key (RSA or EdDSA) used for the JWTs, loaded from PEM files.
+#[derive(Clone, Deserialize)]
+pub struct JwtKeySettings {
+    /// The ID of the key, written in the header of the JWTs (`kid`).
+    pub kid: String,
+    /// The algorithm of the key (e.g. `RS256` or `EdDSA`).
+    pub algorithm: jsonwebtoken::Algorithm,
+    /// The path to the public key (SubjectPublicKeyInfo).
+    pub public_key_path: String,
+    /// The path to the private key (PKCS#8), only needed for signing.
+    pub private_key_path: Option<String>,
 }
 
 #[derive(Clone, Deserialize)]
@@ -62,6 +132,31 @@ impl DatabaseSettings {
     }
 }
 
+/// The settings of the emails sent to the users.
+#[derive(Clone, Deserialize)]
+pub struct MailerSettings {
+    /// The sender of the emails (`From` header), e.g.
+    /// `Conduit <noreply@conduit.io>`.
+    pub sender: String,
+    /// The SMTP server relaying the emails. If not set, the emails are
+    /// written to `directory` instead.
+    pub smtp: Option<SmtpSettings>,
+    /// The directory where the emails are written, one file per email, when
+    /// no SMTP server is configured (for development and tests).
+    pub directory: String,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct SmtpSettings {
+    pub host: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Whether to upgrade the connection with STARTTLS (required if set).
+    pub starttls: bool,
+}
+
 /// Retrieves the configurations in the `configuration` directory.
 /// The configuration to choose (either `local` or `production`) is read from
 /// the `APP_ENVIRONMENT` environment variable.
@@ -0,0 +1,67 @@
+use super::{
+    new_article::{parse_body, parse_description, parse_tag_list},
+    slug::Slug,
+    tag::Tag,
+    title::ArticleTitle,
+};
+use crate::{domain::error::ValidationErrors, dtos::articles::ArticleUpdateDto};
+
+/// This struct represents a valid user input for an article update.
+/// The slug is derived from the new title, if any.
+pub struct ArticleUpdateRequest {
+    pub slug: Option<Slug>,
+    pub title: Option<ArticleTitle>,
+    pub description: Option<String>,
+    pub body: Option<String>,
+    pub tag_list: Option<Vec<Tag>>,
+}
+
+impl ArticleUpdateRequest {
+    /// Returns true if all the fields of the struct are [`None`] variant.
+    pub fn is_all_none(&self) -> bool {
+        self.title.is_none()
+            && self.description.is_none()
+            && self.body.is_none()
+            && self.tag_list.is_none()
+    }
+}
+
+impl TryFrom<ArticleUpdateDto> for ArticleUpdateRequest {
+    type Error = ValidationErrors;
+
+    /// Transforms an [`ArticleUpdateDto`] payload to a domain-compliant
+    /// [`ArticleUpdateRequest`] (valid title and its slug, non-empty
+    /// description and body, valid tags). Every invalid field is reported.
+    fn try_from(value: ArticleUpdateDto) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::default();
+
+        let title = value
+            .article
+            .title
+            .and_then(|title| errors.check("title", ArticleTitle::parse(title)));
+        let description = value
+            .article
+            .description
+            .and_then(|description| errors.check("description", parse_description(description)));
+        let body = value
+            .article
+            .body
+            .and_then(|body| errors.check("body", parse_body(body)));
+        let tag_list = value
+            .article
+            .tag_list
+            .and_then(|tag_list| errors.check("tagList", parse_tag_list(tag_list)));
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(ArticleUpdateRequest {
+            slug: title.as_ref().map(Slug::from_title),
+            title,
+            description,
+            body,
+            tag_list,
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use crate::dtos::articles::ArticlesQueryDto;
+
+/// This struct represents the filters that can be applied when listing
+/// articles. Every filter is optional.
+pub struct ArticlesFilter {
+    /// Only articles having this tag (normalized to lowercase).
+    pub tag: Option<String>,
+    /// Only articles written by this user.
+    pub author: Option<String>,
+    /// Only articles favorited by this user.
+    pub favorited: Option<String>,
+}
+
+impl From<ArticlesQueryDto> for ArticlesFilter {
+    fn from(value: ArticlesQueryDto) -> Self {
+        Self {
+            tag: value.tag.map(|tag| tag.trim().to_lowercase()),
+            author: value.author,
+            favorited: value.favorited,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod article_update_request;
+pub mod articles_filter;
+pub mod new_article;
+pub mod slug;
+pub mod tag;
+pub mod title;
+
+pub use article_update_request::ArticleUpdateRequest;
+pub use articles_filter::ArticlesFilter;
+pub use new_article::NewArticle;
+pub use tag::Tag;
@@ -0,0 +1,142 @@
+use super::{slug::Slug, tag::Tag, title::ArticleTitle};
+use crate::{domain::error::ValidationErrors, dtos::articles::ArticleCreationDto};
+
+/// This struct represents a valid user input for an article creation.
+#[derive(Debug)]
+pub struct NewArticle {
+    pub slug: Slug,
+    pub title: ArticleTitle,
+    pub description: String,
+    pub body: String,
+    pub tag_list: Vec<Tag>,
+}
+
+impl TryFrom<ArticleCreationDto> for NewArticle {
+    type Error = ValidationErrors;
+
+    /// Transforms an [`ArticleCreationDto`] payload to a domain-compliant
+    /// [`NewArticle`] (valid title and its slug, non-empty description and
+    /// body, valid tags). Every invalid field is reported.
+    fn try_from(value: ArticleCreationDto) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::default();
+
+        let title = errors.check("title", ArticleTitle::parse(value.article.title));
+        let description = errors.check("description", parse_description(value.article.description));
+        let body = errors.check("body", parse_body(value.article.body));
+        let tag_list = errors.check("tagList", parse_tag_list(value.article.tag_list));
+
+        let (Some(title), Some(description), Some(body), Some(tag_list)) =
+            (title, description, body, tag_list)
+        else {
+            return Err(errors);
+        };
+
+        Ok(NewArticle {
+            slug: Slug::from_title(&title),
+            title,
+            description,
+            body,
+            tag_list,
+        })
+    }
+}
+
+/// Validates an article description: it must not be empty and must be at
+/// most 255 characters long.
+pub(super) fn parse_description(description: String) -> Result<String, String> {
+    if description.trim().is_empty() {
+        Err("A description cannot be empty.".into())
+    } else if description.chars().count() > 255 {
+        Err("The description is too long! (255 chars max.)".into())
+    } else {
+        Ok(description)
+    }
+}
+
+/// Validates an article body: it must not be empty.
+pub(super) fn parse_body(body: String) -> Result<String, String> {
+    if body.trim().is_empty() {
+        Err("A body cannot be empty.".into())
+    } else {
+        Ok(body)
+    }
+}
+
+/// Validates a list of tags: each tag must be a valid [`Tag`]. Duplicated
+/// tags (after normalization) are removed, the original order is kept.
+pub(super) fn parse_tag_list(tag_list: Vec<String>) -> Result<Vec<Tag>, String> {
+    let mut tags: Vec<Tag> = Vec::with_capacity(tag_list.len());
+    for tag in tag_list {
+        let tag = Tag::parse(tag)?;
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    Ok(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+
+    use super::NewArticle;
+    use crate::dtos::articles::{article_creation_dto::ArticleCreationFields, ArticleCreationDto};
+
+    fn dto(title: &str, description: &str, body: &str, tags: &[&str]) -> ArticleCreationDto {
+        ArticleCreationDto {
+            article: ArticleCreationFields {
+                title: title.into(),
+                description: description.into(),
+                body: body.into(),
+                tag_list: tags.iter().map(|t| t.to_string()).collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn a_valid_article_is_accepted() {
+        let article = NewArticle::try_from(dto("My title", "desc", "body", &["rust"]));
+        assert_ok!(&article);
+        assert_eq!("my-title", article.unwrap().slug.as_ref());
+    }
+
+    #[test]
+    fn an_empty_description_is_not_valid() {
+        assert_err!(NewArticle::try_from(dto("My title", " ", "body", &[])));
+    }
+
+    #[test]
+    fn an_empty_body_is_not_valid() {
+        assert_err!(NewArticle::try_from(dto("My title", "desc", "", &[])));
+    }
+
+    #[test]
+    fn an_empty_tag_is_not_valid() {
+        assert_err!(NewArticle::try_from(dto(
+            "My title",
+            "desc",
+            "body",
+            &["rust", ""]
+        )));
+    }
+
+    #[test]
+    fn every_invalid_field_is_reported() {
+        let errors = NewArticle::try_from(dto("", "", "", &[""])).unwrap_err();
+        for field in ["title", "description", "body", "tagList"] {
+            assert!(errors.get(field).is_some(), "{field} should be reported");
+        }
+    }
+
+    #[test]
+    fn duplicated_tags_are_removed() {
+        let article = NewArticle::try_from(dto("t", "d", "b", &["rust", "web", "Rust"])).unwrap();
+        let tags: Vec<&str> = article
+            .tag_list
+            .iter()
+            .map(|t| t.as_ref().as_str())
+            .collect();
+        assert_eq!(vec!["rust", "web"], tags);
+    }
+}
@@ -0,0 +1,58 @@
+use super::title::ArticleTitle;
+
+/// Holds an article slug, i.e. the URL-friendly identifier of an article
+/// derived from its title (e.g. `how-to-train-your-dragon`).
+#[derive(Debug)]
+pub struct Slug(String);
+
+impl Slug {
+    /// Derives a slug from a valid article title: alphanumeric characters are
+    /// lowercased and every other run of characters is replaced by a single
+    /// dash.
+    pub fn from_title(title: &ArticleTitle) -> Slug {
+        let slug = title
+            .as_ref()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-");
+
+        Self(slug)
+    }
+}
+
+impl AsRef<String> for Slug {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slug;
+    use crate::domain::articles::title::ArticleTitle;
+
+    fn slug_of(title: &str) -> String {
+        let title = ArticleTitle::parse(title.into()).unwrap();
+        Slug::from_title(&title).as_ref().to_owned()
+    }
+
+    #[test]
+    fn a_simple_title_is_lowercased_and_dashed() {
+        assert_eq!(
+            "how-to-train-your-dragon",
+            slug_of("How to train your dragon")
+        );
+    }
+
+    #[test]
+    fn punctuation_and_consecutive_spaces_are_collapsed() {
+        assert_eq!("hello-world-2", slug_of("  Hello,   World!! (2) "));
+    }
+
+    #[test]
+    fn non_ascii_letters_are_kept() {
+        assert_eq!("café-crème", slug_of("Café Crème"));
+    }
+}
@@ -0,0 +1,87 @@
+/// Holds a valid tag, normalized to lowercase.
+/// A valid tag meets these criteria:
+/// - Must not be empty
+/// - Must be at most 30 characters long
+/// - Must only contain alphanumeric characters, dashes, underscores, dots,
+///   plus and sharp signs (e.g. `rust`, `c++`, `c#`, `node.js`)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Tag(String);
+
+impl Tag {
+    /// Tries to parse a string into a valid tag. Leading and trailing
+    /// whitespaces are ignored and the tag is lowercased. Returns [`Err`]
+    /// if the [`Tag`] criteria are not met.
+    pub fn parse(s: String) -> Result<Tag, String> {
+        let tag = s.trim().to_lowercase();
+        let is_too_long = tag.chars().count() > 30;
+        let has_allowed_chars = tag
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '+' | '#'));
+
+        if tag.is_empty() {
+            Err("A tag cannot be empty.".to_string())
+        } else if is_too_long || !has_allowed_chars {
+            Err(format!("{s} is not a valid tag."))
+        } else {
+            Ok(Self(tag))
+        }
+    }
+}
+
+impl AsRef<String> for Tag {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+
+    use super::Tag;
+
+    #[test]
+    fn an_empty_tag_is_not_valid() {
+        assert_err!(Tag::parse("".into()));
+    }
+
+    #[test]
+    fn a_whitespace_only_tag_is_not_valid() {
+        assert_err!(Tag::parse("    ".into()));
+    }
+
+    #[test]
+    fn a_30_characters_long_tag_is_valid() {
+        let tag = "a".repeat(30);
+        assert_ok!(Tag::parse(tag));
+    }
+
+    #[test]
+    fn too_long_tag_is_not_valid() {
+        let tag = "a".repeat(31);
+        assert_err!(Tag::parse(tag));
+    }
+
+    #[test]
+    fn a_tag_with_spaces_is_not_valid() {
+        assert_err!(Tag::parse("not a tag".into()));
+    }
+
+    #[test]
+    fn a_tag_with_forbidden_chars_is_not_valid() {
+        assert_err!(Tag::parse("tag!@/".into()));
+    }
+
+    #[test]
+    fn a_tag_is_trimmed_and_lowercased() {
+        let tag = Tag::parse("  RustLang ".into()).unwrap();
+        assert_eq!("rustlang", tag.as_ref());
+    }
+
+    #[test]
+    fn valid_tags_are_accepted() {
+        for tag in ["rust", "c++", "c#", "node.js", "web-dev", "web_3", "café"] {
+            assert_ok!(Tag::parse(tag.into()));
+        }
+    }
+}
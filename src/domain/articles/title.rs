@@ -0,0 +1,74 @@
+/// Holds a valid article title.
+/// A valid article title meets these criteria:
+/// - Must not be empty
+/// - Must be at most 120 characters long
+/// - Must contain at least one alphanumeric character (so that a slug can be
+///   derived from it)
+#[derive(Debug)]
+pub struct ArticleTitle(String);
+
+impl ArticleTitle {
+    /// Tries to parse a string into a valid article title. Returns [`Err`]
+    /// if the [`ArticleTitle`] criteria are not met.
+    pub fn parse(s: String) -> Result<ArticleTitle, String> {
+        let is_empty_or_whitespace = s.trim().is_empty();
+        let is_too_long = s.chars().count() > 120;
+        let has_alphanumeric = s.chars().any(char::is_alphanumeric);
+
+        if is_empty_or_whitespace {
+            Err("A title cannot be empty.".to_string())
+        } else if is_too_long {
+            Err("The title is too long! (120 chars max.)".to_string())
+        } else if !has_alphanumeric {
+            Err(format!("{s} is not a valid title."))
+        } else {
+            Ok(Self(s.trim().to_owned()))
+        }
+    }
+}
+
+impl AsRef<String> for ArticleTitle {
+    fn as_ref(&self) -> &String {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+
+    use super::ArticleTitle;
+
+    #[test]
+    fn an_empty_title_is_not_valid() {
+        assert_err!(ArticleTitle::parse("".into()));
+    }
+
+    #[test]
+    fn a_whitespace_only_title_is_not_valid() {
+        assert_err!(ArticleTitle::parse("     ".into()));
+    }
+
+    #[test]
+    fn a_120_characters_long_title_is_valid() {
+        let title = "a".repeat(120);
+        assert_ok!(ArticleTitle::parse(title));
+    }
+
+    #[test]
+    fn too_long_title_is_not_valid() {
+        let title = "a".repeat(121);
+        assert_err!(ArticleTitle::parse(title));
+    }
+
+    #[test]
+    fn a_punctuation_only_title_is_not_valid() {
+        assert_err!(ArticleTitle::parse("?!...".into()));
+    }
+
+    #[test]
+    fn a_title_is_trimmed() {
+        let title = ArticleTitle::parse("  How to train your dragon ".into()).unwrap();
+        assert_eq!("How to train your dragon", title.as_ref());
+    }
+}
@@ -0,0 +1,446 @@
+//! This module holds the keys used to sign and verify the JWTs: either a
+//! shared secret (HS256), or asymmetric keys (RSA or EdDSA) loaded from PEM
+//! files. Asymmetric keys are identified by their ID (`kid` header), so that
+//! several verification keys can be active at once (key rotation), and their
+//! public part is published (JWK set) for other services to verify tokens.
+//! The keys are bound to an issuer and an audience: they only sign and verify
+//! tokens with these `iss` and `aud` claims.
+
+use jsonwebtoken::{
+    decode, decode_header, encode,
+    errors::{Error, ErrorKind},
+    Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use simple_asn1::{from_der, ASN1Block};
+
+use crate::configuration::{AppSettings, JwtKeySettings};
+
+/// The keys used to sign (one key) and verify (one or more keys) the JWTs,
+/// along with the claims expected in these JWTs.
+pub struct JwtKeys {
+    signing: SigningKey,
+    verification: Vec<VerificationKey>,
+    issuer: String,
+    audience: String,
+    leeway: u64,
+}
+
+/// The default issuer and audience of the JWTs.
+const DEFAULT_ISSUER_AND_AUDIENCE: &str = "conduit-v1";
+
+/// The default clock skew tolerated when validating the JWTs, in seconds.
+const DEFAULT_LEEWAY: u64 = 60;
+
+struct SigningKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: EncodingKey,
+}
+
+struct VerificationKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: DecodingKey,
+    /// The public key to publish, if the key is asymmetric.
+    public_key: Option<PublicKey>,
+}
+
+/// A public verification key, as published in the JWK set.
+pub struct PublicKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub parameters: PublicKeyParameters,
+}
+
+/// The parameters of a public key, base64url-encoded.
+pub enum PublicKeyParameters {
+    /// A RSA public key: its modulus `n` and exponent `e`.
+    Rsa { n: String, e: String },
+    /// An Ed25519 public key `x`.
+    Ed25519 { x: String },
+}
+
+/// The kinds of asymmetric keys supported.
+#[derive(Clone, Copy)]
+enum KeyKind {
+    Rsa,
+    Ed25519,
+}
+
+impl KeyKind {
+    fn of(algorithm: Algorithm) -> Result<Self, String> {
+        match algorithm {
+            Algorithm::RS256
+            | Algorithm::RS384
+            | Algorithm::RS512
+            | Algorithm::PS256
+            | Algorithm::PS384
+            | Algorithm::PS512 => Ok(KeyKind::Rsa),
+            Algorithm::EdDSA => Ok(KeyKind::Ed25519),
+            other => Err(format!("{other:?} is not a supported JWT key algorithm.")),
+        }
+    }
+}
+
+impl JwtKeys {
+    /// Keys for signing and verifying JWTs with HS256 and the given shared
+    /// secret. Nothing is published in the JWK set.
+    pub fn from_secret(secret: &str) -> Self {
+        Self {
+            signing: SigningKey {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                key: EncodingKey::from_secret(secret.as_bytes()),
+            },
+            verification: vec![VerificationKey {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                key: DecodingKey::from_secret(secret.as_bytes()),
+                public_key: None,
+            }],
+            issuer: DEFAULT_ISSUER_AND_AUDIENCE.into(),
+            audience: DEFAULT_ISSUER_AND_AUDIENCE.into(),
+            leeway: DEFAULT_LEEWAY,
+        }
+    }
+
+    /// Keys configured in the given settings: the asymmetric keys if any,
+    /// the shared secret otherwise, bound to the configured issuer and
+    /// audience.
+    pub fn from_settings(settings: &AppSettings) -> Result<Self, String> {
+        let keys = if !settings.jwt_keys.is_empty() {
+            Self::load(&settings.jwt_keys, settings.jwt_signing_kid.as_deref())?
+        } else {
+            match &settings.jwt_secret {
+                Some(secret) => Self::from_secret(secret),
+                None => return Err("Either a JWT secret or JWT keys must be configured.".into()),
+            }
+        };
+
+        Ok(keys
+            .with_claims(&settings.jwt_issuer, &settings.jwt_audience)
+            .with_leeway(settings.jwt_leeway))
+    }
+
+    /// Binds these keys to the given issuer (`iss`) and audience (`aud`)
+    /// instead of the default ones (`conduit-v1`).
+    pub fn with_claims(mut self, issuer: &str, audience: &str) -> Self {
+        self.issuer = issuer.to_owned();
+        self.audience = audience.to_owned();
+        self
+    }
+
+    /// Sets the clock skew tolerated when validating the expiration of the
+    /// JWTs, in seconds (60 by default).
+    pub fn with_leeway(mut self, leeway: u64) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Get the issuer of the JWTs (`iss` claim).
+    pub fn issuer(&self) -> &str {
+        self.issuer.as_ref()
+    }
+
+    /// Get the audience of the JWTs (`aud` claim).
+    pub fn audience(&self) -> &str {
+        self.audience.as_ref()
+    }
+
+    /// Get the clock skew tolerated when validating the JWTs, in seconds.
+    pub fn leeway(&self) -> u64 {
+        self.leeway
+    }
+
+    /// Loads the given asymmetric keys from their PEM files. Every key is
+    /// used for verification, the one with the given ID is used for signing
+    /// (by default, the first one with a private key).
+    pub fn load(keys: &[JwtKeySettings], signing_kid: Option<&str>) -> Result<Self, String> {
+        let mut signing = None;
+        let mut verification: Vec<VerificationKey> = Vec::with_capacity(keys.len());
+
+        for settings in keys {
+            let kind = KeyKind::of(settings.algorithm)?;
+            if verification
+                .iter()
+                .any(|k| k.kid.as_ref() == Some(&settings.kid))
+            {
+                return Err(format!("The JWT key ID {} is used twice.", settings.kid));
+            }
+
+            // Verification key
+            let public_pem = read_file(&settings.public_key_path)?;
+            let key = match kind {
+                KeyKind::Rsa => DecodingKey::from_rsa_pem(&public_pem),
+                KeyKind::Ed25519 => DecodingKey::from_ed_pem(&public_pem),
+            }
+            .map_err(|e| format!("Invalid public key {}: {e}", settings.kid))?;
+
+            verification.push(VerificationKey {
+                kid: Some(settings.kid.clone()),
+                algorithm: settings.algorithm,
+                key,
+                public_key: Some(PublicKey {
+                    kid: settings.kid.clone(),
+                    algorithm: settings.algorithm,
+                    parameters: public_key_parameters(kind, &public_pem)
+                        .map_err(|e| format!("Invalid public key {}: {e}", settings.kid))?,
+                }),
+            });
+
+            // Signing key
+            let is_signing_key = match signing_kid {
+                Some(kid) => kid == settings.kid,
+                None => signing.is_none() && settings.private_key_path.is_some(),
+            };
+            if is_signing_key {
+                let private_key_path = settings.private_key_path.as_ref().ok_or_else(|| {
+                    format!("The JWT signing key {} has no private key.", settings.kid)
+                })?;
+                let private_pem = read_file(private_key_path)?;
+                let key = match kind {
+                    KeyKind::Rsa => EncodingKey::from_rsa_pem(&private_pem),
+                    KeyKind::Ed25519 => EncodingKey::from_ed_pem(&private_pem),
+                }
+                .map_err(|e| format!("Invalid private key {}: {e}", settings.kid))?;
+
+                signing = Some(SigningKey {
+                    kid: Some(settings.kid.clone()),
+                    algorithm: settings.algorithm,
+                    key,
+                });
+            }
+        }
+
+        let signing = signing.ok_or_else(|| "No JWT signing key found.".to_string())?;
+
+        Ok(Self {
+            signing,
+            verification,
+            issuer: DEFAULT_ISSUER_AND_AUDIENCE.into(),
+            audience: DEFAULT_ISSUER_AND_AUDIENCE.into(),
+            leeway: DEFAULT_LEEWAY,
+        })
+    }
+
+    /// Returns the public keys to publish in the JWK set.
+    pub fn public_keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.verification
+            .iter()
+            .filter_map(|k| k.public_key.as_ref())
+    }
+
+    /// Signs the given claims with the signing key. The resulting token has
+    /// the ID of the key in its header (`kid`).
+    pub(super) fn encode<T: Serialize>(&self, claims: &T) -> Result<String, Error> {
+        let mut header = Header::new(self.signing.algorithm);
+        header.kid = self.signing.kid.clone();
+
+        encode(&header, claims, &self.signing.key)
+    }
+
+    /// Decodes the given token with the verification key matching its `kid`
+    /// header. The token must use the algorithm of this key.
+    pub(super) fn decode<T: DeserializeOwned>(
+        &self,
+        token: &str,
+        validation: &Validation,
+    ) -> Result<T, Error> {
+        let header = decode_header(token)?;
+        let key = self
+            .verification
+            .iter()
+            .find(|k| k.kid == header.kid)
+            .ok_or_else(|| Error::from(ErrorKind::InvalidToken))?;
+
+        let mut validation = validation.clone();
+        validation.algorithms = vec![key.algorithm];
+
+        Ok(decode::<T>(token, &key.key, &validation)?.claims)
+    }
+}
+
+fn read_file(path: &str) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| format!("Unable to read {path}: {e}"))
+}
+
+/// Extracts the parameters of a PEM-encoded public key (SubjectPublicKeyInfo,
+/// or PKCS#1 for RSA).
+fn public_key_parameters(kind: KeyKind, pem: &[u8]) -> Result<PublicKeyParameters, String> {
+    let pem = pem::parse(pem).map_err(|e| e.to_string())?;
+    let key = match pem.tag.as_str() {
+        "PUBLIC KEY" => subject_public_key(&pem.contents)?,
+        "RSA PUBLIC KEY" => pem.contents,
+        tag => return Err(format!("{tag} is not a public key.")),
+    };
+
+    match kind {
+        KeyKind::Rsa => match from_der(&key).map_err(|e| e.to_string())?.as_slice() {
+            [ASN1Block::Sequence(_, fields)] => match fields.as_slice() {
+                [ASN1Block::Integer(_, n), ASN1Block::Integer(_, e)] => {
+                    Ok(PublicKeyParameters::Rsa {
+                        n: base64url(&n.to_bytes_be().1),
+                        e: base64url(&e.to_bytes_be().1),
+                    })
+                },
+                _ => Err("Malformed RSA public key.".into()),
+            },
+            _ => Err("Malformed RSA public key.".into()),
+        },
+        KeyKind::Ed25519 if key.len() == 32 => {
+            Ok(PublicKeyParameters::Ed25519 { x: base64url(&key) })
+        },
+        KeyKind::Ed25519 => Err("Malformed Ed25519 public key.".into()),
+    }
+}
+
+/// Extracts the key from a DER-encoded SubjectPublicKeyInfo structure.
+fn subject_public_key(der: &[u8]) -> Result<Vec<u8>, String> {
+    match from_der(der).map_err(|e| e.to_string())?.as_slice() {
+        [ASN1Block::Sequence(_, fields)] => match fields.as_slice() {
+            [ASN1Block::Sequence(..), ASN1Block::BitString(_, _, key)] => Ok(key.clone()),
+            _ => Err("Malformed SubjectPublicKeyInfo.".into()),
+        },
+        _ => Err("Malformed SubjectPublicKeyInfo.".into()),
+    }
+}
+
+fn base64url(bytes: &[u8]) -> String {
+    base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+    use jsonwebtoken::{Algorithm, Validation};
+    use serde::{Deserialize, Serialize};
+
+    use super::{JwtKeys, PublicKeyParameters};
+    use crate::configuration::JwtKeySettings;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TestClaims {
+        sub: String,
+        exp: u64,
+    }
+
+    fn claims() -> TestClaims {
+        TestClaims {
+            sub: "jack".into(),
+            exp: u64::MAX / 2,
+        }
+    }
+
+    fn key_path(name: &str) -> String {
+        format!("{}/tests/keys/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    fn key(kid: &str, algorithm: Algorithm, name: &str, private: bool) -> JwtKeySettings {
+        JwtKeySettings {
+            kid: kid.into(),
+            algorithm,
+            public_key_path: key_path(&format!("{name}_public.pem")),
+            private_key_path: private.then(|| key_path(&format!("{name}_private.pem"))),
+        }
+    }
+
+    fn roundtrip(keys: &JwtKeys) -> Result<TestClaims, jsonwebtoken::errors::Error> {
+        let token = keys.encode(&claims()).unwrap();
+        keys.decode::<TestClaims>(&token, &Validation::default())
+    }
+
+    #[test]
+    fn a_token_signed_with_a_secret_is_verified() {
+        assert_ok!(roundtrip(&JwtKeys::from_secret("secret")));
+    }
+
+    #[test]
+    fn a_token_signed_with_a_rsa_key_is_verified() {
+        let keys = JwtKeys::load(&[key("rsa", Algorithm::RS256, "rsa", true)], None).unwrap();
+        assert_ok!(roundtrip(&keys));
+    }
+
+    #[test]
+    fn a_token_signed_with_an_ed25519_key_is_verified() {
+        let keys = JwtKeys::load(&[key("ed", Algorithm::EdDSA, "ed25519", true)], None).unwrap();
+        assert_ok!(roundtrip(&keys));
+    }
+
+    #[test]
+    fn a_token_signed_with_a_rotated_key_is_still_verified() {
+        let old_keys = JwtKeys::load(&[key("old", Algorithm::RS256, "rsa", true)], None).unwrap();
+        let token = old_keys.encode(&claims()).unwrap();
+
+        let new_keys = JwtKeys::load(
+            &[
+                key("old", Algorithm::RS256, "rsa", false),
+                key("new", Algorithm::EdDSA, "ed25519", true),
+            ],
+            Some("new"),
+        )
+        .unwrap();
+
+        assert_ok!(new_keys.decode::<TestClaims>(&token, &Validation::default()));
+        assert_eq!(2, new_keys.public_keys().count());
+    }
+
+    #[test]
+    fn a_token_with_an_unknown_kid_is_rejected() {
+        let keys = JwtKeys::load(&[key("a", Algorithm::RS256, "rsa", true)], None).unwrap();
+        let other_keys = JwtKeys::load(&[key("b", Algorithm::RS256, "rsa", true)], None).unwrap();
+        let token = other_keys.encode(&claims()).unwrap();
+
+        assert_err!(keys.decode::<TestClaims>(&token, &Validation::default()));
+    }
+
+    #[test]
+    fn a_token_signed_with_a_secret_is_rejected_by_asymmetric_keys() {
+        let keys = JwtKeys::load(&[key("rsa", Algorithm::RS256, "rsa", true)], None).unwrap();
+        // HS256 with the public key as secret, and the kid of the RSA key
+        let public_pem = std::fs::read_to_string(key_path("rsa_public.pem")).unwrap();
+        let mut forged_keys = JwtKeys::from_secret(&public_pem);
+        forged_keys.signing.kid = Some("rsa".into());
+        let token = forged_keys.encode(&claims()).unwrap();
+
+        assert_err!(keys.decode::<TestClaims>(&token, &Validation::default()));
+    }
+
+    #[test]
+    fn a_signing_key_without_private_key_is_not_valid() {
+        let keys = JwtKeys::load(&[key("rsa", Algorithm::RS256, "rsa", false)], Some("rsa"));
+        assert!(keys.is_err());
+    }
+
+    #[test]
+    fn a_symmetric_algorithm_is_not_valid_for_a_key() {
+        let keys = JwtKeys::load(&[key("rsa", Algorithm::HS256, "rsa", true)], None);
+        assert!(keys.is_err());
+    }
+
+    #[test]
+    fn public_keys_are_exported() {
+        let keys = JwtKeys::load(
+            &[
+                key("rsa", Algorithm::RS256, "rsa", true),
+                key("ed", Algorithm::EdDSA, "ed25519", false),
+            ],
+            None,
+        )
+        .unwrap();
+
+        let public_keys: Vec<_> = keys.public_keys().collect();
+        assert_eq!(2, public_keys.len());
+        match &public_keys[0].parameters {
+            PublicKeyParameters::Rsa { n, e } => {
+                assert_eq!(342, n.len()); // 2048 bits
+                assert_eq!("AQAB", e);
+            },
+            _ => panic!("Expected a RSA key"),
+        }
+        match &public_keys[1].parameters {
+            PublicKeyParameters::Ed25519 { x } => assert_eq!(43, x.len()), // 32 bytes
+            _ => panic!("Expected an Ed25519 key"),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Protection of the login against brute-force attacks: the failed attempts
+//! are counted per account and per client IP address, and once a threshold
+//! is reached, each further failed attempt locks the account (or the address)
+//! for an exponentially growing duration.
+
+use time::Duration;
+
+use crate::configuration::LoginLockoutSettings;
+
+/// The policy applied to the failed login attempts.
+#[derive(Clone, Copy, Debug)]
+pub struct LoginLockoutPolicy {
+    /// Failed attempts on an account before it is locked.
+    pub account_threshold: i32,
+    /// Failed attempts from an IP address before it is locked.
+    pub ip_threshold: i32,
+    /// The duration of the first lock.
+    pub base_lock: Duration,
+    /// The maximum duration of a lock, after which the failed attempts are
+    /// forgotten.
+    pub max_lock: Duration,
+}
+
+impl From<&LoginLockoutSettings> for LoginLockoutPolicy {
+    fn from(settings: &LoginLockoutSettings) -> Self {
+        Self {
+            account_threshold: settings.account_threshold,
+            ip_threshold: settings.ip_threshold,
+            base_lock: Duration::seconds(settings.base_lock_duration),
+            max_lock: Duration::seconds(settings.max_lock_duration),
+        }
+    }
+}
+
+impl LoginLockoutPolicy {
+    /// Returns how long to lock after the given number of consecutive failed
+    /// attempts, given the threshold: [`None`] below the threshold, then the
+    /// base duration doubled for each attempt beyond the threshold, up to the
+    /// maximum duration.
+    pub fn lock_duration(&self, failed_attempts: i32, threshold: i32) -> Option<Duration> {
+        let beyond = failed_attempts.checked_sub(threshold)?;
+        if beyond < 0 {
+            return None;
+        }
+
+        let factor = 2i32.checked_pow(beyond as u32).unwrap_or(i32::MAX);
+        let duration = self.base_lock.checked_mul(factor).unwrap_or(self.max_lock);
+
+        Some(duration.min(self.max_lock))
+    }
+}
+
+/// The key under which the failed attempts on the account with the given
+/// email are counted.
+pub fn account_key(email: &str) -> String {
+    format!("account:{}", email.to_lowercase())
+}
+
+/// The key under which the failed attempts from the given IP address are
+/// counted.
+pub fn ip_key(ip: &str) -> String {
+    format!("ip:{ip}")
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::{account_key, LoginLockoutPolicy};
+
+    fn policy() -> LoginLockoutPolicy {
+        LoginLockoutPolicy {
+            account_threshold: 3,
+            ip_threshold: 10,
+            base_lock: Duration::seconds(30),
+            max_lock: Duration::hours(1),
+        }
+    }
+
+    #[test]
+    fn no_lock_below_the_threshold() {
+        assert_eq!(None, policy().lock_duration(0, 3));
+        assert_eq!(None, policy().lock_duration(2, 3));
+    }
+
+    #[test]
+    fn lock_doubles_beyond_the_threshold() {
+        assert_eq!(Some(Duration::seconds(30)), policy().lock_duration(3, 3));
+        assert_eq!(Some(Duration::seconds(60)), policy().lock_duration(4, 3));
+        assert_eq!(Some(Duration::seconds(120)), policy().lock_duration(5, 3));
+    }
+
+    #[test]
+    fn lock_is_capped() {
+        assert_eq!(Some(Duration::hours(1)), policy().lock_duration(10, 3));
+        assert_eq!(
+            Some(Duration::hours(1)),
+            policy().lock_duration(i32::MAX, 3)
+        );
+    }
+
+    #[test]
+    fn account_keys_ignore_case() {
+        assert_eq!(account_key("Jake@Jake.com"), account_key("jake@jake.com"));
+    }
+}
@@ -0,0 +1,221 @@
+//! This module contains several strutures and functions dealing with
+//! authentication with a JSON Web Token (JWT) (the access token), and with
+//! the refresh tokens used to obtain new access tokens.
+
+use jsonwebtoken::Validation;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+pub mod keys;
+pub mod lockout;
+pub mod opaque_token;
+pub mod personal_access_token;
+pub mod refresh_token;
+pub mod totp;
+
+pub use keys::JwtKeys;
+pub use refresh_token::NewRefreshToken;
+
+/// The lifetimes of the tokens issued upon authentication.
+#[derive(Clone, Copy)]
+pub struct TokenLifetimes {
+    /// The lifetime of an access token (JWT).
+    pub access: Duration,
+    /// The lifetime of a refresh token.
+    pub refresh: Duration,
+    /// The lifetime of a password reset token.
+    pub password_reset: Duration,
+    /// The lifetime of an email verification token.
+    pub email_verification: Duration,
+}
+
+/// The structure defining the payload part of a JWT.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    iss: String,
+    sub: String,
+    aud: String,
+    exp: u64,
+    iat: u64,
+    jti: String,
+    /// The version of the tokens of the user at issue time: every token of
+    /// an older version is revoked.
+    ver: i32,
+}
+
+impl Claims {
+    /// Creates a new Claims structure with the given values as fields.
+    pub fn new(
+        iss: String,
+        sub: String,
+        aud: String,
+        exp: u64,
+        iat: u64,
+        jti: String,
+        ver: i32,
+    ) -> Self {
+        Self {
+            iss,
+            sub,
+            aud,
+            exp,
+            iat,
+            jti,
+            ver,
+        }
+    }
+
+    /// Get the ID of the user referenced in this claims (`sub` field), or
+    /// [`None`] if it is not a valid ID.
+    pub fn user_id(&self) -> Option<i32> {
+        self.sub.parse().ok()
+    }
+
+    /// Get a reference to the unique ID of the token (`jti` field).
+    pub fn token_id(&self) -> &str {
+        self.jti.as_ref()
+    }
+
+    /// Get the version of the tokens of the user when this token has been
+    /// issued (`ver` field).
+    pub fn token_version(&self) -> i32 {
+        self.ver
+    }
+
+    /// Get the date at which the token expires (`exp` field).
+    pub fn expires_at(&self) -> OffsetDateTime {
+        timestamp_to_datetime(self.exp)
+    }
+}
+
+/// Converts a JWT NumericDate to an [`OffsetDateTime`] (out of range values
+/// fall back to the UNIX epoch).
+fn timestamp_to_datetime(timestamp: u64) -> OffsetDateTime {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+/// Create a Claims struct for a new JWT (valid for the given duration), with
+/// the issuer and audience of the given keys.
+fn create_claims_from_user(
+    user_id: i32,
+    token_version: i32,
+    keys: &JwtKeys,
+    ttl: Duration,
+) -> Claims {
+    let iat = OffsetDateTime::now_utc();
+    let exp = iat + ttl;
+
+    Claims::new(
+        keys.issuer().to_owned(),
+        user_id.to_string(),
+        keys.audience().to_owned(),
+        exp.unix_timestamp() as u64,
+        iat.unix_timestamp() as u64,
+        opaque_token::generate_token(),
+        token_version,
+    )
+}
+
+/// Create a JWT for the user with the given ID and current token version
+/// (valid for the given duration), signed with the signing key.
+pub fn create_jwt_for_user(
+    user_id: i32,
+    token_version: i32,
+    keys: &JwtKeys,
+    ttl: Duration,
+) -> Result<String, jsonwebtoken::errors::Error> {
+    let claims = create_claims_from_user(user_id, token_version, keys, ttl);
+    keys.encode(&claims)
+}
+
+/// Decode a JWT token into a [`Claims`] struct with the matching verification
+/// key. The token must not be expired (up to the leeway of the keys), and
+/// must have the issuer and audience of the keys.
+pub fn decode_token(token: &str, keys: &JwtKeys) -> Result<Claims, jsonwebtoken::errors::Error> {
+    let mut validation = Validation::default();
+    validation.set_required_spec_claims(&["exp", "sub", "iss", "aud"]);
+    validation.set_issuer(&[keys.issuer()]);
+    validation.set_audience(&[keys.audience()]);
+    validation.leeway = keys.leeway();
+
+    keys.decode::<Claims>(token, &validation)
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+    use jsonwebtoken::errors::ErrorKind;
+    use time::Duration;
+
+    use super::{create_jwt_for_user, decode_token, Claims, JwtKeys};
+
+    fn keys() -> JwtKeys {
+        JwtKeys::from_secret("secret").with_claims("conduit", "conduit-api")
+    }
+
+    #[test]
+    fn a_valid_token_is_decoded() {
+        let token = create_jwt_for_user(42, 0, &keys(), Duration::hours(1)).unwrap();
+
+        let claims = assert_ok!(decode_token(&token, &keys()));
+        assert_eq!(Some(42), claims.user_id());
+        assert_eq!(0, claims.token_version());
+    }
+
+    #[test]
+    fn a_token_with_a_username_as_subject_has_no_user_id() {
+        // Tokens issued when the subject was the username
+        let claims = Claims::new(
+            "conduit".into(),
+            "jake".into(),
+            "conduit-api".into(),
+            0,
+            0,
+            "jti".into(),
+            0,
+        );
+
+        assert_eq!(None, claims.user_id());
+    }
+
+    #[test]
+    fn a_token_with_a_wrong_issuer_is_rejected() {
+        let other_keys = JwtKeys::from_secret("secret").with_claims("other", "conduit-api");
+        let token = create_jwt_for_user(42, 0, &other_keys, Duration::hours(1)).unwrap();
+
+        let error = assert_err!(decode_token(&token, &keys()));
+        assert_eq!(&ErrorKind::InvalidIssuer, error.kind());
+    }
+
+    #[test]
+    fn a_token_with_a_wrong_audience_is_rejected() {
+        let other_keys = JwtKeys::from_secret("secret").with_claims("conduit", "other");
+        let token = create_jwt_for_user(42, 0, &other_keys, Duration::hours(1)).unwrap();
+
+        let error = assert_err!(decode_token(&token, &keys()));
+        assert_eq!(&ErrorKind::InvalidAudience, error.kind());
+    }
+
+    #[test]
+    fn an_expired_token_is_rejected() {
+        let keys = keys().with_leeway(0);
+        let token = create_jwt_for_user(42, 0, &keys, Duration::seconds(-10)).unwrap();
+
+        let error = assert_err!(decode_token(&token, &keys));
+        assert_eq!(&ErrorKind::ExpiredSignature, error.kind());
+    }
+
+    #[test]
+    fn a_token_expired_within_the_leeway_is_accepted() {
+        let keys = keys().with_leeway(30);
+        let token = create_jwt_for_user(42, 0, &keys, Duration::seconds(-10)).unwrap();
+
+        assert_ok!(decode_token(&token, &keys));
+
+        let keys = keys.with_leeway(5);
+        assert_err!(decode_token(&token, &keys));
+    }
+}
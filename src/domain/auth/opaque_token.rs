@@ -0,0 +1,42 @@
+//! Opaque tokens are random strings handed to the client, of which only a
+//! hash is stored server-side (e.g. refresh tokens).
+
+use rand_core::{OsRng, RngCore};
+use sha3::{Digest, Sha3_256};
+
+/// Number of random bytes in a token (hex-encoded, the token is twice as
+/// long).
+const TOKEN_BYTES: usize = 32;
+
+/// Generates a new random token, hex-encoded.
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Returns the hash of the given token, the form in which it is stored.
+/// A fast hash is enough here as the tokens have a high entropy.
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha3_256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_token, hash_token};
+
+    #[test]
+    fn tokens_are_random() {
+        let token = generate_token();
+        assert_eq!(64, token.len());
+        assert_ne!(token, generate_token());
+    }
+
+    #[test]
+    fn hashes_are_deterministic() {
+        let token = generate_token();
+        assert_eq!(hash_token(&token), hash_token(&token));
+        assert_ne!(token, hash_token(&token));
+    }
+}
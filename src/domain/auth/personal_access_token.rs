@@ -0,0 +1,222 @@
+//! Personal access tokens are long-lived opaque tokens created by the users
+//! for their scripts, as an alternative to the JWTs. They are restricted to
+//! some [`Scope`]s.
+
+use time::OffsetDateTime;
+
+use super::opaque_token::{generate_token, hash_token};
+use crate::{domain::error::ValidationErrors, dtos::tokens::PersonalAccessTokenCreationDto};
+
+/// The prefix of every personal access token, telling them apart from the
+/// JWTs.
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "cpat_";
+
+/// Returns true if the given token looks like a personal access token.
+pub fn is_personal_access_token(token: &str) -> bool {
+    token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX)
+}
+
+/// What an authenticated request may do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    /// Read resources (`GET` requests).
+    Read,
+    /// Create, update or delete resources (any other request).
+    Write,
+}
+
+impl Scope {
+    /// Every scope, granted to the JWTs and by default to the personal access
+    /// tokens.
+    pub const ALL: &'static [Scope] = &[Scope::Read, Scope::Write];
+
+    /// Parses a scope from its name.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "read" => Ok(Scope::Read),
+            "write" => Ok(Scope::Write),
+            _ => Err(format!("{name} is not a valid scope.")),
+        }
+    }
+
+    /// Returns the name of the scope.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::Write => "write",
+        }
+    }
+}
+
+/// This struct represents a valid user input for a personal access token
+/// creation, along with the newly generated token.
+pub struct NewPersonalAccessToken {
+    pub name: String,
+    pub scopes: Vec<Scope>,
+    pub expires_at: Option<OffsetDateTime>,
+    /// The token to send to the client (only once).
+    pub token: String,
+    /// The hash of the token, to store.
+    pub hash: String,
+}
+
+impl TryFrom<PersonalAccessTokenCreationDto> for NewPersonalAccessToken {
+    type Error = ValidationErrors;
+
+    /// Transforms a [`PersonalAccessTokenCreationDto`] payload to a
+    /// domain-compliant [`NewPersonalAccessToken`] (non-empty name of 64
+    /// chars max., known scopes, every scope by default, expiration date in
+    /// the future if any). Every invalid field is reported.
+    fn try_from(value: PersonalAccessTokenCreationDto) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::default();
+
+        let name = errors.check("name", parse_name(value.token.name));
+        let scopes = errors.check("scopes", parse_scopes(value.token.scopes));
+        let expires_at = errors.check("expiresAt", parse_expiration(value.token.expires_at));
+
+        let (Some(name), Some(scopes), Some(expires_at)) = (name, scopes, expires_at) else {
+            return Err(errors);
+        };
+
+        let token = format!("{PERSONAL_ACCESS_TOKEN_PREFIX}{}", generate_token());
+
+        Ok(NewPersonalAccessToken {
+            name,
+            scopes,
+            expires_at,
+            hash: hash_token(&token),
+            token,
+        })
+    }
+}
+
+/// Validates the name of a token: it must not be empty and must be at most
+/// 64 characters long. Surrounding whitespaces are removed.
+fn parse_name(name: String) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        Err("The name of a token cannot be empty.".into())
+    } else if name.chars().count() > 64 {
+        Err("The name of a token is too long! (64 chars max.)".into())
+    } else {
+        Ok(name.to_owned())
+    }
+}
+
+/// Validates a list of scopes: each scope must be known, and at least one
+/// must be given. Duplicated scopes are removed. Every scope is granted if
+/// none is specified.
+fn parse_scopes(scopes: Option<Vec<String>>) -> Result<Vec<Scope>, String> {
+    let Some(names) = scopes else {
+        return Ok(Scope::ALL.to_vec());
+    };
+
+    let mut scopes = Vec::with_capacity(names.len());
+    for name in names {
+        let scope = Scope::parse(&name)?;
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+
+    if scopes.is_empty() {
+        return Err("At least one scope must be given.".into());
+    }
+
+    Ok(scopes)
+}
+
+/// Validates the expiration date of a token, if any: it must be in the
+/// future.
+fn parse_expiration(expires_at: Option<OffsetDateTime>) -> Result<Option<OffsetDateTime>, String> {
+    match expires_at {
+        Some(date) if date <= OffsetDateTime::now_utc() => {
+            Err("The expiration date must be in the future.".into())
+        },
+        _ => Ok(expires_at),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::assert_ok;
+    use time::{Duration, OffsetDateTime};
+
+    use super::{is_personal_access_token, NewPersonalAccessToken, Scope};
+    use crate::{
+        domain::auth::opaque_token::hash_token,
+        dtos::tokens::{
+            personal_access_token_creation_dto::PersonalAccessTokenCreationFields,
+            PersonalAccessTokenCreationDto,
+        },
+    };
+
+    fn dto(
+        name: &str,
+        scopes: Option<&[&str]>,
+        expires_at: Option<OffsetDateTime>,
+    ) -> PersonalAccessTokenCreationDto {
+        PersonalAccessTokenCreationDto {
+            token: PersonalAccessTokenCreationFields {
+                name: name.into(),
+                scopes: scopes.map(|s| s.iter().map(|&s| s.into()).collect()),
+                expires_at,
+            },
+        }
+    }
+
+    #[test]
+    fn a_valid_token_is_generated() {
+        let token = assert_ok!(NewPersonalAccessToken::try_from(dto(" ci ", None, None)));
+
+        assert_eq!("ci", token.name);
+        assert_eq!(Scope::ALL, token.scopes);
+        assert!(is_personal_access_token(&token.token));
+        assert_eq!(hash_token(&token.token), token.hash);
+    }
+
+    #[test]
+    fn duplicated_scopes_are_removed() {
+        let token = assert_ok!(NewPersonalAccessToken::try_from(dto(
+            "ci",
+            Some(&["read", "read"]),
+            None
+        )));
+        assert_eq!(vec![Scope::Read], token.scopes);
+    }
+
+    #[test]
+    fn empty_or_unknown_scopes_are_not_valid() {
+        assert!(NewPersonalAccessToken::try_from(dto("ci", Some(&[]), None)).is_err());
+        assert!(NewPersonalAccessToken::try_from(dto("ci", Some(&["admin"]), None)).is_err());
+    }
+
+    #[test]
+    fn a_past_expiration_date_is_not_valid() {
+        let yesterday = OffsetDateTime::now_utc() - Duration::days(1);
+        assert!(NewPersonalAccessToken::try_from(dto("ci", None, Some(yesterday))).is_err());
+
+        let tomorrow = OffsetDateTime::now_utc() + Duration::days(1);
+        assert_ok!(NewPersonalAccessToken::try_from(dto(
+            "ci",
+            None,
+            Some(tomorrow)
+        )));
+    }
+
+    #[test]
+    fn every_invalid_field_is_reported() {
+        let yesterday = OffsetDateTime::now_utc() - Duration::days(1);
+        let Err(errors) = NewPersonalAccessToken::try_from(dto(
+            &"a".repeat(65),
+            Some(&["admin"]),
+            Some(yesterday),
+        )) else {
+            panic!("The token should not be valid");
+        };
+
+        for field in ["name", "scopes", "expiresAt"] {
+            assert!(errors.get(field).is_some(), "{field} should be reported");
+        }
+    }
+}
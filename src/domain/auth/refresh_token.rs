@@ -0,0 +1,58 @@
+use time::{Duration, OffsetDateTime};
+
+use super::opaque_token::{generate_token, hash_token};
+
+/// This struct represents a newly issued refresh token, ready to be stored
+/// (only its hash is) and sent to the client.
+pub struct NewRefreshToken {
+    /// The token to send to the client.
+    pub token: String,
+    /// The hash of the token, to store.
+    pub hash: String,
+    /// The family of the token, shared by the tokens obtained by rotation.
+    pub family: String,
+    pub expires_at: OffsetDateTime,
+}
+
+impl NewRefreshToken {
+    /// Issues a refresh token starting a new family (i.e. upon login),
+    /// valid for the given duration.
+    pub fn new_family(ttl: Duration) -> Self {
+        Self::with_family(generate_token(), ttl)
+    }
+
+    /// Issues a refresh token replacing one of the given family (i.e. upon
+    /// rotation), valid for the given duration.
+    pub fn rotate(family: &str, ttl: Duration) -> Self {
+        Self::with_family(family.to_owned(), ttl)
+    }
+
+    fn with_family(family: String, ttl: Duration) -> Self {
+        let token = generate_token();
+
+        Self {
+            hash: hash_token(&token),
+            token,
+            family,
+            expires_at: OffsetDateTime::now_utc() + ttl,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::NewRefreshToken;
+    use crate::domain::auth::opaque_token::hash_token;
+
+    #[test]
+    fn a_rotated_token_keeps_its_family() {
+        let first = NewRefreshToken::new_family(Duration::days(1));
+        let second = NewRefreshToken::rotate(&first.family, Duration::days(1));
+
+        assert_eq!(first.family, second.family);
+        assert_ne!(first.token, second.token);
+        assert_eq!(hash_token(&second.token), second.hash);
+    }
+}
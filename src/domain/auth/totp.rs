@@ -0,0 +1,277 @@
+//! Time-based one-time passwords (TOTP, RFC 6238) used for two-factor
+//! authentication: 6-digit codes computed every 30 seconds from a secret
+//! shared with an authenticator app (HMAC-SHA1), and one-time recovery codes
+//! to use when the app is not available.
+
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use sha1::Sha1;
+use time::{Duration, OffsetDateTime};
+
+use super::opaque_token::hash_token;
+use crate::domain::users::password::constant_time_eq;
+
+/// The lifetime of a login challenge, issued when the password of a user
+/// with two-factor authentication is verified.
+pub const LOGIN_CHALLENGE_TTL: Duration = Duration::minutes(5);
+
+/// The number of invalid codes after which a login challenge is revoked.
+pub const LOGIN_CHALLENGE_MAX_ATTEMPTS: i32 = 5;
+
+/// The name of the service displayed by the authenticator apps.
+const ISSUER: &str = "Conduit";
+
+/// Number of random bytes of a secret (160 bits, as recommended by RFC 4226).
+const SECRET_BYTES: usize = 20;
+
+/// The duration of a time step, in seconds.
+const STEP_SECONDS: i64 = 30;
+
+/// Number of digits of a code.
+const DIGITS: u32 = 6;
+
+/// Number of time steps before and after the current one whose codes are
+/// accepted, to tolerate clock drifts and typing delays.
+const ALLOWED_DRIFT: i64 = 1;
+
+/// Number of recovery codes generated at once.
+const RECOVERY_CODES: usize = 10;
+
+/// The secret shared with an authenticator app.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TotpSecret(Vec<u8>);
+
+impl TotpSecret {
+    /// Generates a new random secret.
+    pub fn generate() -> Self {
+        let mut bytes = vec![0u8; SECRET_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Parses a base32-encoded secret (RFC 4648, without padding), as stored
+    /// and shown to the user.
+    pub fn from_base32(encoded: &str) -> Result<Self, String> {
+        base32_decode(encoded)
+            .map(Self)
+            .ok_or_else(|| "Invalid TOTP secret.".into())
+    }
+
+    /// Returns the secret base32-encoded (RFC 4648, without padding).
+    pub fn to_base32(&self) -> String {
+        base32_encode(&self.0)
+    }
+
+    /// Returns the `otpauth://` URI to register the secret of the given
+    /// account in an authenticator app (usually as a QR code).
+    pub fn otpauth_uri(&self, account: &str) -> String {
+        format!(
+            "otpauth://totp/{ISSUER}:{}?secret={}&issuer={ISSUER}&algorithm=SHA1&digits={DIGITS}&period={STEP_SECONDS}",
+            percent_encode(account),
+            self.to_base32()
+        )
+    }
+
+    /// Returns the code at the given time, as displayed by the authenticator
+    /// app.
+    pub fn code_at(&self, at: OffsetDateTime) -> String {
+        self.code(at.unix_timestamp().div_euclid(STEP_SECONDS))
+    }
+
+    /// Returns the code of the given time step.
+    fn code(&self, step: i64) -> String {
+        let mut mac =
+            Hmac::<Sha1>::new_from_slice(&self.0).expect("HMAC can take a key of any size");
+        mac.update(&step.to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+
+        // Dynamic truncation (RFC 4226, section 5.3)
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+
+        format!(
+            "{:0width$}",
+            binary % 10u32.pow(DIGITS),
+            width = DIGITS as usize
+        )
+    }
+
+    /// Verifies the given code at the given time. Returns the time step of
+    /// the code if it is valid, and more recent than `last_step` (the step of
+    /// the last code used), so that a code cannot be used twice.
+    pub fn verify(&self, code: &str, at: OffsetDateTime, last_step: Option<i64>) -> Option<i64> {
+        let code = code.trim();
+        let current = at.unix_timestamp().div_euclid(STEP_SECONDS);
+
+        (current - ALLOWED_DRIFT..=current + ALLOWED_DRIFT)
+            .filter(|step| last_step.is_none_or(|last| *step > last))
+            .find(|step| constant_time_eq(self.code(*step).as_bytes(), code.as_bytes()))
+    }
+}
+
+/// Generates a new set of recovery codes, of the form `xxxxx-xxxxx`
+/// (hexadecimal).
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODES)
+        .map(|_| {
+            let mut bytes = [0u8; 5];
+            OsRng.fill_bytes(&mut bytes);
+            let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+            format!("{}-{}", &hex[..5], &hex[5..])
+        })
+        .collect()
+}
+
+/// Returns the hash of the given recovery code, the form in which it is
+/// stored. The code is normalized first (case and dashes are ignored).
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .trim()
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    hash_token(&normalized)
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        encoded.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    encoded
+}
+
+fn base32_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+
+    for c in encoded.trim_end_matches('=').bytes() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Percent-encodes everything but the unreserved characters (RFC 3986).
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            },
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{generate_recovery_codes, hash_recovery_code, TotpSecret};
+
+    /// The secret of the RFC 6238 test vectors.
+    fn rfc_secret() -> TotpSecret {
+        TotpSecret(b"12345678901234567890".to_vec())
+    }
+
+    fn at(timestamp: i64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(timestamp).unwrap()
+    }
+
+    #[test]
+    fn codes_match_the_rfc_test_vectors() {
+        // The RFC gives 8-digit codes, we keep the last 6 digits
+        for (timestamp, code) in [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+        ] {
+            assert_eq!(code, rfc_secret().code_at(at(timestamp)));
+        }
+    }
+
+    #[test]
+    fn a_valid_code_is_accepted_with_a_small_drift() {
+        assert_eq!(Some(1), rfc_secret().verify("287082", at(59), None));
+        assert_eq!(Some(1), rfc_secret().verify("287082", at(89), None));
+        assert_eq!(None, rfc_secret().verify("287082", at(120), None));
+    }
+
+    #[test]
+    fn an_invalid_code_is_rejected() {
+        assert_eq!(None, rfc_secret().verify("287083", at(59), None));
+        assert_eq!(None, rfc_secret().verify("", at(59), None));
+    }
+
+    #[test]
+    fn a_code_cannot_be_used_twice() {
+        assert_eq!(None, rfc_secret().verify("287082", at(59), Some(1)));
+    }
+
+    #[test]
+    fn secrets_are_base32_encoded() {
+        let secret = rfc_secret();
+        assert_eq!("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ", secret.to_base32());
+        assert_eq!(
+            secret,
+            TotpSecret::from_base32("gezdgnbvgy3tqojqgezdgnbvgy3tqojq").unwrap()
+        );
+        assert!(TotpSecret::from_base32("not base32!").is_err());
+
+        let secret = TotpSecret::generate();
+        assert_eq!(
+            secret,
+            TotpSecret::from_base32(&secret.to_base32()).unwrap()
+        );
+    }
+
+    #[test]
+    fn the_otpauth_uri_contains_the_secret() {
+        let uri = rfc_secret().otpauth_uri("jake doe");
+        assert_eq!(
+            "otpauth://totp/Conduit:jake%20doe?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Conduit&algorithm=SHA1&digits=6&period=30",
+            uri
+        );
+    }
+
+    #[test]
+    fn recovery_codes_are_normalized_before_hashing() {
+        let codes = generate_recovery_codes();
+        assert_eq!(10, codes.len());
+        assert_eq!(11, codes[0].len());
+        assert_eq!(
+            hash_recovery_code(&codes[0]),
+            hash_recovery_code(&codes[0].to_uppercase().replace('-', ""))
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod new_comment;
+
+pub use new_comment::NewComment;
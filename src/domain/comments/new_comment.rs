@@ -0,0 +1,54 @@
+use crate::dtos::comments::CommentCreationDto;
+
+/// This struct represents a valid user input for a comment creation.
+#[derive(Debug)]
+pub struct NewComment {
+    pub body: String,
+}
+
+impl TryFrom<CommentCreationDto> for NewComment {
+    type Error = String;
+
+    /// Transforms a [`CommentCreationDto`] payload to a domain-compliant
+    /// [`NewComment`] (non-empty body, 1000 chars max.).
+    fn try_from(value: CommentCreationDto) -> Result<Self, Self::Error> {
+        let body = value.comment.body;
+        if body.trim().is_empty() {
+            return Err("A comment cannot be empty.".into());
+        }
+        if body.chars().count() > 1000 {
+            return Err("The comment is too long! (1000 chars max.)".into());
+        }
+
+        Ok(NewComment { body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+
+    use super::NewComment;
+    use crate::dtos::comments::{comment_creation_dto::CommentCreationFields, CommentCreationDto};
+
+    fn dto(body: String) -> CommentCreationDto {
+        CommentCreationDto {
+            comment: CommentCreationFields { body },
+        }
+    }
+
+    #[test]
+    fn an_empty_comment_is_not_valid() {
+        assert_err!(NewComment::try_from(dto("  ".into())));
+    }
+
+    #[test]
+    fn a_1000_characters_long_comment_is_valid() {
+        assert_ok!(NewComment::try_from(dto("a".repeat(1000))));
+    }
+
+    #[test]
+    fn too_long_comment_is_not_valid() {
+        assert_err!(NewComment::try_from(dto("a".repeat(1001))));
+    }
+}
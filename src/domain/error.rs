@@ -1,30 +1,404 @@
-use actix_web::HttpResponse;
+//! This module defines the error type returned by every request handler,
+//! [`AppError`], the validation failures collected per field,
+//! [`ValidationErrors`], and the JSON error body sent to the client,
+//! [`ErrorResponse`].
+
+use std::{collections::BTreeMap, fmt};
+
+use actix_web::{
+    http::{header, StatusCode},
+    HttpResponse, ResponseError,
+};
+use jsonwebtoken::errors::ErrorKind;
 use serde::Serialize;
 
-/// Returns a [`HttpResponse`], ready to be returned by a request
-/// handler in case of **validation** error with the appropriate body.
-/// The HTTP Status Code is set to 422 Unprocessable Entity, and the body
-/// is a JSON-encoded [`ErrorResponse`].
-pub fn validation_error(body: &str) -> HttpResponse {
-    HttpResponse::UnprocessableEntity().json(ErrorResponse::new(body))
+/// The error type of the application. Each variant carries the message sent
+/// to the client, except [`AppError::Internal`] whose message is only logged.
+#[derive(Debug)]
+pub enum AppError {
+    /// The input is invalid (422 Unprocessable Entity).
+    Validation(String),
+    /// Some fields of the input are invalid (422 Unprocessable Entity).
+    InvalidFields(ValidationErrors),
+    /// The resource does not exist (404 Not Found).
+    NotFound(String),
+    /// The request is not authenticated (401 Unauthorized).
+    Unauthorized(String),
+    /// The request is not authenticated with a valid access token (401
+    /// Unauthorized), or its token lacks the required scope (403 Forbidden).
+    /// The client is challenged to send a valid one.
+    Unauthenticated(BearerChallenge),
+    /// The authenticated user is not allowed to do this (403 Forbidden).
+    Forbidden(String),
+    /// The resource conflicts with an existing one (409 Conflict).
+    Conflict(String),
+    /// Too many attempts have been made, the client must wait for the given
+    /// duration before retrying (429 Too Many Requests).
+    TooManyRequests(time::Duration),
+    /// Something unexpected happened (500 Internal Server Error).
+    Internal(String),
+}
+
+impl AppError {
+    /// Returns a closure mapping a [`sqlx::Error::RowNotFound`] to a
+    /// [`AppError::NotFound`] with the given message. Any other error is
+    /// converted as usual. Meant to be used with [`Result::map_err`].
+    pub fn when_row_not_found(message: &'static str) -> impl FnOnce(sqlx::Error) -> AppError {
+        move |e| match e {
+            sqlx::Error::RowNotFound => AppError::NotFound(message.into()),
+            e => e.into(),
+        }
+    }
+
+    /// Returns a closure mapping a [`sqlx::Error::Database`] (e.g. a
+    /// constraint violation) to a [`AppError::Validation`] with the given
+    /// message. Any other error is converted as usual. Meant to be used with
+    /// [`Result::map_err`].
+    pub fn when_database_error(message: &'static str) -> impl FnOnce(sqlx::Error) -> AppError {
+        move |e| match e {
+            sqlx::Error::Database(_) => AppError::Validation(message.into()),
+            e => e.into(),
+        }
+    }
+
+    /// Returns a closure mapping a unique constraint violation to a
+    /// [`AppError::InvalidFields`] reporting the field associated to the
+    /// violated constraint, given `(constraint, field)` pairs. Any other error
+    /// is converted as usual. Meant to be used with [`Result::map_err`].
+    pub fn when_unique_violation(
+        fields: &'static [(&'static str, &'static str)],
+    ) -> impl FnOnce(sqlx::Error) -> AppError {
+        move |e| {
+            let field = match &e {
+                sqlx::Error::Database(db_error) => db_error.constraint().and_then(|constraint| {
+                    fields
+                        .iter()
+                        .find(|(name, _)| *name == constraint)
+                        .map(|(_, field)| *field)
+                }),
+                _ => None,
+            };
+
+            match field {
+                Some(field) => {
+                    let mut errors = ValidationErrors::default();
+                    errors.add(field, "has already been taken");
+                    AppError::InvalidFields(errors)
+                },
+                None => e.into(),
+            }
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Validation(message)
+            | AppError::NotFound(message)
+            | AppError::Unauthorized(message)
+            | AppError::Forbidden(message)
+            | AppError::Conflict(message) => write!(f, "{message}"),
+            AppError::Unauthenticated(challenge) => write!(f, "{}", challenge.message()),
+            AppError::TooManyRequests(_) => write!(f, "Too many attempts, try again later."),
+            AppError::InvalidFields(errors) => write!(f, "Invalid fields: {errors:?}"),
+            AppError::Internal(_) => write!(f, "Unexpected error happened."),
+        }
+    }
+}
+
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::Validation(_) | AppError::InvalidFields(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            },
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Unauthenticated(BearerChallenge::InsufficientScope) => StatusCode::FORBIDDEN,
+            AppError::Unauthorized(_) | AppError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Every error is sent as a JSON-encoded [`ErrorResponse`]. An
+    /// [`AppError::Unauthenticated`] error also has a `WWW-Authenticate`
+    /// header, and an [`AppError::TooManyRequests`] error a `Retry-After`
+    /// header (in whole seconds, rounded up).
+    fn error_response(&self) -> HttpResponse {
+        let response = match self {
+            AppError::InvalidFields(errors) => ErrorResponse::from(errors.clone()),
+            AppError::Internal(details) => {
+                log::error!("Internal error: {details}");
+                ErrorResponse::new(&self.to_string())
+            },
+            _ => ErrorResponse::new(&self.to_string()),
+        };
+
+        let mut builder = HttpResponse::build(self.status_code());
+        if let AppError::Unauthenticated(challenge) = self {
+            builder.insert_header((header::WWW_AUTHENTICATE, challenge.to_string()));
+        }
+        if let AppError::TooManyRequests(retry_after) = self {
+            let seconds = retry_after.as_seconds_f64().ceil().max(1.0) as u64;
+            builder.insert_header((header::RETRY_AFTER, seconds));
+        }
+
+        builder.json(response)
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::RowNotFound => AppError::NotFound("Resource not found.".into()),
+            sqlx::Error::Database(db_error) if db_error.code().as_deref() == Some("23505") => {
+                AppError::Conflict("This resource already exists.".into())
+            },
+            e => AppError::Internal(e.to_string()),
+        }
+    }
+}
+
+impl From<actix_web::error::BlockingError> for AppError {
+    fn from(e: actix_web::error::BlockingError) -> Self {
+        AppError::Internal(e.to_string())
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for AppError {
+    fn from(e: jsonwebtoken::errors::Error) -> Self {
+        match e.kind() {
+            ErrorKind::InvalidToken
+            | ErrorKind::InvalidSignature
+            | ErrorKind::ExpiredSignature
+            | ErrorKind::ImmatureSignature
+            | ErrorKind::InvalidIssuer
+            | ErrorKind::InvalidAudience
+            | ErrorKind::InvalidSubject
+            | ErrorKind::InvalidAlgorithm
+            | ErrorKind::Base64(_)
+            | ErrorKind::Json(_)
+            | ErrorKind::Utf8(_) => AppError::Unauthenticated(BearerChallenge::InvalidToken),
+            _ => AppError::Internal(e.to_string()),
+        }
+    }
+}
+
+/// The reason why a request is not authenticated, sent to the client in the
+/// `WWW-Authenticate` header of the response (RFC 6750).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BearerChallenge {
+    /// No access token has been sent (or with an unsupported scheme).
+    MissingToken,
+    /// The Authorization header is malformed.
+    InvalidRequest,
+    /// The access token is malformed, expired, revoked, or its user does not
+    /// exist anymore.
+    InvalidToken,
+    /// The access token does not have the scope required by the request.
+    InsufficientScope,
+}
+
+impl BearerChallenge {
+    /// The message sent in the error body.
+    fn message(&self) -> &'static str {
+        match self {
+            BearerChallenge::MissingToken => "You're not authenticated.",
+            BearerChallenge::InvalidRequest => "Malformed Authorization header.",
+            BearerChallenge::InvalidToken => "Invalid token.",
+            BearerChallenge::InsufficientScope => "This token does not allow this request.",
+        }
+    }
+}
+
+/// Formats the challenge as the value of a `WWW-Authenticate` header.
+impl fmt::Display for BearerChallenge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, r#"Bearer realm="conduit""#)?;
+        let error = match self {
+            BearerChallenge::MissingToken => return Ok(()),
+            BearerChallenge::InvalidRequest => "invalid_request",
+            BearerChallenge::InvalidToken => "invalid_token",
+            BearerChallenge::InsufficientScope => "insufficient_scope",
+        };
+        write!(
+            f,
+            r#", error="{error}", error_description="{}""#,
+            self.message()
+        )
+    }
+}
+
+/// Validation failures keyed by field name, each field having one or more
+/// error messages. Errors that are not related to a specific field are
+/// stored under the `body` key.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationErrors(BTreeMap<String, Vec<String>>);
+
+impl ValidationErrors {
+    /// Records an error message for the given field.
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.0
+            .entry(field.to_owned())
+            .or_default()
+            .push(message.into());
+    }
+
+    /// Returns the value of the given result, or records its error for the
+    /// given field and returns [`None`].
+    pub fn check<T>(&mut self, field: &str, result: Result<T, String>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(message) => {
+                self.add(field, message);
+                None
+            },
+        }
+    }
+
+    /// Records every error message of the given errors.
+    pub fn merge(&mut self, other: ValidationErrors) {
+        for (field, messages) in other.0 {
+            self.0.entry(field).or_default().extend(messages);
+        }
+    }
+
+    /// Returns true if no error has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the error messages recorded for the given field, if any.
+    pub fn get(&self, field: &str) -> Option<&[String]> {
+        self.0.get(field).map(Vec::as_slice)
+    }
 }
 
 #[derive(Serialize)]
 /// Error response model sent by any handler in case of error
-pub struct ErrorResponse<'a> {
-    errors: ErrorBody<'a>,
+pub struct ErrorResponse {
+    errors: ValidationErrors,
 }
 
-#[derive(Serialize)]
-struct ErrorBody<'a> {
-    body: Vec<&'a str>,
+impl ErrorResponse {
+    /// Create a new [`ErrorResponse`] with a single error not related to a
+    /// specific field.
+    pub fn new(error: &str) -> Self {
+        let mut errors = ValidationErrors::default();
+        errors.add("body", error);
+        ErrorResponse { errors }
+    }
 }
 
-impl<'a> ErrorResponse<'a> {
-    /// Create a new [`ErrorResponse`]
-    pub fn new(error: &'a str) -> Self {
-        ErrorResponse {
-            errors: ErrorBody { body: vec![error] },
-        }
+impl From<ValidationErrors> for ErrorResponse {
+    fn from(errors: ValidationErrors) -> Self {
+        ErrorResponse { errors }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, ResponseError};
+    use jsonwebtoken::errors::ErrorKind;
+
+    use super::{AppError, BearerChallenge, ErrorResponse, ValidationErrors};
+
+    #[test]
+    fn row_not_found_is_404() {
+        let error = AppError::from(sqlx::Error::RowNotFound);
+        assert_eq!(StatusCode::NOT_FOUND, error.status_code());
+    }
+
+    #[test]
+    fn other_sqlx_errors_are_500() {
+        let error = AppError::from(sqlx::Error::PoolTimedOut);
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, error.status_code());
+    }
+
+    #[test]
+    fn internal_error_details_are_not_displayed() {
+        let error = AppError::Internal("secret details".into());
+        assert_eq!("Unexpected error happened.", error.to_string());
+    }
+
+    #[test]
+    fn expired_token_is_401() {
+        let error = AppError::from(jsonwebtoken::errors::Error::from(
+            ErrorKind::ExpiredSignature,
+        ));
+        assert_eq!(StatusCode::UNAUTHORIZED, error.status_code());
+    }
+
+    #[test]
+    fn unauthenticated_error_has_a_bearer_challenge() {
+        let response = AppError::Unauthenticated(BearerChallenge::MissingToken).error_response();
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+        assert_eq!(
+            r#"Bearer realm="conduit""#,
+            response.headers().get("WWW-Authenticate").unwrap()
+        );
+
+        let response = AppError::Unauthenticated(BearerChallenge::InvalidToken).error_response();
+        assert_eq!(
+            r#"Bearer realm="conduit", error="invalid_token", error_description="Invalid token.""#,
+            response.headers().get("WWW-Authenticate").unwrap()
+        );
+    }
+
+    #[test]
+    fn other_errors_have_no_challenge() {
+        let response = AppError::Unauthorized("Invalid refresh token.".into()).error_response();
+        assert_eq!(None, response.headers().get("WWW-Authenticate"));
+    }
+
+    #[test]
+    fn too_many_requests_has_a_rounded_up_retry_after() {
+        let response =
+            AppError::TooManyRequests(time::Duration::milliseconds(29_500)).error_response();
+        assert_eq!(StatusCode::TOO_MANY_REQUESTS, response.status());
+        assert_eq!("30", response.headers().get("Retry-After").unwrap());
+    }
+
+    #[test]
+    fn invalid_key_is_500() {
+        let error = AppError::from(jsonwebtoken::errors::Error::from(ErrorKind::InvalidRsaKey(
+            "bad key",
+        )));
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, error.status_code());
+    }
+
+    #[test]
+    fn validation_errors_are_grouped_by_field() {
+        let mut errors = ValidationErrors::default();
+        errors.add("email", "is invalid");
+        assert_eq!(
+            Some("jake"),
+            errors.check("username", Ok::<_, String>("jake"))
+        );
+        errors.check::<()>("email", Err("is too long".into()));
+
+        assert_eq!(
+            Some(&["is invalid".into(), "is too long".into()][..]),
+            errors.get("email")
+        );
+        assert_eq!(None, errors.get("username"));
+    }
+
+    #[test]
+    fn error_response_is_keyed_by_field() {
+        let mut errors = ValidationErrors::default();
+        errors.add("username", "has already been taken");
+
+        let json = serde_json::to_string(&ErrorResponse::from(errors)).unwrap();
+        assert_eq!(
+            r#"{"errors":{"username":["has already been taken"]}}"#,
+            json
+        );
+
+        let json = serde_json::to_string(&ErrorResponse::new("Not found.")).unwrap();
+        assert_eq!(r#"{"errors":{"body":["Not found."]}}"#, json);
     }
 }
@@ -1,6 +1,9 @@
 //! This module deals with the bussiness logic of the application
 //! (e.g. input validation, JWT tokens...).
 
+pub mod articles;
 pub mod auth;
+pub mod comments;
 pub mod error;
+pub mod pagination;
 pub mod users;
@@ -0,0 +1,176 @@
+//! This module deals with the pagination of lists: with a limit and an
+//! offset ([`Pagination`]), or with a limit and a cursor
+//! ([`CursorPagination`]), which stays consistent while items are added.
+
+use time::OffsetDateTime;
+
+/// The number of items of a page when not specified.
+const DEFAULT_LIMIT: i64 = 20;
+
+/// Holds valid limit/offset pagination parameters.
+/// A valid pagination meets these criteria:
+/// - The limit must be between 1 and 100 (defaults to 20)
+/// - The offset must be positive (defaults to 0)
+#[derive(Debug)]
+pub struct Pagination {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl Pagination {
+    /// Tries to parse optional limit and offset values into a valid
+    /// [`Pagination`]. Missing values are replaced by their defaults.
+    pub fn parse(limit: Option<i64>, offset: Option<i64>) -> Result<Pagination, String> {
+        let limit = parse_limit(limit)?;
+        let offset = offset.unwrap_or(0);
+
+        if offset < 0 {
+            Err(format!("{offset} is not a valid offset."))
+        } else {
+            Ok(Self { limit, offset })
+        }
+    }
+}
+
+/// Holds valid cursor pagination parameters, for lists ordered by date (most
+/// recent first) then by ID:
+/// - The limit must be between 1 and 100 (defaults to 20)
+/// - The cursor, if any, is the position of the last item of the previous
+///   page (the first page is returned otherwise)
+#[derive(Debug)]
+pub struct CursorPagination {
+    pub limit: i64,
+    pub after: Option<Cursor>,
+}
+
+impl CursorPagination {
+    /// Tries to parse an optional limit and an optional encoded cursor into a
+    /// valid [`CursorPagination`].
+    pub fn parse(limit: Option<i64>, cursor: Option<&str>) -> Result<CursorPagination, String> {
+        Ok(Self {
+            limit: parse_limit(limit)?,
+            after: cursor.map(Cursor::decode).transpose()?,
+        })
+    }
+}
+
+/// The position of an item in a list ordered by date, then by ID. It is
+/// handed to the clients encoded as an opaque string.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cursor {
+    pub date: OffsetDateTime,
+    pub id: i32,
+}
+
+impl Cursor {
+    /// Encodes the cursor as an opaque (URL-safe) string.
+    pub fn encode(&self) -> String {
+        base64::encode_config(
+            format!("{}:{}", self.date.unix_timestamp_nanos(), self.id),
+            base64::URL_SAFE_NO_PAD,
+        )
+    }
+
+    /// Tries to decode a cursor encoded with [`Cursor::encode`].
+    pub fn decode(encoded: &str) -> Result<Cursor, String> {
+        let invalid = || format!("{encoded} is not a valid cursor.");
+
+        let decoded = base64::decode_config(encoded, base64::URL_SAFE_NO_PAD)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+        let (nanos, id) = decoded.split_once(':').ok_or_else(invalid)?;
+        let date = nanos
+            .parse()
+            .ok()
+            .and_then(|nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos).ok())
+            .ok_or_else(invalid)?;
+        let id = id.parse().map_err(|_| invalid())?;
+
+        Ok(Self { date, id })
+    }
+}
+
+/// Parses an optional limit, which must be between 1 and 100.
+fn parse_limit(limit: Option<i64>) -> Result<i64, String> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    if (1..=100).contains(&limit) {
+        Ok(limit)
+    } else {
+        Err(format!("{limit} is not a valid limit (between 1 and 100)."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+    use time::macros::datetime;
+
+    use super::{Cursor, CursorPagination, Pagination};
+
+    #[test]
+    fn missing_values_are_defaulted() {
+        let pagination = Pagination::parse(None, None).unwrap();
+        assert_eq!(20, pagination.limit);
+        assert_eq!(0, pagination.offset);
+    }
+
+    #[test]
+    fn a_zero_limit_is_not_valid() {
+        assert_err!(Pagination::parse(Some(0), None));
+    }
+
+    #[test]
+    fn a_too_big_limit_is_not_valid() {
+        assert_err!(Pagination::parse(Some(101), None));
+    }
+
+    #[test]
+    fn a_negative_offset_is_not_valid() {
+        assert_err!(Pagination::parse(None, Some(-1)));
+    }
+
+    #[test]
+    fn valid_values_are_accepted() {
+        assert_ok!(Pagination::parse(Some(100), Some(40)));
+    }
+
+    #[test]
+    fn cursors_are_decoded_back() {
+        let cursor = Cursor {
+            date: datetime!(2022-06-01 12:30:45.123456789 UTC),
+            id: 42,
+        };
+        assert_eq!(cursor, Cursor::decode(&cursor.encode()).unwrap());
+    }
+
+    #[test]
+    fn invalid_cursors_are_rejected() {
+        assert_err!(Cursor::decode("not a cursor"));
+        assert_err!(Cursor::decode(&base64::encode_config(
+            "42",
+            base64::URL_SAFE_NO_PAD
+        )));
+        assert_err!(Cursor::decode(&base64::encode_config(
+            "yesterday:42",
+            base64::URL_SAFE_NO_PAD
+        )));
+        assert_err!(Cursor::decode(&base64::encode_config(
+            "1654086645000000000:jake",
+            base64::URL_SAFE_NO_PAD
+        )));
+    }
+
+    #[test]
+    fn cursor_pagination_defaults_to_the_first_page() {
+        let pagination = CursorPagination::parse(None, None).unwrap();
+        assert_eq!(20, pagination.limit);
+        assert_eq!(None, pagination.after);
+    }
+
+    #[test]
+    fn cursor_pagination_limit_is_checked() {
+        assert_err!(CursorPagination::parse(Some(0), None));
+    }
+}
@@ -0,0 +1,85 @@
+//! This module deals with the verification of the email addresses of the
+//! users: a single-use token is emailed to the address to verify (upon
+//! registration, or when an user changes its email), which proves its
+//! ownership once sent back.
+
+use serde::Deserialize;
+use time::Duration;
+
+use crate::mailer::Email;
+
+/// What the users whose email address is not verified yet may do with the
+/// authenticated endpoints.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnverifiedEmailPolicy {
+    /// Everything, as verified users.
+    Allow,
+    /// Only read (safe requests), except for their own account
+    /// (`/api/user` endpoints) which they can fully manage.
+    ReadOnly,
+    /// Only manage their own account (`/api/user` endpoints).
+    AccountOnly,
+}
+
+impl UnverifiedEmailPolicy {
+    /// Returns true if an user whose email is not verified may make a request
+    /// with the given method safety on the given path.
+    pub fn allows(&self, safe_method: bool, path: &str) -> bool {
+        let own_account = path == "/api/user" || path.starts_with("/api/user/");
+
+        match self {
+            UnverifiedEmailPolicy::Allow => true,
+            UnverifiedEmailPolicy::ReadOnly => safe_method || own_account,
+            UnverifiedEmailPolicy::AccountOnly => own_account,
+        }
+    }
+}
+
+/// Returns the email sent to the given address to verify it, with the given
+/// token valid for the given duration.
+pub fn email_verification_email(to: &str, token: &str, ttl: Duration) -> Email {
+    Email {
+        to: to.to_string(),
+        subject: "Verify your Conduit email address".into(),
+        body: format!(
+            "Please confirm that this address belongs to your Conduit account.\n\
+             \n\
+             To verify it, use the following token within {} hours:\n\
+             \n\
+             {token}\n\
+             \n\
+             If you do not have a Conduit account, you can safely ignore this email.",
+            ttl.whole_hours()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnverifiedEmailPolicy;
+
+    #[test]
+    fn allow_policy_allows_everything() {
+        assert!(UnverifiedEmailPolicy::Allow.allows(false, "/api/articles"));
+    }
+
+    #[test]
+    fn read_only_policy_allows_reads_and_own_account() {
+        let policy = UnverifiedEmailPolicy::ReadOnly;
+        assert!(policy.allows(true, "/api/articles/feed"));
+        assert!(policy.allows(false, "/api/user"));
+        assert!(policy.allows(false, "/api/user/logout"));
+        assert!(!policy.allows(false, "/api/articles"));
+        assert!(!policy.allows(false, "/api/users"));
+        assert!(!policy.allows(false, "/api/username"));
+    }
+
+    #[test]
+    fn account_only_policy_allows_own_account() {
+        let policy = UnverifiedEmailPolicy::AccountOnly;
+        assert!(policy.allows(true, "/api/user"));
+        assert!(policy.allows(false, "/api/user/tokens"));
+        assert!(!policy.allows(true, "/api/articles/feed"));
+    }
+}
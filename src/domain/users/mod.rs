@@ -1,5 +1,8 @@
 pub mod email;
+pub mod email_verification;
 pub mod new_user;
+pub mod password;
+pub mod password_reset;
 pub mod user_login_request;
 pub mod user_update_request;
 pub mod username;
@@ -1,7 +1,5 @@
-use sha3::{Digest, Sha3_512};
-
-use super::{email::UserEmail, username::Username};
-use crate::dtos::users::UserRegistrationDto;
+use super::{email::UserEmail, password::hash_password, username::Username};
+use crate::{domain::error::ValidationErrors, dtos::users::UserRegistrationDto};
 
 /// This struct represents a valid user input for registration.
 pub struct NewUser {
@@ -11,20 +9,28 @@ pub struct NewUser {
 }
 
 impl TryFrom<UserRegistrationDto> for NewUser {
-    type Error = String;
+    type Error = ValidationErrors;
 
     /// Transforms a [`UserRegistration`] payload to a domain-compliant
     /// [`NewUser`] (valid username, valid email address, hashed password).
+    /// Every invalid field is reported.
     fn try_from(value: UserRegistrationDto) -> Result<Self, Self::Error> {
-        let username = Username::parse(value.user.username)?;
-        let email = UserEmail::parse(value.user.email)?;
+        let mut errors = ValidationErrors::default();
+
+        let username = errors.check("username", Username::parse(value.user.username));
+        let email = errors.check("email", UserEmail::parse(value.user.email));
         if value.user.password.is_empty() {
-            return Err("A password cannot be empty.".to_string());
+            errors.add("password", "A password cannot be empty.");
         }
 
-        let mut hasher = Sha3_512::new();
-        hasher.update(value.user.password.as_str());
-        let hashed_password = format!("{:x}", hasher.finalize());
+        // Only hash the password once everything else is valid
+        let (Some(username), Some(email), true) = (username, email, errors.is_empty()) else {
+            return Err(errors);
+        };
+        let Some(hashed_password) = errors.check("password", hash_password(&value.user.password))
+        else {
+            return Err(errors);
+        };
 
         Ok(NewUser {
             username,
@@ -0,0 +1,147 @@
+//! This module deals with password hashing and verification.
+//!
+//! Passwords are hashed with Argon2id (salted and memory-hard) and stored in
+//! the PHC string format (e.g. `$argon2id$v=19$m=19456,t=2,p=1$...`).
+//!
+//! Legacy passwords were stored as unsalted SHA3-512 hex digests. They are
+//! still accepted, but must be rehashed with Argon2id as soon as possible
+//! (see [`PasswordVerification::ValidNeedsRehash`]).
+
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use rand_core::OsRng;
+use sha3::{Digest, Sha3_512};
+
+/// The outcome of the verification of a password against a stored hash.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PasswordVerification {
+    /// The password matches the stored hash.
+    Valid,
+    /// The password matches a stored hash that uses a legacy scheme: the
+    /// password should be rehashed with [`hash_password`].
+    ValidNeedsRehash,
+    /// The password does not match the stored hash.
+    Invalid,
+}
+
+/// The Argon2id hash of a random password nobody knows, with the default
+/// parameters: verifying a password against it takes as long as against the
+/// hash of an existing user, so that unknown users cannot be told apart.
+pub const DUMMY_PASSWORD_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$T4aVwyEl3LkltE3fNc+lxA$1IGY8ArOwQS+wefHNZQsIbkqGy8VrJDXpuh626ySyYk";
+
+/// Hashes a password with Argon2id and a random salt. Returns the hash in the
+/// PHC string format.
+pub fn hash_password(password: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|_| "Unable to hash the password.".to_string())
+}
+
+/// Verifies a password against a stored hash (either Argon2id in the PHC
+/// string format, or a legacy SHA3-512 hex digest). The comparison is done
+/// in constant time.
+pub fn verify_password(password: &str, stored_hash: &str) -> PasswordVerification {
+    match PasswordHash::new(stored_hash) {
+        Ok(hash) => match Argon2::default().verify_password(password.as_bytes(), &hash) {
+            Ok(_) => PasswordVerification::Valid,
+            Err(_) => PasswordVerification::Invalid,
+        },
+        // Not a PHC string: this is a legacy SHA3-512 hex digest
+        Err(_) => {
+            let mut hasher = Sha3_512::new();
+            hasher.update(password.as_bytes());
+            let legacy_hash = format!("{:x}", hasher.finalize());
+
+            if constant_time_eq(legacy_hash.as_bytes(), stored_hash.as_bytes()) {
+                PasswordVerification::ValidNeedsRehash
+            } else {
+                PasswordVerification::Invalid
+            }
+        },
+    }
+}
+
+/// Compares two byte slices in constant time (with respect to their content).
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::password_hash::PasswordHash;
+
+    use super::{hash_password, verify_password, PasswordVerification, DUMMY_PASSWORD_HASH};
+
+    #[test]
+    fn a_hashed_password_is_argon2id() {
+        let hash = hash_password("jack1234").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+    }
+
+    #[test]
+    fn the_same_password_is_salted_differently() {
+        assert_ne!(
+            hash_password("jack1234").unwrap(),
+            hash_password("jack1234").unwrap()
+        );
+    }
+
+    #[test]
+    fn the_dummy_hash_has_the_parameters_of_a_new_hash() {
+        let new_hash = hash_password("jack1234").unwrap();
+        let new_hash = PasswordHash::new(&new_hash).unwrap();
+        let dummy_hash = PasswordHash::new(DUMMY_PASSWORD_HASH).unwrap();
+
+        assert_eq!(new_hash.algorithm, dummy_hash.algorithm);
+        assert_eq!(new_hash.version, dummy_hash.version);
+        assert_eq!(new_hash.params, dummy_hash.params);
+        assert_eq!(
+            PasswordVerification::Invalid,
+            verify_password("jack1234", DUMMY_PASSWORD_HASH)
+        );
+    }
+
+    #[test]
+    fn a_correct_password_is_valid() {
+        let hash = hash_password("jack1234").unwrap();
+        assert_eq!(
+            PasswordVerification::Valid,
+            verify_password("jack1234", &hash)
+        );
+    }
+
+    #[test]
+    fn an_incorrect_password_is_invalid() {
+        let hash = hash_password("jack1234").unwrap();
+        assert_eq!(
+            PasswordVerification::Invalid,
+            verify_password("jack4321", &hash)
+        );
+    }
+
+    #[test]
+    fn a_correct_legacy_password_needs_rehash() {
+        // SHA3-512 of "jack"
+        let hash = "d309fd6af59c2018f41b3b2285b1570a2ac2fc3d3bbb467f2e74ba5196fa9bde15834ff7eac93de3e8fbf83249d767c0e8d90cdc22fcb6d2785ff91bfbcd79c4";
+        assert_eq!(
+            PasswordVerification::ValidNeedsRehash,
+            verify_password("jack", hash)
+        );
+    }
+
+    #[test]
+    fn an_incorrect_legacy_password_is_invalid() {
+        // SHA3-512 of "jack"
+        let hash = "d309fd6af59c2018f41b3b2285b1570a2ac2fc3d3bbb467f2e74ba5196fa9bde15834ff7eac93de3e8fbf83249d767c0e8d90cdc22fcb6d2785ff91bfbcd79c4";
+        assert_eq!(PasswordVerification::Invalid, verify_password("john", hash));
+    }
+}
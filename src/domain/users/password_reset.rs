@@ -0,0 +1,113 @@
+//! This module deals with the password reset of the users who forgot their
+//! password: a single-use token is emailed to them, which is exchanged for a
+//! new password.
+
+use time::Duration;
+
+use super::password::hash_password;
+use crate::{
+    domain::{auth::opaque_token::hash_token, error::ValidationErrors},
+    dtos::users::PasswordResetDto,
+    mailer::Email,
+};
+
+/// This struct represents a valid user input for resetting a password.
+pub struct PasswordResetRequest {
+    /// The hash of the reset token, the form in which it is stored.
+    pub token_hash: String,
+    /// The new password, hashed.
+    pub password: String,
+}
+
+impl TryFrom<PasswordResetDto> for PasswordResetRequest {
+    type Error = ValidationErrors;
+
+    /// Transforms a [`PasswordResetDto`] payload to a domain-compliant
+    /// [`PasswordResetRequest`] (non-empty token, hashed password).
+    /// Every invalid field is reported.
+    fn try_from(value: PasswordResetDto) -> Result<Self, Self::Error> {
+        let mut errors = ValidationErrors::default();
+
+        if value.user.token.is_empty() {
+            errors.add("token", "A token cannot be empty.");
+        }
+        if value.user.password.is_empty() {
+            errors.add("password", "A password cannot be empty.");
+        }
+
+        // Only hash the password once everything else is valid
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let Some(hashed_password) = errors.check("password", hash_password(&value.user.password))
+        else {
+            return Err(errors);
+        };
+
+        Ok(PasswordResetRequest {
+            token_hash: hash_token(&value.user.token),
+            password: hashed_password,
+        })
+    }
+}
+
+/// Returns the email sent to the given address, with the given reset token
+/// valid for the given duration.
+pub fn password_reset_email(to: &str, token: &str, ttl: Duration) -> Email {
+    Email {
+        to: to.to_string(),
+        subject: "Reset your Conduit password".into(),
+        body: format!(
+            "Someone (hopefully you) asked to reset the password of your Conduit account.\n\
+             \n\
+             To choose a new password, use the following token within {} minutes:\n\
+             \n\
+             {token}\n\
+             \n\
+             If you did not ask for it, you can safely ignore this email.",
+            ttl.whole_minutes()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claim::{assert_err, assert_ok};
+    use time::Duration;
+
+    use super::{password_reset_email, PasswordResetRequest};
+    use crate::{
+        domain::auth::opaque_token::hash_token,
+        dtos::users::{password_reset_dto::PasswordResetFields, PasswordResetDto},
+    };
+
+    fn dto(token: &str, password: &str) -> PasswordResetDto {
+        PasswordResetDto {
+            user: PasswordResetFields {
+                token: token.into(),
+                password: password.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn token_is_hashed() {
+        let request = assert_ok!(PasswordResetRequest::try_from(dto("token", "jake1234")));
+        assert_eq!(hash_token("token"), request.token_hash);
+    }
+
+    #[test]
+    fn empty_fields_are_rejected() {
+        let errors = assert_err!(PasswordResetRequest::try_from(dto("", "")).map(|_| ()));
+        assert!(errors.get("token").is_some());
+        assert!(errors.get("password").is_some());
+    }
+
+    #[test]
+    fn email_contains_the_token() {
+        let email = password_reset_email("jake@jake.com", "abcdef", Duration::hours(1));
+        assert_eq!("jake@jake.com", email.to);
+        assert!(email.body.contains("\nabcdef\n"));
+        assert!(email.body.contains("60 minutes"));
+    }
+}
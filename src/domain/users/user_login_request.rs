@@ -1,32 +1,35 @@
-use sha3::{Digest, Sha3_512};
-
 use super::email::UserEmail;
-use crate::dtos::users::UserLoginDto;
+use crate::{domain::error::ValidationErrors, dtos::users::UserLoginDto};
 
 /// This struct represents a valid user input for authentication.
+/// The password is kept in clear, it is meant to be checked against the
+/// stored hash with [`super::password::verify_password`].
 pub struct UserLoginRequest {
     pub email: UserEmail,
     pub password: String,
 }
 
 impl TryFrom<UserLoginDto> for UserLoginRequest {
-    type Error = String;
+    type Error = ValidationErrors;
 
     /// Transforms a [`UserLogin`] payload to a domain-compliant
-    /// [`LoginUser`] (valid email address, hashed password).
+    /// [`LoginUser`] (valid email address, non-empty password).
+    /// Every invalid field is reported.
     fn try_from(value: UserLoginDto) -> Result<Self, Self::Error> {
-        let email = UserEmail::parse(value.user.email)?;
+        let mut errors = ValidationErrors::default();
+
+        let email = errors.check("email", UserEmail::parse(value.user.email));
         if value.user.password.is_empty() {
-            return Err("A password cannot be empty.".to_string());
+            errors.add("password", "A password cannot be empty.");
         }
 
-        let mut hasher = Sha3_512::new();
-        hasher.update(value.user.password.as_str());
-        let hashed_password = format!("{:x}", hasher.finalize());
+        let (Some(email), true) = (email, errors.is_empty()) else {
+            return Err(errors);
+        };
 
         Ok(UserLoginRequest {
             email,
-            password: hashed_password,
+            password: value.user.password,
         })
     }
 }
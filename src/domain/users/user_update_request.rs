@@ -1,7 +1,5 @@
-use sha3::{Digest, Sha3_512};
-
-use super::{email::UserEmail, username::Username};
-use crate::dtos::users::UserUpdateDto;
+use super::{email::UserEmail, password::hash_password, username::Username};
+use crate::{domain::error::ValidationErrors, dtos::users::UserUpdateDto};
 
 /// This struct represents a valid user input for registration.
 pub struct UserUpdateRequest {
@@ -10,6 +8,7 @@ pub struct UserUpdateRequest {
     pub password: Option<String>,
     pub bio: Option<String>,
     pub image: Option<String>,
+    pub private: Option<bool>,
 }
 
 impl UserUpdateRequest {
@@ -20,59 +19,72 @@ impl UserUpdateRequest {
             && self.password.is_none()
             && self.bio.is_none()
             && self.image.is_none()
+            && self.private.is_none()
     }
 }
 
 impl TryFrom<UserUpdateDto> for UserUpdateRequest {
-    type Error = String;
+    type Error = ValidationErrors;
 
     /// Transforms a [`UserUpdate`] payload to a domain-compliant
     /// [`UpdateUser`] (valid username, valid email address, hashed password,
-    /// valid bio and image).
+    /// valid bio and image). Every invalid field is reported.
     fn try_from(value: UserUpdateDto) -> Result<Self, Self::Error> {
-        let mut username = None;
-        if let Some(uname) = value.user.username {
-            username = Some(Username::parse(uname)?);
-        }
+        let mut errors = ValidationErrors::default();
 
-        let mut email = None;
-        if let Some(user_email) = value.user.email {
-            email = Some(UserEmail::parse(user_email)?);
-        }
+        let username = value
+            .user
+            .username
+            .and_then(|uname| errors.check("username", Username::parse(uname)));
+
+        let email = value
+            .user
+            .email
+            .and_then(|user_email| errors.check("email", UserEmail::parse(user_email)));
 
-        let mut password = None;
-        if let Some(user_password) = value.user.password {
+        let password = value.user.password.filter(|user_password| {
             if user_password.is_empty() {
-                return Err("A password cannot be empty".into());
+                errors.add("password", "A password cannot be empty");
             }
-            let mut hasher = Sha3_512::new();
-            hasher.update(user_password);
-            password = Some(format!("{:x}", hasher.finalize()));
-        }
+            !user_password.is_empty()
+        });
 
-        let mut bio = None;
-        if let Some(user_bio) = value.user.bio {
+        let bio = value.user.bio.filter(|user_bio| {
             if user_bio.chars().count() > 140 {
-                return Err("The bio is too long! (140 chars max.)".into());
+                errors.add("bio", "The bio is too long! (140 chars max.)");
+                return false;
             }
-            bio = Some(user_bio);
-        }
+            true
+        });
 
-        let mut image = None;
-        if let Some(image_uri) = value.user.image {
-            if validator::validate_url(&image_uri) {
-                image = Some(image_uri);
-            } else {
-                return Err(format!("{image_uri} is not a valid URI!"));
+        let image = value.user.image.filter(|image_uri| {
+            if !validator::validate_url(image_uri) {
+                errors.add("image", format!("{image_uri} is not a valid URI!"));
+                return false;
             }
+            true
+        });
+
+        if !errors.is_empty() {
+            return Err(errors);
         }
 
+        // Only hash the password once everything else is valid
+        let password = match password {
+            Some(user_password) => match errors.check("password", hash_password(&user_password)) {
+                Some(hashed_password) => Some(hashed_password),
+                None => return Err(errors),
+            },
+            None => None,
+        };
+
         Ok(UserUpdateRequest {
             username,
             email,
             password,
             bio,
             image,
+            private: value.user.private,
         })
     }
 }
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for an article creation.
+#[derive(Deserialize)]
+pub struct ArticleCreationDto {
+    pub article: ArticleCreationFields,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleCreationFields {
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    #[serde(default)]
+    pub tag_list: Vec<String>,
+}
@@ -0,0 +1,64 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    dtos::profiles::profile_response_dto::ProfileResponseFields,
+    repositories::articles_repository::Article,
+};
+
+/// The Article API Response format, as described in the spec, encapsulates
+/// article information inside an `article` field.
+#[derive(Serialize)]
+pub struct ArticleResponseDto<'a> {
+    article: ArticleResponseFields<'a>,
+}
+
+/// The article fields. The `author` field embeds the author's profile the
+/// same way the Profile API does.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleResponseFields<'a> {
+    slug: &'a str,
+    title: &'a str,
+    description: &'a str,
+    body: &'a str,
+    tag_list: &'a [String],
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+    favorited: bool,
+    favorites_count: i64,
+    author: ProfileResponseFields<'a>,
+}
+
+impl<'a> From<&'a Article> for ArticleResponseFields<'a> {
+    /// Builds the article fields from an article as stored in the database.
+    fn from(article: &'a Article) -> Self {
+        Self {
+            slug: &article.slug,
+            title: &article.title,
+            description: &article.description,
+            body: &article.body,
+            tag_list: &article.tag_list,
+            created_at: article.created_at,
+            updated_at: article.updated_at,
+            favorited: article.favorited,
+            favorites_count: article.favorites_count,
+            author: ProfileResponseFields::new(
+                &article.author_username,
+                article.author_bio.as_deref(),
+                article.author_image.as_deref(),
+                article.author_following,
+            ),
+        }
+    }
+}
+
+impl<'a> From<&'a Article> for ArticleResponseDto<'a> {
+    fn from(article: &'a Article) -> Self {
+        Self {
+            article: article.into(),
+        }
+    }
+}
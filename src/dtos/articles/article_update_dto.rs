@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for an article update.
+#[derive(Deserialize)]
+pub struct ArticleUpdateDto {
+    pub article: ArticleUpdateFields,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleUpdateFields {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub body: Option<String>,
+    pub tag_list: Option<Vec<String>>,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+/// The query string model received to filter the list of articles
+/// (e.g. `?tag=rust&author=jack&favorited=john`).
+#[derive(Deserialize)]
+pub struct ArticlesQueryDto {
+    pub tag: Option<String>,
+    pub author: Option<String>,
+    pub favorited: Option<String>,
+}
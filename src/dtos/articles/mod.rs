@@ -0,0 +1,11 @@
+pub mod article_creation_dto;
+pub mod article_response_dto;
+pub mod article_update_dto;
+pub mod articles_query_dto;
+pub mod multiple_articles_response_dto;
+
+pub use article_creation_dto::ArticleCreationDto;
+pub use article_response_dto::ArticleResponseDto;
+pub use article_update_dto::ArticleUpdateDto;
+pub use articles_query_dto::ArticlesQueryDto;
+pub use multiple_articles_response_dto::MultipleArticlesResponseDto;
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+use super::article_response_dto::ArticleResponseFields;
+use crate::repositories::articles_repository::Article;
+
+/// The Multiple Articles API Response format, as described in the spec, lists
+/// articles inside an `articles` field along with the total count of articles
+/// matching the request (regardless of pagination).
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleArticlesResponseDto<'a> {
+    articles: Vec<ArticleResponseFields<'a>>,
+    articles_count: i64,
+}
+
+impl<'a> MultipleArticlesResponseDto<'a> {
+    /// Constructs a new [`MultipleArticlesResponseDto`] from a page of
+    /// articles and the total count of articles.
+    pub fn new(articles: &'a [Article], articles_count: i64) -> Self {
+        Self {
+            articles: articles.iter().map(ArticleResponseFields::from).collect(),
+            articles_count,
+        }
+    }
+}
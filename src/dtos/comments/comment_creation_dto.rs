@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for a comment creation.
+#[derive(Deserialize)]
+pub struct CommentCreationDto {
+    pub comment: CommentCreationFields,
+}
+
+#[derive(Deserialize)]
+pub struct CommentCreationFields {
+    pub body: String,
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    dtos::profiles::profile_response_dto::ProfileResponseFields,
+    repositories::comments_repository::Comment,
+};
+
+/// The Comment API Response format, as described in the spec, encapsulates
+/// comment information inside a `comment` field.
+#[derive(Serialize)]
+pub struct CommentResponseDto<'a> {
+    comment: CommentResponseFields<'a>,
+}
+
+/// The comment fields. The `author` field embeds the author's profile the
+/// same way the Profile API does.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentResponseFields<'a> {
+    id: i32,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+    body: &'a str,
+    author: ProfileResponseFields<'a>,
+}
+
+impl<'a> From<&'a Comment> for CommentResponseFields<'a> {
+    /// Builds the comment fields from a comment as stored in the database.
+    fn from(comment: &'a Comment) -> Self {
+        Self {
+            id: comment.id,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            body: &comment.body,
+            author: ProfileResponseFields::new(
+                &comment.author_username,
+                comment.author_bio.as_deref(),
+                comment.author_image.as_deref(),
+                comment.author_following,
+            ),
+        }
+    }
+}
+
+impl<'a> From<&'a Comment> for CommentResponseDto<'a> {
+    fn from(comment: &'a Comment) -> Self {
+        Self {
+            comment: comment.into(),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod comment_creation_dto;
+pub mod comment_response_dto;
+pub mod multiple_comments_response_dto;
+
+pub use comment_creation_dto::CommentCreationDto;
+pub use comment_response_dto::CommentResponseDto;
+pub use multiple_comments_response_dto::MultipleCommentsResponseDto;
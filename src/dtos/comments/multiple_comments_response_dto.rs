@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+use super::comment_response_dto::CommentResponseFields;
+use crate::repositories::comments_repository::Comment;
+
+/// The Multiple Comments API Response format, as described in the spec, lists
+/// comments inside a `comments` field.
+#[derive(Serialize)]
+pub struct MultipleCommentsResponseDto<'a> {
+    comments: Vec<CommentResponseFields<'a>>,
+}
+
+impl<'a> MultipleCommentsResponseDto<'a> {
+    /// Constructs a new [`MultipleCommentsResponseDto`] from a list of comments.
+    pub fn new(comments: &'a [Comment]) -> Self {
+        Self {
+            comments: comments.iter().map(CommentResponseFields::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use jsonwebtoken::Algorithm;
+use serde::Serialize;
+
+use crate::domain::auth::keys::{PublicKey, PublicKeyParameters};
+
+/// The JSON Web Key Set (RFC 7517) response format, listing the public keys
+/// used to verify the JWTs.
+#[derive(Serialize)]
+pub struct JwksResponseDto<'a> {
+    keys: Vec<JwkFields<'a>>,
+}
+
+/// A public JSON Web Key. Only the fields matching its key type (`kty`) are
+/// present.
+#[derive(Serialize)]
+struct JwkFields<'a> {
+    kty: &'static str,
+    #[serde(rename = "use")]
+    key_use: &'static str,
+    alg: Algorithm,
+    kid: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    e: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crv: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<&'a str>,
+}
+
+impl<'a> From<&'a PublicKey> for JwkFields<'a> {
+    fn from(key: &'a PublicKey) -> Self {
+        let (kty, n, e, crv, x) = match &key.parameters {
+            PublicKeyParameters::Rsa { n, e } => {
+                ("RSA", Some(n.as_str()), Some(e.as_str()), None, None)
+            },
+            PublicKeyParameters::Ed25519 { x } => {
+                ("OKP", None, None, Some("Ed25519"), Some(x.as_str()))
+            },
+        };
+
+        Self {
+            kty,
+            key_use: "sig",
+            alg: key.algorithm,
+            kid: &key.kid,
+            n,
+            e,
+            crv,
+            x,
+        }
+    }
+}
+
+impl<'a> JwksResponseDto<'a> {
+    /// Constructs a new [`JwksResponseDto`] from the given public keys.
+    pub fn new(keys: impl Iterator<Item = &'a PublicKey>) -> Self {
+        Self {
+            keys: keys.map(JwkFields::from).collect(),
+        }
+    }
+}
@@ -1,5 +1,12 @@
 //! This module contains all the DTOs (Data Transfer Objects) used throughout
 //! the program (mainly in the `handlers` module).
 
+pub mod articles;
+pub mod comments;
+pub mod jwks_response_dto;
+pub mod pagination_dto;
 pub mod profiles;
+pub mod tags;
+pub mod tokens;
+pub mod two_factor;
 pub mod users;
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// The query string model received for paginated lists
+/// (e.g. `?limit=20&offset=0`).
+#[derive(Deserialize)]
+pub struct PaginationDto {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// The query string model received for lists paginated with a cursor
+/// (e.g. `?limit=20&cursor=...`).
+#[derive(Deserialize)]
+pub struct CursorPaginationDto {
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
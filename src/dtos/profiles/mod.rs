@@ -1 +1,2 @@
+pub mod multiple_profiles_response_dto;
 pub mod profile_response_dto;
@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+use super::profile_response_dto::ProfileResponseFields;
+use crate::repositories::followers_repository::ListedProfile;
+
+/// The Multiple Profiles API Response format lists profiles inside a
+/// `profiles` field along with the cursor of the next page (`null` on the last
+/// page).
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleProfilesResponseDto<'a> {
+    profiles: Vec<ProfileResponseFields<'a>>,
+    next_cursor: Option<String>,
+}
+
+impl<'a> MultipleProfilesResponseDto<'a> {
+    /// Constructs a new [`MultipleProfilesResponseDto`] from a page of
+    /// profiles and the cursor of the next page.
+    pub fn new(profiles: &'a [ListedProfile], next_cursor: Option<String>) -> Self {
+        Self {
+            profiles: profiles
+                .iter()
+                .map(|p| {
+                    ProfileResponseFields::new(
+                        &p.username,
+                        p.bio.as_deref(),
+                        p.image.as_deref(),
+                        p.following,
+                    )
+                })
+                .collect(),
+            next_cursor,
+        }
+    }
+}
@@ -4,15 +4,33 @@ use serde::Serialize;
 /// profile information inside a `profile` field.
 #[derive(Serialize)]
 pub struct ProfileResponseDto<'a> {
-    profile: ProfileResponseFields<'a>,
+    profile: ProfileDetailsFields<'a>,
+}
+
+/// The profile fields, along with whether the follow request of the
+/// authenticated user is pending (for private accounts) and the optional
+/// counts of followers and followed users (only returned by
+/// `GET /api/profiles/:username`).
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProfileDetailsFields<'a> {
+    #[serde(flatten)]
+    fields: ProfileResponseFields<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    followers_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    following_count: Option<i64>,
 }
 
 /// The profile fields. We accept `bio` and `image` to be [`None`]
 /// (translated to `null` in JSON) as they have not a default value on
 /// registration. `following` is also an option because it is displayed only
 /// when the request is authenticated.
+/// These fields are also embedded as the `author` of articles.
 #[derive(Serialize)]
-struct ProfileResponseFields<'a> {
+pub struct ProfileResponseFields<'a> {
     username: &'a str,
     bio: Option<&'a str>,
     image: Option<&'a str>,
@@ -30,12 +48,42 @@ impl<'a> ProfileResponseDto<'a> {
         following: Option<bool>,
     ) -> Self {
         Self {
-            profile: ProfileResponseFields {
-                username,
-                bio,
-                image,
-                following,
+            profile: ProfileDetailsFields {
+                fields: ProfileResponseFields::new(username, bio, image, following),
+                pending: None,
+                followers_count: None,
+                following_count: None,
             },
         }
     }
+
+    /// Adds whether the follow request of the authenticated user is pending.
+    pub fn with_pending(mut self, pending: bool) -> Self {
+        self.profile.pending = Some(pending);
+        self
+    }
+
+    /// Adds the counts of followers and followed users to the profile.
+    pub fn with_counts(mut self, followers_count: i64, following_count: i64) -> Self {
+        self.profile.followers_count = Some(followers_count);
+        self.profile.following_count = Some(following_count);
+        self
+    }
+}
+
+impl<'a> ProfileResponseFields<'a> {
+    /// Constructs new [`ProfileResponseFields`] with the given values.
+    pub fn new(
+        username: &'a str,
+        bio: Option<&'a str>,
+        image: Option<&'a str>,
+        following: Option<bool>,
+    ) -> Self {
+        Self {
+            username,
+            bio,
+            image,
+            following,
+        }
+    }
 }
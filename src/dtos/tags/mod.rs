@@ -0,0 +1,3 @@
+pub mod tags_response_dto;
+
+pub use tags_response_dto::TagsResponseDto;
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+/// The Tags API Response format, as described in the spec, lists tags inside
+/// a `tags` field.
+#[derive(Serialize)]
+pub struct TagsResponseDto<'a> {
+    tags: &'a [String],
+}
+
+impl<'a> TagsResponseDto<'a> {
+    /// Constructs a new [`TagsResponseDto`] with the given tags.
+    pub fn new(tags: &'a [String]) -> Self {
+        Self { tags }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod multiple_personal_access_tokens_response_dto;
+pub mod personal_access_token_creation_dto;
+pub mod personal_access_token_response_dto;
+
+pub use multiple_personal_access_tokens_response_dto::MultiplePersonalAccessTokensResponseDto;
+pub use personal_access_token_creation_dto::PersonalAccessTokenCreationDto;
+pub use personal_access_token_response_dto::PersonalAccessTokenResponseDto;
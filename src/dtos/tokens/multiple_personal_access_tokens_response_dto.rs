@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+use super::personal_access_token_response_dto::PersonalAccessTokenResponseFields;
+use crate::repositories::personal_access_tokens_repository::PersonalAccessToken;
+
+/// The Multiple Personal Access Tokens API Response format, lists tokens
+/// inside a `tokens` field.
+#[derive(Serialize)]
+pub struct MultiplePersonalAccessTokensResponseDto<'a> {
+    tokens: Vec<PersonalAccessTokenResponseFields<'a>>,
+}
+
+impl<'a> MultiplePersonalAccessTokensResponseDto<'a> {
+    /// Constructs a new [`MultiplePersonalAccessTokensResponseDto`] from a
+    /// list of tokens.
+    pub fn new(tokens: &'a [PersonalAccessToken]) -> Self {
+        Self {
+            tokens: tokens
+                .iter()
+                .map(PersonalAccessTokenResponseFields::from)
+                .collect(),
+        }
+    }
+}
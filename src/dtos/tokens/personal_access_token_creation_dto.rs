@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+/// The JSON payload model received for a personal access token creation.
+#[derive(Deserialize)]
+pub struct PersonalAccessTokenCreationDto {
+    pub token: PersonalAccessTokenCreationFields,
+}
+
+/// The token fields. The `scopes` and the expiration date are optional.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalAccessTokenCreationFields {
+    pub name: String,
+    pub scopes: Option<Vec<String>>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub expires_at: Option<OffsetDateTime>,
+}
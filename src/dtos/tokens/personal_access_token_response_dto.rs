@@ -0,0 +1,54 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::repositories::personal_access_tokens_repository::PersonalAccessToken;
+
+/// The Personal Access Token API Response format, encapsulates token
+/// information inside a `token` field.
+#[derive(Serialize)]
+pub struct PersonalAccessTokenResponseDto<'a> {
+    token: PersonalAccessTokenResponseFields<'a>,
+}
+
+/// The token fields. The token itself is only present upon creation.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalAccessTokenResponseFields<'a> {
+    id: i32,
+    name: &'a str,
+    scopes: &'a [String],
+    #[serde(with = "time::serde::rfc3339::option")]
+    expires_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    last_used_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<&'a str>,
+}
+
+impl<'a> From<&'a PersonalAccessToken> for PersonalAccessTokenResponseFields<'a> {
+    /// Builds the token fields from a token as stored in the database.
+    fn from(token: &'a PersonalAccessToken) -> Self {
+        Self {
+            id: token.id,
+            name: &token.name,
+            scopes: &token.scopes,
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            created_at: token.created_at,
+            token: None,
+        }
+    }
+}
+
+impl<'a> PersonalAccessTokenResponseDto<'a> {
+    /// Constructs a new [`PersonalAccessTokenResponseDto`] for a newly created
+    /// token, including the token itself.
+    pub fn created(stored: &'a PersonalAccessToken, token: &'a str) -> Self {
+        let mut fields = PersonalAccessTokenResponseFields::from(stored);
+        fields.token = Some(token);
+
+        Self { token: fields }
+    }
+}
@@ -0,0 +1,32 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+/// The response to the first step of a login when two-factor authentication
+/// is enabled: the challenge token to send along with a code to complete the
+/// login.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginChallengeResponseDto<'a> {
+    two_factor: LoginChallengeFields<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginChallengeFields<'a> {
+    challenge_token: &'a str,
+    #[serde(with = "time::serde::rfc3339")]
+    expires_at: OffsetDateTime,
+}
+
+impl<'a> LoginChallengeResponseDto<'a> {
+    /// Constructs a new [`LoginChallengeResponseDto`] with the given
+    /// challenge token and its expiration date.
+    pub fn new(challenge_token: &'a str, expires_at: OffsetDateTime) -> Self {
+        Self {
+            two_factor: LoginChallengeFields {
+                challenge_token,
+                expires_at,
+            },
+        }
+    }
+}
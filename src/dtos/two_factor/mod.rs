@@ -0,0 +1,11 @@
+pub mod login_challenge_response_dto;
+pub mod recovery_codes_response_dto;
+pub mod two_factor_code_dto;
+pub mod two_factor_enrollment_response_dto;
+pub mod two_factor_login_dto;
+
+pub use login_challenge_response_dto::LoginChallengeResponseDto;
+pub use recovery_codes_response_dto::RecoveryCodesResponseDto;
+pub use two_factor_code_dto::TwoFactorCodeDto;
+pub use two_factor_enrollment_response_dto::TwoFactorEnrollmentResponseDto;
+pub use two_factor_login_dto::TwoFactorLoginDto;
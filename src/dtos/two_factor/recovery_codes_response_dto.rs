@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+/// The response to a two-factor authentication confirmation: the recovery
+/// codes, only sent this time.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryCodesResponseDto<'a> {
+    two_factor: RecoveryCodesFields<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecoveryCodesFields<'a> {
+    recovery_codes: &'a [String],
+}
+
+impl<'a> RecoveryCodesResponseDto<'a> {
+    /// Constructs a new [`RecoveryCodesResponseDto`] with the given codes.
+    pub fn new(recovery_codes: &'a [String]) -> Self {
+        Self {
+            two_factor: RecoveryCodesFields { recovery_codes },
+        }
+    }
+}
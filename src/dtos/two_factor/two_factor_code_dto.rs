@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for confirming or disabling two-factor
+/// authentication: a code of the authenticator app (or a recovery code).
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorCodeDto {
+    pub two_factor: TwoFactorCodeFields,
+}
+
+#[derive(Deserialize)]
+pub struct TwoFactorCodeFields {
+    pub code: String,
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+/// The response to a two-factor authentication enrollment: the secret to
+/// register in an authenticator app, also as an `otpauth://` URI.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorEnrollmentResponseDto<'a> {
+    two_factor: TwoFactorEnrollmentFields<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TwoFactorEnrollmentFields<'a> {
+    secret: &'a str,
+    otpauth_uri: &'a str,
+}
+
+impl<'a> TwoFactorEnrollmentResponseDto<'a> {
+    /// Constructs a new [`TwoFactorEnrollmentResponseDto`] with the given
+    /// secret and URI.
+    pub fn new(secret: &'a str, otpauth_uri: &'a str) -> Self {
+        Self {
+            two_factor: TwoFactorEnrollmentFields {
+                secret,
+                otpauth_uri,
+            },
+        }
+    }
+}
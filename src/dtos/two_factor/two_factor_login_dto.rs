@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for the second step of a login: the
+/// challenge token obtained upon the first step, and a code of the
+/// authenticator app (or a recovery code).
+#[derive(Deserialize)]
+pub struct TwoFactorLoginDto {
+    pub user: TwoFactorLoginFields,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorLoginFields {
+    pub challenge_token: String,
+    pub code: String,
+}
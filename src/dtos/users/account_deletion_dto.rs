@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for deleting the account of the
+/// authenticated user: its password, and a code of its authenticator app (or
+/// a recovery code) if two-factor authentication is enabled.
+#[derive(Deserialize)]
+pub struct AccountDeletionDto {
+    pub user: AccountDeletionFields,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDeletionFields {
+    pub password: String,
+    pub two_factor_code: Option<String>,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for verifying an email address.
+#[derive(Deserialize)]
+pub struct EmailVerificationDto {
+    pub user: EmailVerificationFields,
+}
+
+#[derive(Deserialize)]
+pub struct EmailVerificationFields {
+    pub token: String,
+}
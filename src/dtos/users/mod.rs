@@ -1,8 +1,20 @@
+pub mod account_deletion_dto;
+pub mod email_verification_dto;
+pub mod password_forgot_dto;
+pub mod password_reset_dto;
+pub mod token_refresh_dto;
+pub mod user_export_response_dto;
 pub mod user_login_dto;
 pub mod user_registration_dto;
 pub mod user_response_dto;
 pub mod user_update_dto;
 
+pub use account_deletion_dto::AccountDeletionDto;
+pub use email_verification_dto::EmailVerificationDto;
+pub use password_forgot_dto::PasswordForgotDto;
+pub use password_reset_dto::PasswordResetDto;
+pub use token_refresh_dto::TokenRefreshDto;
+pub use user_export_response_dto::UserExportResponseDto;
 pub use user_login_dto::UserLoginDto;
 pub use user_registration_dto::UserRegistrationDto;
 pub use user_response_dto::UserResponseDto;
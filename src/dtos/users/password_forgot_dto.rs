@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for requesting a password reset.
+#[derive(Deserialize)]
+pub struct PasswordForgotDto {
+    pub user: PasswordForgotFields,
+}
+
+#[derive(Deserialize)]
+pub struct PasswordForgotFields {
+    pub email: String,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for resetting a password with a reset
+/// token.
+#[derive(Deserialize)]
+pub struct PasswordResetDto {
+    pub user: PasswordResetFields,
+}
+
+#[derive(Deserialize)]
+pub struct PasswordResetFields {
+    pub token: String,
+    pub password: String,
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+/// The JSON payload model received for refreshing an access token.
+#[derive(Deserialize)]
+pub struct TokenRefreshDto {
+    pub user: TokenRefreshFields,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenRefreshFields {
+    pub refresh_token: String,
+}
@@ -0,0 +1,182 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    dtos::tokens::personal_access_token_response_dto::PersonalAccessTokenResponseFields,
+    repositories::{
+        login_attempts_repository::LoginAttempts,
+        user_export_repository::{ExportedArticle, ExportedComment, ExportedSession, UserExport},
+    },
+};
+
+/// The User Export API Response format: everything stored about the user
+/// inside an `export` field.
+#[derive(Serialize)]
+pub struct UserExportResponseDto<'a> {
+    export: UserExportFields<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserExportFields<'a> {
+    #[serde(with = "time::serde::rfc3339")]
+    exported_at: OffsetDateTime,
+    user: ExportedUserFields<'a>,
+    following: &'a [String],
+    followers: &'a [String],
+    blocks: &'a [String],
+    follow_requests_sent: &'a [String],
+    follow_requests_received: &'a [String],
+    articles: Vec<ExportedArticleFields<'a>>,
+    comments: Vec<ExportedCommentFields<'a>>,
+    favorites: &'a [String],
+    personal_access_tokens: Vec<PersonalAccessTokenResponseFields<'a>>,
+    sessions: Vec<ExportedSessionFields>,
+    login_attempts: Option<ExportedLoginAttemptsFields>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedUserFields<'a> {
+    username: &'a str,
+    email: &'a str,
+    email_verified: bool,
+    pending_email: Option<&'a str>,
+    bio: Option<&'a str>,
+    image: Option<&'a str>,
+    private: bool,
+    two_factor_enabled: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedArticleFields<'a> {
+    slug: &'a str,
+    title: &'a str,
+    description: &'a str,
+    body: &'a str,
+    tag_list: &'a [String],
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedCommentFields<'a> {
+    id: i32,
+    article_slug: &'a str,
+    body: &'a str,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedSessionFields {
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    expires_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    used_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    revoked_at: Option<OffsetDateTime>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedLoginAttemptsFields {
+    failed_attempts: i32,
+    #[serde(with = "time::serde::rfc3339")]
+    last_failure_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    locked_until: Option<OffsetDateTime>,
+}
+
+impl<'a> From<&'a ExportedArticle> for ExportedArticleFields<'a> {
+    fn from(article: &'a ExportedArticle) -> Self {
+        Self {
+            slug: &article.slug,
+            title: &article.title,
+            description: &article.description,
+            body: &article.body,
+            tag_list: &article.tag_list,
+            created_at: article.created_at,
+            updated_at: article.updated_at,
+        }
+    }
+}
+
+impl<'a> From<&'a ExportedComment> for ExportedCommentFields<'a> {
+    fn from(comment: &'a ExportedComment) -> Self {
+        Self {
+            id: comment.id,
+            article_slug: &comment.article_slug,
+            body: &comment.body,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+        }
+    }
+}
+
+impl From<&ExportedSession> for ExportedSessionFields {
+    fn from(session: &ExportedSession) -> Self {
+        Self {
+            created_at: session.created_at,
+            expires_at: session.expires_at,
+            used_at: session.used_at,
+            revoked_at: session.revoked_at,
+        }
+    }
+}
+
+impl From<&LoginAttempts> for ExportedLoginAttemptsFields {
+    fn from(attempts: &LoginAttempts) -> Self {
+        Self {
+            failed_attempts: attempts.failed_attempts,
+            last_failure_at: attempts.last_failure_at,
+            locked_until: attempts.locked_until,
+        }
+    }
+}
+
+impl<'a> UserExportResponseDto<'a> {
+    /// Constructs a new [`UserExportResponseDto`] from everything stored about
+    /// an user, exported at the given date.
+    pub fn new(export: &'a UserExport, exported_at: OffsetDateTime) -> Self {
+        Self {
+            export: UserExportFields {
+                exported_at,
+                user: ExportedUserFields {
+                    username: &export.user.username,
+                    email: &export.user.email,
+                    email_verified: export.user.email_verified,
+                    pending_email: export.pending_email.as_deref(),
+                    bio: export.user.bio.as_deref(),
+                    image: export.user.image.as_deref(),
+                    private: export.user.private,
+                    two_factor_enabled: export.two_factor_enabled,
+                },
+                following: &export.following,
+                followers: &export.followers,
+                blocks: &export.blocks,
+                follow_requests_sent: &export.follow_requests_sent,
+                follow_requests_received: &export.follow_requests_received,
+                articles: export.articles.iter().map(Into::into).collect(),
+                comments: export.comments.iter().map(Into::into).collect(),
+                favorites: &export.favorites,
+                personal_access_tokens: export
+                    .personal_access_tokens
+                    .iter()
+                    .map(Into::into)
+                    .collect(),
+                sessions: export.sessions.iter().map(Into::into).collect(),
+                login_attempts: export.login_attempts.as_ref().map(Into::into),
+            },
+        }
+    }
+}
@@ -9,14 +9,20 @@ pub struct UserResponseDto<'a> {
 
 /// The user fields. We accept `bio` and `image` to be [`None`]
 /// (translated to `null` in JSON) as they have not a default value on
-/// registration.
+/// registration. The refresh token is only present upon authentication, and
+/// the `private` flag only when reading or updating the user.
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 struct UserResponseFields<'a> {
     username: &'a str,
     email: &'a str,
     bio: Option<&'a str>,
     image: Option<&'a str>,
     token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
 }
 
 impl<'a> UserResponseDto<'a> {
@@ -36,7 +42,21 @@ impl<'a> UserResponseDto<'a> {
                 bio,
                 image,
                 token,
+                refresh_token: None,
+                private: None,
             },
         }
     }
+
+    /// Adds the given refresh token to the response.
+    pub fn with_refresh_token(mut self, refresh_token: &'a str) -> Self {
+        self.user.refresh_token = Some(refresh_token);
+        self
+    }
+
+    /// Adds whether the user account is private to the response.
+    pub fn with_private(mut self, private: bool) -> Self {
+        self.user.private = Some(private);
+        self
+    }
 }
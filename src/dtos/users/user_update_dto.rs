@@ -13,4 +13,5 @@ pub struct UserUpdateFields {
     pub password: Option<String>,
     pub bio: Option<String>,
     pub image: Option<String>,
+    pub private: Option<bool>,
 }
@@ -0,0 +1,27 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError, dtos::articles::ArticleResponseDto, middlewares,
+    repositories::articles_repository::get_article_by_slug,
+};
+
+/// The `GET /api/articles/:slug` endpoint.
+/// Returns 200 with the article if it is found (the presence of the author's
+/// `following` field depends on authentication).
+/// Returns 404 if the article is not found (or if its author has blocked the
+/// viewer).
+#[get("/{slug}")]
+async fn get_article(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::MaybeAuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let viewer = user.inner().as_ref().map(|u| u.user.id);
+
+    let article = get_article_by_slug(&pool, &slug, viewer)
+        .await
+        .map_err(AppError::when_row_not_found("Article not found."))?;
+
+    Ok(HttpResponse::Ok().json(ArticleResponseDto::from(&article)))
+}
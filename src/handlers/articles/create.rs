@@ -0,0 +1,38 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{articles::NewArticle, error::AppError},
+    dtos::articles::{ArticleCreationDto, ArticleResponseDto},
+    middlewares,
+    repositories::articles_repository::{get_article_by_slug, insert_new_article},
+};
+
+/// The `POST /api/articles` endpoint. **Requires authentication.**
+/// Return 201 Created with the new article in case of success.
+/// Return 422 if the input is invalid or if an article with the same slug
+/// already exists.
+#[post("")]
+async fn create_article(
+    pool: web::Data<PgPool>,
+    user: middlewares::AuthenticatedUser,
+    article: web::Json<ArticleCreationDto>,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let new_article: NewArticle = article
+        .into_inner()
+        .try_into()
+        .map_err(AppError::InvalidFields)?;
+
+    // Store the article
+    insert_new_article(&pool, user.user.id, &new_article)
+        .await
+        .map_err(AppError::when_database_error(
+            "Unable to create the article. An article with a similar title might already exist.",
+        ))?;
+
+    // Get the stored article and respond
+    let article = get_article_by_slug(&pool, new_article.slug.as_ref(), Some(user.user.id)).await?;
+
+    Ok(HttpResponse::Created().json(ArticleResponseDto::from(&article)))
+}
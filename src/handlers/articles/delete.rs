@@ -0,0 +1,33 @@
+use actix_web::{delete, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError,
+    middlewares,
+    repositories::articles_repository::{delete_article_by_slug, get_article_author_id},
+};
+
+/// The `DELETE /api/articles/:slug` endpoint. **Requires authentication.**
+/// Returns 200 upon success.
+/// Returns 403 if the authenticated user is not the author of the article.
+/// Returns 404 if the article is not found.
+#[delete("/{slug}")]
+async fn delete_article(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Only the author can delete its article
+    let author_id = get_article_author_id(&pool, &slug)
+        .await
+        .map_err(AppError::when_row_not_found("Article not found."))?;
+    if author_id != user.user.id {
+        return Err(AppError::Forbidden(
+            "You are not the author of this article.".into(),
+        ));
+    }
+
+    delete_article_by_slug(&pool, &slug).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -0,0 +1,36 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError,
+    dtos::articles::ArticleResponseDto,
+    handlers::articles::check_visible_article,
+    middlewares,
+    repositories::{articles_repository::get_article_by_slug, favorites_repository::favorite},
+};
+
+/// The `POST /api/articles/:slug/favorite` endpoint. **Requires
+/// authentication.**
+/// Returns 200 with the favorited article upon success.
+/// Returns 404 if the article to favorite is not found (or if its author has
+/// blocked the user).
+/// Returns 422 if the article is already favorited.
+#[post("/{slug}/favorite")]
+async fn favorite_article(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Check the article exists and is visible
+    check_visible_article(&pool, &slug, Some(user.user.id)).await?;
+
+    favorite(&pool, user.user.id, &slug)
+        .await
+        .map_err(AppError::when_database_error(
+            "Unable to favorite. You might already favorite this article.",
+        ))?;
+
+    let article = get_article_by_slug(&pool, &slug, Some(user.user.id)).await?;
+
+    Ok(HttpResponse::Ok().json(ArticleResponseDto::from(&article)))
+}
@@ -0,0 +1,29 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{error::AppError, pagination::Pagination},
+    dtos::{articles::MultipleArticlesResponseDto, pagination_dto::PaginationDto},
+    middlewares,
+    repositories::articles_repository::{count_feed_articles, list_feed_articles},
+};
+
+/// The `GET /api/articles/feed` endpoint. **Requires authentication.**
+/// Returns 200 with the most recent articles written by the users followed
+/// by the authenticated user, paginated with `limit` and `offset`.
+/// Returns 422 if the pagination parameters are invalid.
+#[get("/feed")]
+async fn feed(
+    pool: web::Data<PgPool>,
+    pagination: web::Query<PaginationDto>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let pagination =
+        Pagination::parse(pagination.limit, pagination.offset).map_err(AppError::Validation)?;
+
+    let articles = list_feed_articles(&pool, user.user.id, &pagination).await?;
+    let count = count_feed_articles(&pool, user.user.id).await?;
+
+    Ok(HttpResponse::Ok().json(MultipleArticlesResponseDto::new(&articles, count)))
+}
@@ -0,0 +1,39 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{articles::ArticlesFilter, error::AppError, pagination::Pagination},
+    dtos::{
+        articles::{ArticlesQueryDto, MultipleArticlesResponseDto},
+        pagination_dto::PaginationDto,
+    },
+    middlewares,
+    repositories::articles_repository::{count_articles, list_articles},
+};
+
+/// The `GET /api/articles` endpoint.
+/// Returns 200 with the most recent articles matching the optional `tag`,
+/// `author` and `favorited` filters, paginated with `limit` and `offset`
+/// (the presence of the authors' `following` field depends on
+/// authentication). The articles of the authors who have blocked the viewer
+/// are left out.
+/// Returns 422 if the pagination parameters are invalid.
+#[get("")]
+async fn list(
+    pool: web::Data<PgPool>,
+    query: web::Query<ArticlesQueryDto>,
+    pagination: web::Query<PaginationDto>,
+    user: middlewares::MaybeAuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let pagination =
+        Pagination::parse(pagination.limit, pagination.offset).map_err(AppError::Validation)?;
+    let filter = ArticlesFilter::from(query.into_inner());
+
+    let viewer = user.inner().as_ref().map(|u| u.user.id);
+
+    let articles = list_articles(&pool, &filter, &pagination, viewer).await?;
+    let count = count_articles(&pool, &filter, viewer).await?;
+
+    Ok(HttpResponse::Ok().json(MultipleArticlesResponseDto::new(&articles, count)))
+}
@@ -0,0 +1,64 @@
+//! The Articles request handlers are responsible for handling CRUD actions on
+//! the articles:
+//! - List articles (`GET /api/articles`) with the `list` module ;
+//! - Get the articles feed (`GET /api/articles/feed`) with the `feed` module ;
+//! - Create an article (`POST /api/articles`) with the `create` module ;
+//! - Get an article (`GET /api/articles/:slug`) with the `article` module ;
+//! - Update an article (`PUT /api/articles/:slug`) with the `update` module ;
+//! - Delete an article (`DELETE /api/articles/:slug`) with the `delete`
+//!   module ;
+//! - Favorite an article (`POST /api/articles/:slug/favorite`) with the
+//!   `favorite` module ;
+//! - Unfavorite an article (`DELETE /api/articles/:slug/favorite`) with the
+//!   `unfavorite` module.
+
+use actix_web::web;
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError,
+    repositories::{articles_repository::get_article_author_id, blocks_repository::is_blocking},
+};
+
+pub mod article;
+pub mod create;
+pub mod delete;
+pub mod favorite;
+pub mod feed;
+pub mod list;
+pub mod unfavorite;
+pub mod update;
+
+/// Configure the Articles service (`/api/articles/...` endpoints).
+pub fn config_articles(cfg: &mut web::ServiceConfig) {
+    cfg.service(list::list);
+    // Must be registered before `GET /api/articles/:slug`
+    cfg.service(feed::feed);
+    cfg.service(create::create_article);
+    cfg.service(article::get_article);
+    cfg.service(update::update_article);
+    cfg.service(delete::delete_article);
+    cfg.service(favorite::favorite_article);
+    cfg.service(unfavorite::unfavorite_article);
+}
+
+/// Checks that the article with the given slug is visible to the viewer (given
+/// its ID, if any). Returns a [`AppError::NotFound`] if the article does not
+/// exist, or if its author has blocked the viewer.
+pub(crate) async fn check_visible_article(
+    pool: &PgPool,
+    slug: &str,
+    viewer: Option<i32>,
+) -> Result<(), AppError> {
+    let author_id = get_article_author_id(pool, slug)
+        .await
+        .map_err(AppError::when_row_not_found("Article not found."))?;
+
+    if let Some(viewer) = viewer {
+        if is_blocking(pool, author_id, viewer).await? {
+            return Err(AppError::NotFound("Article not found.".into()));
+        }
+    }
+
+    Ok(())
+}
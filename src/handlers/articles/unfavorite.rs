@@ -0,0 +1,32 @@
+use actix_web::{delete, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError,
+    dtos::articles::ArticleResponseDto,
+    handlers::articles::check_visible_article,
+    middlewares,
+    repositories::{articles_repository::get_article_by_slug, favorites_repository::unfavorite},
+};
+
+/// The `DELETE /api/articles/:slug/favorite` endpoint. **Requires
+/// authentication.**
+/// Returns 200 with the unfavorited article upon success.
+/// Returns 404 if the article to unfavorite is not found (or if its author has
+/// blocked the user).
+/// Unfavoriting an article you did not favorite does not trigger an error.
+#[delete("/{slug}/favorite")]
+async fn unfavorite_article(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Check the article exists and is visible
+    check_visible_article(&pool, &slug, Some(user.user.id)).await?;
+
+    unfavorite(&pool, user.user.id, &slug).await?;
+
+    let article = get_article_by_slug(&pool, &slug, Some(user.user.id)).await?;
+
+    Ok(HttpResponse::Ok().json(ArticleResponseDto::from(&article)))
+}
@@ -0,0 +1,56 @@
+use actix_web::{put, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{articles::ArticleUpdateRequest, error::AppError},
+    dtos::articles::{ArticleResponseDto, ArticleUpdateDto},
+    middlewares,
+    repositories::articles_repository::{
+        get_article_author_id, get_article_by_slug, update_article_by_slug,
+    },
+};
+
+/// The `PUT /api/articles/:slug` endpoint. **Requires authentication.**
+/// Returns 200 with the updated article upon success.
+/// Returns 403 if the authenticated user is not the author of the article.
+/// Returns 404 if the article is not found.
+/// Returns 422 if the input is invalid or if the new title conflicts with
+/// another article.
+#[put("/{slug}")]
+async fn update_article(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::AuthenticatedUser,
+    update: web::Json<ArticleUpdateDto>,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let updated_article: ArticleUpdateRequest = update
+        .into_inner()
+        .try_into()
+        .map_err(AppError::InvalidFields)?;
+
+    if updated_article.is_all_none() {
+        return Err(AppError::Validation("No update provided!".into()));
+    }
+
+    // Only the author can update its article
+    let author_id = get_article_author_id(&pool, &slug)
+        .await
+        .map_err(AppError::when_row_not_found("Article not found."))?;
+    if author_id != user.user.id {
+        return Err(AppError::Forbidden(
+            "You are not the author of this article.".into(),
+        ));
+    }
+
+    // Update in the database and respond
+    let new_slug = update_article_by_slug(&pool, &slug, &updated_article)
+        .await
+        .map_err(AppError::when_database_error(
+            "Unable to update the article. An article with a similar title might already exist.",
+        ))?;
+
+    let article = get_article_by_slug(&pool, &new_slug, Some(user.user.id)).await?;
+
+    Ok(HttpResponse::Ok().json(ArticleResponseDto::from(&article)))
+}
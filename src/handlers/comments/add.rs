@@ -0,0 +1,43 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{comments::NewComment, error::AppError},
+    dtos::comments::{CommentCreationDto, CommentResponseDto},
+    handlers::articles::check_visible_article,
+    middlewares,
+    repositories::comments_repository::{get_comment_by_id, insert_new_comment},
+};
+
+/// The `POST /api/articles/:slug/comments` endpoint. **Requires
+/// authentication.**
+/// Returns 200 with the new comment upon success.
+/// Returns 404 if the article is not found (or if its author has blocked the
+/// user).
+/// Returns 422 if the input is invalid.
+#[post("/{slug}/comments")]
+async fn add_comment(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::AuthenticatedUser,
+    comment: web::Json<CommentCreationDto>,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let new_comment: NewComment = comment
+        .into_inner()
+        .try_into()
+        .map_err(AppError::Validation)?;
+
+    // Check the article is visible
+    check_visible_article(&pool, &slug, Some(user.user.id)).await?;
+
+    // Store the comment
+    let id = insert_new_comment(&pool, &slug, user.user.id, &new_comment)
+        .await
+        .map_err(AppError::when_row_not_found("Article not found."))?;
+
+    // Get the stored comment and respond
+    let comment = get_comment_by_id(&pool, id, Some(user.user.id)).await?;
+
+    Ok(HttpResponse::Ok().json(CommentResponseDto::from(&comment)))
+}
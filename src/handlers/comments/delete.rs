@@ -0,0 +1,36 @@
+use actix_web::{delete, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError,
+    middlewares,
+    repositories::comments_repository::{delete_comment_by_id, get_comment_author_id},
+};
+
+/// The `DELETE /api/articles/:slug/comments/:id` endpoint. **Requires
+/// authentication.**
+/// Returns 200 upon success.
+/// Returns 403 if the authenticated user is not the author of the comment.
+/// Returns 404 if the comment is not found on this article.
+#[delete("/{slug}/comments/{id}")]
+async fn delete_comment(
+    pool: web::Data<PgPool>,
+    path: web::Path<(String, i32)>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (slug, id) = path.into_inner();
+
+    // Only the author can delete its comment
+    let author_id = get_comment_author_id(&pool, &slug, id)
+        .await
+        .map_err(AppError::when_row_not_found("Comment not found."))?;
+    if author_id != user.user.id {
+        return Err(AppError::Forbidden(
+            "You are not the author of this comment.".into(),
+        ));
+    }
+
+    delete_comment_by_id(&pool, id).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
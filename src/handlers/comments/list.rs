@@ -0,0 +1,30 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError, dtos::comments::MultipleCommentsResponseDto,
+    handlers::articles::check_visible_article, middlewares,
+    repositories::comments_repository::list_comments_by_slug,
+};
+
+/// The `GET /api/articles/:slug/comments` endpoint.
+/// Returns 200 with the comments of the article (the presence of the authors'
+/// `following` field depends on authentication).
+/// The comments of the users who have blocked the viewer are left out.
+/// Returns 404 if the article is not found (or if its author has blocked the
+/// viewer).
+#[get("/{slug}/comments")]
+async fn list_comments(
+    pool: web::Data<PgPool>,
+    slug: web::Path<String>,
+    user: middlewares::MaybeAuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let viewer = user.inner().as_ref().map(|u| u.user.id);
+
+    // Check the article exists and is visible
+    check_visible_article(&pool, &slug, viewer).await?;
+
+    let comments = list_comments_by_slug(&pool, &slug, viewer).await?;
+
+    Ok(HttpResponse::Ok().json(MultipleCommentsResponseDto::new(&comments)))
+}
@@ -0,0 +1,22 @@
+//! The Comments request handlers are responsible for handling CR_D actions on
+//! the comments of an article:
+//! - Add a comment to an article (`POST /api/articles/:slug/comments`) with
+//!   the `add` module ;
+//! - Get the comments of an article (`GET /api/articles/:slug/comments`) with
+//!   the `list` module ;
+//! - Delete a comment (`DELETE /api/articles/:slug/comments/:id`) with the
+//!   `delete` module.
+
+use actix_web::web;
+
+pub mod add;
+pub mod delete;
+pub mod list;
+
+/// Configure the Comments service (`/api/articles/:slug/comments/...`
+/// endpoints).
+pub fn config_comments(cfg: &mut web::ServiceConfig) {
+    cfg.service(add::add_comment);
+    cfg.service(list::list_comments);
+    cfg.service(delete::delete_comment);
+}
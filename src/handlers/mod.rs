@@ -3,9 +3,15 @@
 
 use actix_web::web;
 
+pub mod articles;
+pub mod comments;
 pub mod health_check;
 pub mod profiles;
+pub mod tags;
+pub mod tokens;
+pub mod two_factor;
 pub mod users;
+pub mod well_known;
 
 /// Configure the services for Conduit, not including the `/api`
 /// prefix, prepended by the application factory.
@@ -13,6 +19,15 @@ pub mod users;
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(health_check::health_check);
     cfg.service(web::scope("/users").configure(users::config_users));
+    // Registered before `/user`, which would match first otherwise
+    cfg.service(web::scope("/user/tokens").configure(tokens::config_tokens));
+    cfg.service(web::scope("/user/2fa").configure(two_factor::config_two_factor));
     cfg.service(web::scope("/user").configure(users::config_user));
     cfg.service(web::scope("/profiles").configure(profiles::config_profiles));
+    cfg.service(
+        web::scope("/articles")
+            .configure(articles::config_articles)
+            .configure(comments::config_comments),
+    );
+    cfg.service(web::scope("/tags").configure(tags::config_tags));
 }
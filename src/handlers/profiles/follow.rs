@@ -1,48 +1,70 @@
-use std::ops::Deref;
-
 use actix_web::{post, web, HttpResponse};
 use sqlx::PgPool;
 
+use super::profile::get_visible_profile;
 use crate::{
-    domain::error::{validation_error, ErrorResponse},
+    domain::error::AppError,
     dtos::profiles::profile_response_dto::ProfileResponseDto,
     middlewares,
-    repositories::{followers_repository::follow, user_repository::get_user_by_username},
+    repositories::{
+        blocks_repository::is_blocking,
+        follow_requests_repository::request_follow,
+        followers_repository::{follow, is_following},
+    },
 };
 
 /// The `POST /api/profiles/:username/follow` endpoint.
-/// Returns 200 with the followed profile upon success.
-/// Returns 404 if the user to follow is not found.
-/// Returns 422 in other cases (self-following/already-following).
+/// Returns 200 with the followed profile upon success. If the account is
+/// private, a follow request is made instead: it is `pending` until approved.
+/// Returns 403 if the authenticated user has blocked the user to follow.
+/// Returns 404 if the user to follow is not found, or has blocked the
+/// authenticated user.
+/// Returns 422 in other cases (self-following/already-following/already
+/// requested).
 #[post("/{username}/follow")]
 async fn follow_user(
     pool: web::Data<PgPool>,
     username: web::Path<String>,
     user: middlewares::AuthenticatedUser,
-) -> HttpResponse {
+) -> Result<HttpResponse, AppError> {
     // Retrieve profile
-    let profile = match get_user_by_username(&pool, &username).await {
-        Ok(user) => user,
-        Err(_) => return HttpResponse::NotFound().json(ErrorResponse::new("User not found.")),
-    };
+    let profile = get_visible_profile(&pool, &username, Some(user.user.id)).await?;
 
     // Check the users are different
-    if username.deref() == &user.user.username {
-        return validation_error("Cannot follow yourself!");
+    if profile.id == user.user.id {
+        return Err(AppError::Validation("Cannot follow yourself!".into()));
+    }
+
+    if is_blocking(&pool, user.user.id, profile.id).await? {
+        return Err(AppError::Forbidden(
+            "Cannot follow an user you have blocked.".into(),
+        ));
     }
 
-    match follow(&pool, &user.user.username, &username).await {
-        Ok(_) => HttpResponse::Ok().json(ProfileResponseDto::new(
+    // Private accounts approve their followers
+    let pending = profile.private && !is_following(&pool, user.user.id, profile.id).await?;
+
+    if pending {
+        request_follow(&pool, user.user.id, profile.id)
+            .await
+            .map_err(AppError::when_database_error(
+                "Unable to follow. You might already have requested to follow this user.",
+            ))?;
+    } else {
+        follow(&pool, user.user.id, profile.id)
+            .await
+            .map_err(AppError::when_database_error(
+                "Unable to follow. You might already follow this user.",
+            ))?;
+    }
+
+    Ok(HttpResponse::Ok().json(
+        ProfileResponseDto::new(
             &username,
             profile.bio.as_deref(),
             profile.image.as_deref(),
-            Some(true),
-        )),
-        Err(e) => match e {
-            sqlx::Error::Database(_) => {
-                validation_error("Unable to follow. You might already follow this user.")
-            },
-            _ => HttpResponse::InternalServerError().body("Unexpected error happened."),
-        },
-    }
+            Some(!pending),
+        )
+        .with_pending(pending),
+    ))
 }
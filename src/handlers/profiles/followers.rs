@@ -0,0 +1,64 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use super::profile::get_visible_profile;
+use crate::{
+    domain::{
+        error::AppError,
+        pagination::{Cursor, CursorPagination},
+    },
+    dtos::{
+        pagination_dto::CursorPaginationDto,
+        profiles::multiple_profiles_response_dto::MultipleProfilesResponseDto,
+    },
+    middlewares,
+    repositories::followers_repository::{list_followers, ListedProfile},
+};
+
+/// The `GET /api/profiles/:username/followers` endpoint.
+/// Returns 200 with the most recent followers of the user, paginated with
+/// `limit` and `cursor` (the presence of their `following` field depends on
+/// authentication).
+/// Returns 404 if the user is not found, or has blocked the authenticated
+/// user.
+/// Returns 422 if the pagination parameters are invalid.
+#[get("/{username}/followers")]
+async fn get_followers(
+    pool: web::Data<PgPool>,
+    username: web::Path<String>,
+    pagination: web::Query<CursorPaginationDto>,
+    user: middlewares::MaybeAuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let pagination = CursorPagination::parse(pagination.limit, pagination.cursor.as_deref())
+        .map_err(AppError::Validation)?;
+
+    let viewer = user.inner().as_ref().map(|u| u.user.id);
+
+    let profile = get_visible_profile(&pool, &username, viewer).await?;
+
+    let mut profiles = list_followers(&pool, profile.id, viewer, &pagination).await?;
+    let next_cursor = next_page_cursor(&mut profiles, &pagination);
+
+    Ok(HttpResponse::Ok().json(MultipleProfilesResponseDto::new(&profiles, next_cursor)))
+}
+
+/// Trims the extra profile fetched beyond the limit of the page, if any, and
+/// returns the cursor of the next page in that case.
+pub(crate) fn next_page_cursor(
+    profiles: &mut Vec<ListedProfile>,
+    pagination: &CursorPagination,
+) -> Option<String> {
+    if profiles.len() as i64 <= pagination.limit {
+        return None;
+    }
+
+    profiles.truncate(pagination.limit as usize);
+    profiles.last().map(|last| {
+        Cursor {
+            date: last.listed_at,
+            id: last.id,
+        }
+        .encode()
+    })
+}
@@ -0,0 +1,41 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use super::{followers::next_page_cursor, profile::get_visible_profile};
+use crate::{
+    domain::{error::AppError, pagination::CursorPagination},
+    dtos::{
+        pagination_dto::CursorPaginationDto,
+        profiles::multiple_profiles_response_dto::MultipleProfilesResponseDto,
+    },
+    middlewares,
+    repositories::followers_repository::list_following,
+};
+
+/// The `GET /api/profiles/:username/following` endpoint.
+/// Returns 200 with the users most recently followed by the user, paginated
+/// with `limit` and `cursor` (the presence of their `following` field depends
+/// on authentication).
+/// Returns 404 if the user is not found, or has blocked the authenticated
+/// user.
+/// Returns 422 if the pagination parameters are invalid.
+#[get("/{username}/following")]
+async fn get_following(
+    pool: web::Data<PgPool>,
+    username: web::Path<String>,
+    pagination: web::Query<CursorPaginationDto>,
+    user: middlewares::MaybeAuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let pagination = CursorPagination::parse(pagination.limit, pagination.cursor.as_deref())
+        .map_err(AppError::Validation)?;
+
+    let viewer = user.inner().as_ref().map(|u| u.user.id);
+
+    let profile = get_visible_profile(&pool, &username, viewer).await?;
+
+    let mut profiles = list_following(&pool, profile.id, viewer, &pagination).await?;
+    let next_cursor = next_page_cursor(&mut profiles, &pagination);
+
+    Ok(HttpResponse::Ok().json(MultipleProfilesResponseDto::new(&profiles, next_cursor)))
+}
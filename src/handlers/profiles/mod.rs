@@ -6,11 +6,23 @@
 //!   `follow` module ;
 //! - Unfollow an user profile (`DELETE /api/profiles/:username/follow`) with
 //!   the `unfollow` module ;
+//! - List the followers of an user (`GET /api/profiles/:username/followers`)
+//!   with the `followers` module ;
+//! - List the users followed by an user
+//!   (`GET /api/profiles/:username/following`) with the `following` module ;
+//! - Block an user profile (`POST /api/profiles/:username/block`) with the
+//!   `block` module ;
+//! - Unblock an user profile (`DELETE /api/profiles/:username/block`) with
+//!   the `unblock` module ;
 
 use actix_web::web;
 
+pub mod block;
 pub mod follow;
+pub mod followers;
+pub mod following;
 pub mod profile;
+pub mod unblock;
 pub mod unfollow;
 
 /// Configure the Profiles service (`/api/profiles/...` endpoints).
@@ -18,4 +30,8 @@ pub fn config_profiles(cfg: &mut web::ServiceConfig) {
     cfg.service(profile::get_profile);
     cfg.service(follow::follow_user);
     cfg.service(unfollow::unfollow_user);
+    cfg.service(followers::get_followers);
+    cfg.service(following::get_following);
+    cfg.service(block::block_user);
+    cfg.service(unblock::unblock_user);
 }
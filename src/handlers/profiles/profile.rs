@@ -2,46 +2,76 @@ use actix_web::{get, web, HttpResponse};
 use sqlx::PgPool;
 
 use crate::{
-    domain::error::ErrorResponse,
+    domain::error::AppError,
     dtos::profiles::profile_response_dto::ProfileResponseDto,
     middlewares,
-    repositories::{followers_repository::is_following, user_repository::get_user_by_username},
+    repositories::{
+        blocks_repository::is_blocking,
+        follow_requests_repository::is_follow_pending,
+        followers_repository::{count_follows, is_following},
+        user_repository::{get_user_by_username, User},
+    },
 };
 
 /// The `GET /api/profiles/:username` endpoint.
 /// Returns 200 with the profile if the user is found (the presence of the
-/// `following` field depends on authentication).
-/// Returns 404 if the user is not found.
+/// `following` and `pending` fields depends on authentication), along with
+/// its counts of followers and followed users.
+/// Returns 404 if the user is not found, or has blocked the authenticated
+/// user.
 #[get("/{username}")]
 async fn get_profile(
     pool: web::Data<PgPool>,
     username: web::Path<String>,
     user: middlewares::MaybeAuthenticatedUser,
-) -> HttpResponse {
+) -> Result<HttpResponse, AppError> {
+    let viewer = user.inner().as_ref().map(|u| u.user.id);
+
     // Retrieve profile
-    let profile = match get_user_by_username(&pool, &username).await {
-        Ok(user) => user,
-        Err(_) => return HttpResponse::NotFound().json(ErrorResponse::new("User not found.")),
+    let profile = get_visible_profile(&pool, &username, viewer).await?;
+
+    // Behave differently if authenticated: check if following (or pending)
+    let (following, pending) = match viewer {
+        None => (None, None),
+        Some(viewer) => (
+            Some(is_following(&pool, viewer, profile.id).await?),
+            Some(is_follow_pending(&pool, viewer, profile.id).await?),
+        ),
     };
 
-    // Behave differently if authenticated.
-    match user.inner() {
-        // Not authenticated
-        None => HttpResponse::Ok().json(ProfileResponseDto::new(
-            &profile.username,
-            profile.bio.as_deref(),
-            profile.image.as_deref(),
-            None,
-        )),
-        // Authenticated, check if following
-        Some(u) => match is_following(&pool, &u.user.username, &username).await {
-            Ok(following) => HttpResponse::Ok().json(ProfileResponseDto::new(
-                &profile.username,
-                profile.bio.as_deref(),
-                profile.image.as_deref(),
-                Some(following),
-            )),
-            Err(_) => HttpResponse::InternalServerError().body("Unexpected error happened."),
-        },
+    let (followers_count, following_count) = count_follows(&pool, profile.id).await?;
+
+    let response = ProfileResponseDto::new(
+        &profile.username,
+        profile.bio.as_deref(),
+        profile.image.as_deref(),
+        following,
+    )
+    .with_counts(followers_count, following_count);
+
+    Ok(HttpResponse::Ok().json(match pending {
+        Some(pending) => response.with_pending(pending),
+        None => response,
+    }))
+}
+
+/// Retrieves the user with the given username, as seen by the viewer (given its
+/// ID, if any). Returns a [`AppError::NotFound`] if the user does not exist, or
+/// if it has blocked the viewer.
+pub(crate) async fn get_visible_profile(
+    pool: &PgPool,
+    username: &str,
+    viewer: Option<i32>,
+) -> Result<User, AppError> {
+    let profile = get_user_by_username(pool, username)
+        .await
+        .map_err(AppError::when_row_not_found("User not found."))?;
+
+    if let Some(viewer) = viewer {
+        if is_blocking(pool, profile.id, viewer).await? {
+            return Err(AppError::NotFound("User not found.".into()));
+        }
     }
+
+    Ok(profile)
 }
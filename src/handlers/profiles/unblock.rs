@@ -0,0 +1,42 @@
+use actix_web::{delete, web, HttpResponse};
+use sqlx::PgPool;
+
+use super::profile::get_visible_profile;
+use crate::{
+    domain::error::AppError, dtos::profiles::profile_response_dto::ProfileResponseDto, middlewares,
+    repositories::blocks_repository::unblock,
+};
+
+/// The `DELETE /api/profiles/:username/block` endpoint.
+/// Returns 200 with the unblocked profile upon success (the former follow
+/// relationships are not restored).
+/// Returns 404 if the user to unblock is not found, or has blocked the
+/// authenticated user.
+/// Returns 422 in other cases (self-unblocking).
+/// Unblocking an user you have not blocked does not trigger an error.
+#[delete("/{username}/block")]
+async fn unblock_user(
+    pool: web::Data<PgPool>,
+    username: web::Path<String>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Retrieve profile
+    let profile = get_visible_profile(&pool, &username, Some(user.user.id)).await?;
+
+    // Check the users are different
+    if profile.id == user.user.id {
+        return Err(AppError::Validation("Cannot unblock yourself!".into()));
+    }
+
+    unblock(&pool, user.user.id, profile.id).await?;
+
+    Ok(HttpResponse::Ok().json(
+        ProfileResponseDto::new(
+            &username,
+            profile.bio.as_deref(),
+            profile.image.as_deref(),
+            Some(false),
+        )
+        .with_pending(false),
+    ))
+}
@@ -1,18 +1,21 @@
-use std::ops::Deref;
-
 use actix_web::{delete, web, HttpResponse};
 use sqlx::PgPool;
 
+use super::profile::get_visible_profile;
 use crate::{
-    domain::error::{validation_error, ErrorResponse},
+    domain::error::AppError,
     dtos::profiles::profile_response_dto::ProfileResponseDto,
     middlewares,
-    repositories::{followers_repository::unfollow, user_repository::get_user_by_username},
+    repositories::{
+        follow_requests_repository::cancel_follow_request, followers_repository::unfollow,
+    },
 };
 
 /// The `DELETE /api/profiles/:username/follow` endpoint.
-/// Returns 200 with the unfollowed profile upon success.
-/// Returns 404 if the user to follow is not found.
+/// Returns 200 with the unfollowed profile upon success. A pending follow
+/// request is cancelled as well.
+/// Returns 404 if the user to follow is not found, or has blocked the
+/// authenticated user.
 /// Returns 422 in other cases (self-unfollowing).
 /// Unfollowing an user you're not following does not trigger an error.
 #[delete("/{username}/follow")]
@@ -20,30 +23,30 @@ async fn unfollow_user(
     pool: web::Data<PgPool>,
     username: web::Path<String>,
     user: middlewares::AuthenticatedUser,
-) -> HttpResponse {
+) -> Result<HttpResponse, AppError> {
     // Retrieve profile
-    let profile = match get_user_by_username(&pool, &username).await {
-        Ok(user) => user,
-        Err(_) => return HttpResponse::NotFound().json(ErrorResponse::new("User not found.")),
-    };
+    let profile = get_visible_profile(&pool, &username, Some(user.user.id)).await?;
 
     // Check the users are different
-    if username.deref() == &user.user.username {
-        return validation_error("Cannot unfollow yourself!");
+    if profile.id == user.user.id {
+        return Err(AppError::Validation("Cannot unfollow yourself!".into()));
     }
 
-    match unfollow(&pool, &user.user.username, &username).await {
-        Ok(_) => HttpResponse::Ok().json(ProfileResponseDto::new(
+    unfollow(&pool, user.user.id, profile.id)
+        .await
+        .map_err(AppError::when_database_error(
+            "Unable to unfollow. You may not already be following this user.",
+        ))?;
+
+    cancel_follow_request(&pool, user.user.id, profile.id).await?;
+
+    Ok(HttpResponse::Ok().json(
+        ProfileResponseDto::new(
             &username,
             profile.bio.as_deref(),
             profile.image.as_deref(),
             Some(false),
-        )),
-        Err(e) => match e {
-            sqlx::Error::Database(_) => {
-                validation_error("Unable to unfollow. You may not already be following this user.")
-            },
-            _ => HttpResponse::InternalServerError().body("Unexpected error happened."),
-        },
-    }
+        )
+        .with_pending(false),
+    ))
 }
@@ -0,0 +1,15 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError, dtos::tags::TagsResponseDto, repositories::tags_repository::get_tags,
+};
+
+/// The `GET /api/tags` endpoint.
+/// Returns 200 with every tag attached to at least one article.
+#[get("")]
+async fn list_tags(pool: web::Data<PgPool>) -> Result<HttpResponse, AppError> {
+    let tags = get_tags(&pool).await?;
+
+    Ok(HttpResponse::Ok().json(TagsResponseDto::new(&tags)))
+}
@@ -0,0 +1,12 @@
+//! The Tags request handlers are responsible for handling _R__ actions on the
+//! tags:
+//! - Get the tags in use (`GET /api/tags`) with the `list` module.
+
+use actix_web::web;
+
+pub mod list;
+
+/// Configure the Tags service (`/api/tags` endpoints).
+pub fn config_tags(cfg: &mut web::ServiceConfig) {
+    cfg.service(list::list_tags);
+}
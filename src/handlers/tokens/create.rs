@@ -0,0 +1,45 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{auth::personal_access_token::NewPersonalAccessToken, error::AppError},
+    dtos::tokens::{PersonalAccessTokenCreationDto, PersonalAccessTokenResponseDto},
+    middlewares,
+    repositories::personal_access_tokens_repository::{
+        insert_personal_access_token, PERSONAL_ACCESS_TOKENS_UNIQUE_CONSTRAINTS,
+    },
+};
+
+/// The `POST /api/user/tokens` endpoint. **Requires authentication.**
+/// Returns 201 with the new token, which is only sent this time.
+/// Returns 403 if the user is authenticated with a personal access token.
+/// Returns 422 with the errors keyed by field name if the input is invalid or
+/// if the user already has a token with this name.
+#[post("")]
+async fn create_token(
+    pool: web::Data<PgPool>,
+    user: middlewares::AuthenticatedUser,
+    token: web::Json<PersonalAccessTokenCreationDto>,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    // Validate the input
+    let new_token: NewPersonalAccessToken = token
+        .into_inner()
+        .try_into()
+        .map_err(AppError::InvalidFields)?;
+
+    // Store the token and respond
+    let stored = insert_personal_access_token(&pool, user.user.id, &new_token)
+        .await
+        .map_err(AppError::when_unique_violation(
+            PERSONAL_ACCESS_TOKENS_UNIQUE_CONSTRAINTS,
+        ))?;
+
+    Ok(
+        HttpResponse::Created().json(PersonalAccessTokenResponseDto::created(
+            &stored,
+            &new_token.token,
+        )),
+    )
+}
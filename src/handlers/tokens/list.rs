@@ -0,0 +1,23 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError, dtos::tokens::MultiplePersonalAccessTokensResponseDto, middlewares,
+    repositories::personal_access_tokens_repository::list_user_personal_access_tokens,
+};
+
+/// The `GET /api/user/tokens` endpoint. **Requires authentication.**
+/// Returns 200 with the tokens of the user (without the tokens themselves),
+/// newest first.
+/// Returns 403 if the user is authenticated with a personal access token.
+#[get("")]
+async fn list_tokens(
+    pool: web::Data<PgPool>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    let tokens = list_user_personal_access_tokens(&pool, user.user.id).await?;
+
+    Ok(HttpResponse::Ok().json(MultiplePersonalAccessTokensResponseDto::new(&tokens)))
+}
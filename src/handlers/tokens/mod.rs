@@ -0,0 +1,23 @@
+//! The Personal Access Tokens request handlers are responsible for handling
+//! CR_D actions on the personal access tokens of the authenticated user:
+//! - Create a token (`POST /api/user/tokens`) with the `create` module ;
+//! - List the tokens (`GET /api/user/tokens`) with the `list` module ;
+//! - Revoke a token (`DELETE /api/user/tokens/:id`) with the `revoke`
+//!   module.
+//!
+//! These endpoints require a session (i.e. a JWT): a personal access token
+//! cannot be used to manage personal access tokens.
+
+use actix_web::web;
+
+pub mod create;
+pub mod list;
+pub mod revoke;
+
+/// Configure the Personal Access Tokens service (`/api/user/tokens/...`
+/// endpoints).
+pub fn config_tokens(cfg: &mut web::ServiceConfig) {
+    cfg.service(create::create_token);
+    cfg.service(list::list_tokens);
+    cfg.service(revoke::revoke_token);
+}
@@ -0,0 +1,26 @@
+use actix_web::{delete, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::error::AppError, middlewares,
+    repositories::personal_access_tokens_repository::delete_personal_access_token,
+};
+
+/// The `DELETE /api/user/tokens/:id` endpoint. **Requires authentication.**
+/// Returns 200 upon success, the token cannot be used anymore.
+/// Returns 403 if the user is authenticated with a personal access token.
+/// Returns 404 if the user has no token with this ID.
+#[delete("/{id}")]
+async fn revoke_token(
+    pool: web::Data<PgPool>,
+    id: web::Path<i32>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    delete_personal_access_token(&pool, *id, user.user.id)
+        .await
+        .map_err(AppError::when_row_not_found("Token not found."))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -0,0 +1,70 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::{
+        auth::totp::{generate_recovery_codes, hash_recovery_code, TotpSecret},
+        error::{AppError, ValidationErrors},
+    },
+    dtos::two_factor::{RecoveryCodesResponseDto, TwoFactorCodeDto},
+    middlewares,
+    repositories::two_factor_repository::{enable_two_factor, get_user_totp, record_totp_step},
+};
+
+/// The `POST /api/user/2fa/confirm` endpoint. **Requires authentication.**
+/// Enables two-factor authentication with the pending secret, given a valid
+/// code of the authenticator app.
+/// Returns 200 with the recovery codes, only sent this time.
+/// Returns 403 if the user is authenticated with a personal access token.
+/// Returns 409 if two-factor authentication is already enabled.
+/// Returns 422 if there is no pending secret or if the code is not valid.
+#[post("/confirm")]
+async fn confirm(
+    pool: web::Data<PgPool>,
+    user: middlewares::AuthenticatedUser,
+    code: web::Json<TwoFactorCodeDto>,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    let totp = get_user_totp(&pool, user.user.id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => {
+                AppError::Validation("There is no pending two-factor enrollment.".into())
+            },
+            e => e.into(),
+        })?;
+    if totp.enabled_at.is_some() {
+        return Err(AppError::Conflict(
+            "Two-factor authentication is already enabled.".into(),
+        ));
+    }
+
+    // Check the code
+    let secret = TotpSecret::from_base32(&totp.secret).map_err(AppError::Internal)?;
+    let step = secret.verify(
+        &code.two_factor.code,
+        OffsetDateTime::now_utc(),
+        totp.last_used_step,
+    );
+    let is_valid = match step {
+        Some(step) => record_totp_step(&pool, user.user.id, step).await?,
+        None => false,
+    };
+    if !is_valid {
+        let mut errors = ValidationErrors::default();
+        errors.add("code", "is invalid");
+        return Err(AppError::InvalidFields(errors));
+    }
+
+    // Enable and respond with the recovery codes
+    let recovery_codes = generate_recovery_codes();
+    let hashes: Vec<String> = recovery_codes
+        .iter()
+        .map(|c| hash_recovery_code(c))
+        .collect();
+    enable_two_factor(&pool, user.user.id, &hashes).await?;
+
+    Ok(HttpResponse::Ok().json(RecoveryCodesResponseDto::new(&recovery_codes)))
+}
@@ -0,0 +1,51 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+
+use super::verify_second_factor;
+use crate::{
+    domain::{
+        auth::lockout::{account_key, LoginLockoutPolicy},
+        error::AppError,
+    },
+    dtos::two_factor::TwoFactorCodeDto,
+    handlers::users::login::{check_not_locked, record_failure},
+    middlewares,
+    repositories::two_factor_repository::{disable_two_factor, is_two_factor_enabled},
+};
+
+/// The `POST /api/user/2fa/disable` endpoint. **Requires authentication.**
+/// Disables two-factor authentication, given a valid code of the
+/// authenticator app or a recovery code.
+/// Returns 200 upon success.
+/// Returns 403 if the user is authenticated with a personal access token, or
+/// if the code is not valid.
+/// Returns 422 if two-factor authentication is not enabled.
+/// Returns 429 if the account is locked: an invalid code counts as a failed
+/// login attempt (see [`LoginLockoutPolicy`]).
+#[post("/disable")]
+async fn disable(
+    pool: web::Data<PgPool>,
+    lockout: web::Data<LoginLockoutPolicy>,
+    user: middlewares::AuthenticatedUser,
+    code: web::Json<TwoFactorCodeDto>,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    if !is_two_factor_enabled(&pool, user.user.id).await? {
+        return Err(AppError::Validation(
+            "Two-factor authentication is not enabled.".into(),
+        ));
+    }
+
+    let account_key = account_key(&user.user.email);
+    check_not_locked(&pool, std::slice::from_ref(&account_key)).await?;
+
+    if !verify_second_factor(&pool, user.user.id, &code.two_factor.code).await? {
+        record_failure(&pool, &lockout, &account_key, None).await?;
+        return Err(AppError::Forbidden("Invalid authentication code.".into()));
+    }
+
+    disable_two_factor(&pool, user.user.id).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -0,0 +1,40 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{auth::totp::TotpSecret, error::AppError},
+    dtos::two_factor::TwoFactorEnrollmentResponseDto,
+    middlewares,
+    repositories::two_factor_repository::set_pending_totp_secret,
+};
+
+/// The `POST /api/user/2fa/enroll` endpoint. **Requires authentication.**
+/// Generates a new secret, pending until confirmed with a first code (see
+/// [`super::confirm`]). Enrolling again replaces the pending secret.
+/// Returns 200 with the secret and its `otpauth://` URI.
+/// Returns 403 if the user is authenticated with a personal access token.
+/// Returns 409 if two-factor authentication is already enabled.
+#[post("/enroll")]
+async fn enroll(
+    pool: web::Data<PgPool>,
+    user: middlewares::AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    let secret = TotpSecret::generate();
+    let encoded = secret.to_base32();
+
+    set_pending_totp_secret(&pool, user.user.id, &encoded)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => {
+                AppError::Conflict("Two-factor authentication is already enabled.".into())
+            },
+            e => e.into(),
+        })?;
+
+    Ok(HttpResponse::Ok().json(TwoFactorEnrollmentResponseDto::new(
+        &encoded,
+        &secret.otpauth_uri(&user.user.username),
+    )))
+}
@@ -0,0 +1,60 @@
+//! The Two-Factor Authentication request handlers are responsible for
+//! managing the TOTP two-factor authentication of the authenticated user:
+//! - Enroll, i.e. get a new secret to register in an authenticator app
+//!   (`POST /api/user/2fa/enroll`) with the `enroll` module ;
+//! - Confirm the enrollment with a first code, which enables two-factor
+//!   authentication and returns recovery codes (`POST /api/user/2fa/confirm`)
+//!   with the `confirm` module ;
+//! - Disable it (`POST /api/user/2fa/disable`) with the `disable` module.
+//!
+//! Once enabled, the login is completed with a code
+//! (see [`crate::handlers::users::two_factor_login`]).
+
+use actix_web::web;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::{
+        auth::totp::{hash_recovery_code, TotpSecret},
+        error::AppError,
+    },
+    repositories::two_factor_repository::{get_user_totp, record_totp_step, use_recovery_code},
+};
+
+pub mod confirm;
+pub mod disable;
+pub mod enroll;
+
+/// Configure the Two-Factor Authentication service (`/api/user/2fa/...`
+/// endpoints).
+pub fn config_two_factor(cfg: &mut web::ServiceConfig) {
+    cfg.service(enroll::enroll);
+    cfg.service(confirm::confirm);
+    cfg.service(disable::disable);
+}
+
+/// Verifies the given code for the given user with two-factor authentication
+/// enabled: either a code of its authenticator app that has not been used
+/// yet, or one of its unused recovery codes (which is then used).
+/// Returns false if the code is not valid, or if two-factor authentication
+/// is not enabled.
+pub(crate) async fn verify_second_factor(
+    pool: &PgPool,
+    user_id: i32,
+    code: &str,
+) -> Result<bool, AppError> {
+    let totp = match get_user_totp(pool, user_id).await {
+        Ok(totp) if totp.enabled_at.is_some() => totp,
+        Ok(_) | Err(sqlx::Error::RowNotFound) => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+
+    let secret = TotpSecret::from_base32(&totp.secret).map_err(AppError::Internal)?;
+    if let Some(step) = secret.verify(code, OffsetDateTime::now_utc(), totp.last_used_step) {
+        // Another request may have used the same code in the meantime
+        return Ok(record_totp_step(pool, user_id, step).await?);
+    }
+
+    Ok(use_recovery_code(pool, user_id, &hash_recovery_code(code)).await?)
+}
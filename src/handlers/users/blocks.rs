@@ -0,0 +1,35 @@
+use actix_web::{get, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{error::AppError, pagination::CursorPagination},
+    dtos::{
+        pagination_dto::CursorPaginationDto,
+        profiles::multiple_profiles_response_dto::MultipleProfilesResponseDto,
+    },
+    handlers::profiles::followers::next_page_cursor,
+    middlewares,
+    repositories::blocks_repository::list_blocks,
+};
+
+/// The `GET /api/user/blocks` endpoint. **Requires authentication.**
+/// Return 200 OK with the users most recently blocked by the authenticated
+/// user, paginated with `limit` and `cursor`.
+/// Return 422 Unprocessable Entity if the pagination parameters are invalid.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[get("/blocks")]
+async fn list_blocked_users(
+    auth: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    pagination: web::Query<CursorPaginationDto>,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let pagination = CursorPagination::parse(pagination.limit, pagination.cursor.as_deref())
+        .map_err(AppError::Validation)?;
+
+    let mut profiles = list_blocks(&pool, auth.user.id, &pagination).await?;
+    let next_cursor = next_page_cursor(&mut profiles, &pagination);
+
+    Ok(HttpResponse::Ok().json(MultipleProfilesResponseDto::new(&profiles, next_cursor)))
+}
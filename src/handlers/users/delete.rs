@@ -0,0 +1,73 @@
+use actix_web::{delete, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        auth::lockout::{account_key, LoginLockoutPolicy},
+        error::AppError,
+        users::password::{verify_password, PasswordVerification},
+    },
+    dtos::users::AccountDeletionDto,
+    handlers::{
+        two_factor::verify_second_factor,
+        users::login::{check_not_locked, record_failure},
+    },
+    middlewares,
+    repositories::{
+        login_attempts_repository::reset_login_attempts,
+        two_factor_repository::is_two_factor_enabled,
+        user_repository::{delete_user, get_user_with_password_by_email},
+    },
+};
+
+/// The `DELETE /api/user` endpoint. **Requires authentication.**
+/// Deletes the account of the authenticated user, along with everything that
+/// belongs to it (follow relationships, articles, comments, favorites,
+/// tokens...). The password must be confirmed, as well as a code of the
+/// authenticator app (or a recovery code) if two-factor authentication is
+/// enabled.
+/// Return 200 OK upon success.
+/// Return 403 Forbidden if the password or the code is not valid, or if the
+/// user is authenticated with a personal access token.
+/// Return 429 Too Many Requests if the account is locked: an invalid password
+/// or code counts as a failed login attempt (see [`LoginLockoutPolicy`]).
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[delete("")]
+async fn delete_account(
+    auth: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    lockout: web::Data<LoginLockoutPolicy>,
+    confirmation: web::Json<AccountDeletionDto>,
+) -> Result<HttpResponse, AppError> {
+    auth.require_session()?;
+    let user_id = auth.user.id;
+
+    let account_key = account_key(&auth.user.email);
+    check_not_locked(&pool, std::slice::from_ref(&account_key)).await?;
+
+    let user = get_user_with_password_by_email(&pool, &auth.user.email).await?;
+    let password = confirmation.user.password.clone();
+    let verification = web::block(move || verify_password(&password, &user.password)).await?;
+    if verification == PasswordVerification::Invalid {
+        record_failure(&pool, &lockout, &account_key, None).await?;
+        return Err(AppError::Forbidden("Incorrect password.".into()));
+    }
+
+    if is_two_factor_enabled(&pool, user_id).await? {
+        let code = confirmation
+            .user
+            .two_factor_code
+            .as_deref()
+            .unwrap_or_default();
+        if !verify_second_factor(&pool, user_id, code).await? {
+            record_failure(&pool, &lockout, &account_key, None).await?;
+            return Err(AppError::Forbidden("Invalid authentication code.".into()));
+        }
+    }
+
+    delete_user(&pool, user_id).await?;
+    reset_login_attempts(&pool, &[account_key]).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
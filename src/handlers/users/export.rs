@@ -0,0 +1,42 @@
+use actix_web::{get, http::header, web, HttpResponse};
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::error::AppError, dtos::users::UserExportResponseDto, middlewares,
+    repositories::user_export_repository::get_user_export,
+};
+
+/// The `GET /api/user/export` endpoint. **Requires authentication.**
+/// Return 200 OK with everything stored about the authenticated user as a
+/// JSON attachment: its profile (with its pending email change), its follow
+/// relationships and requests, the users it blocked, its articles, comments
+/// and favorites, its personal access tokens, its login sessions and the
+/// failed login attempts on its account (without the secrets, such as the
+/// password or the token hashes).
+/// Return 403 Forbidden if the user is authenticated with a personal access
+/// token.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[get("/export")]
+async fn export(
+    auth: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    auth.require_session()?;
+
+    let export = get_user_export(&pool, auth.user.id).await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!(
+                r#"attachment; filename="conduit-{}.json""#,
+                auth.user.username
+            ),
+        ))
+        .json(UserExportResponseDto::new(
+            &export,
+            OffsetDateTime::now_utc(),
+        )))
+}
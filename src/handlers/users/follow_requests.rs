@@ -0,0 +1,90 @@
+use actix_web::{get, post, web, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{error::AppError, pagination::CursorPagination},
+    dtos::{
+        pagination_dto::CursorPaginationDto,
+        profiles::multiple_profiles_response_dto::MultipleProfilesResponseDto,
+    },
+    handlers::profiles::followers::next_page_cursor,
+    middlewares,
+    repositories::{
+        follow_requests_repository::{
+            approve_follow_request, list_follow_requests, reject_follow_request,
+        },
+        user_repository::get_user_by_username,
+    },
+};
+
+/// The `GET /api/user/follow-requests` endpoint. **Requires authentication.**
+/// Return 200 OK with the users who most recently requested to follow the
+/// authenticated user (and are still pending), paginated with `limit` and
+/// `cursor`.
+/// Return 422 Unprocessable Entity if the pagination parameters are invalid.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[get("/follow-requests")]
+async fn list_incoming_follow_requests(
+    auth: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    pagination: web::Query<CursorPaginationDto>,
+) -> Result<HttpResponse, AppError> {
+    // Validate the input
+    let pagination = CursorPagination::parse(pagination.limit, pagination.cursor.as_deref())
+        .map_err(AppError::Validation)?;
+
+    let mut profiles = list_follow_requests(&pool, auth.user.id, &pagination).await?;
+    let next_cursor = next_page_cursor(&mut profiles, &pagination);
+
+    Ok(HttpResponse::Ok().json(MultipleProfilesResponseDto::new(&profiles, next_cursor)))
+}
+
+/// The `POST /api/user/follow-requests/:username/approve` endpoint.
+/// **Requires authentication.**
+/// Return 200 OK once the request is approved: the requester now follows the
+/// authenticated user.
+/// Return 404 Not Found if the user has not requested to follow the
+/// authenticated user.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[post("/follow-requests/{username}/approve")]
+async fn approve(
+    auth: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    username: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let requester = get_user_by_username(&pool, &username)
+        .await
+        .map_err(AppError::when_row_not_found("Follow request not found."))?;
+
+    approve_follow_request(&pool, auth.user.id, requester.id)
+        .await
+        .map_err(AppError::when_row_not_found("Follow request not found."))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// The `POST /api/user/follow-requests/:username/reject` endpoint.
+/// **Requires authentication.**
+/// Return 200 OK once the request is rejected (the requester is not told).
+/// Return 404 Not Found if the user has not requested to follow the
+/// authenticated user.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[post("/follow-requests/{username}/reject")]
+async fn reject(
+    auth: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    username: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let requester = get_user_by_username(&pool, &username)
+        .await
+        .map_err(AppError::when_row_not_found("Follow request not found."))?;
+
+    reject_follow_request(&pool, auth.user.id, requester.id)
+        .await
+        .map_err(AppError::when_row_not_found("Follow request not found."))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
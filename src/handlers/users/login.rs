@@ -1,52 +1,174 @@
-use actix_web::{post, web, HttpResponse};
+use actix_web::{post, web, HttpRequest, HttpResponse};
 use sqlx::PgPool;
+use time::OffsetDateTime;
 
 use crate::{
     domain::{
-        auth::{create_jwt_for_user, JwtSecret},
-        error::validation_error,
-        users::UserLoginRequest,
+        auth::{
+            create_jwt_for_user,
+            lockout::{account_key, ip_key, LoginLockoutPolicy},
+            opaque_token::{generate_token, hash_token},
+            totp::LOGIN_CHALLENGE_TTL,
+            JwtKeys, NewRefreshToken, TokenLifetimes,
+        },
+        error::AppError,
+        users::{
+            password::{hash_password, verify_password, PasswordVerification, DUMMY_PASSWORD_HASH},
+            UserLoginRequest,
+        },
+    },
+    dtos::{
+        two_factor::LoginChallengeResponseDto,
+        users::{UserLoginDto, UserResponseDto},
+    },
+    repositories::{
+        login_attempts_repository::{
+            get_locked_until, lock_login, record_failed_login, reset_login_attempts,
+        },
+        refresh_tokens_repository::insert_refresh_token,
+        two_factor_repository::{insert_login_challenge, is_two_factor_enabled},
+        user_repository::{get_user_with_password_by_email, update_password},
     },
-    dtos::users::{UserLoginDto, UserResponseDto},
-    repositories::user_repository::get_user_with_password_by_email,
 };
 
 /// The `POST /api/users/login` endpoint used for authentication.
-/// Return 200 OK in case of success.
+/// Return 200 OK in case of success, with an access token and a refresh
+/// token.
+/// Passwords stored with a legacy hashing scheme are transparently rehashed
+/// upon successful authentication.
+/// If the user has enabled two-factor authentication, return 200 OK with a
+/// short-lived challenge token instead, to complete the login with a code at
+/// `POST /api/users/login/2fa`.
+/// The failed attempts are counted per account and per client IP address:
+/// past the configured thresholds, the login is refused with 429 Too Many
+/// Requests for an exponentially growing duration (see [`LoginLockoutPolicy`]).
+/// Only a complete login (including the second factor) forgets the failed
+/// attempts of the account, and never those of the client IP address.
 #[post("login")]
 async fn login(
+    req: HttpRequest,
     pool: web::Data<PgPool>,
-    jwt_secret: web::Data<JwtSecret>,
+    jwt_keys: web::Data<JwtKeys>,
+    lifetimes: web::Data<TokenLifetimes>,
+    lockout: web::Data<LoginLockoutPolicy>,
     user: web::Json<UserLoginDto>,
-) -> HttpResponse {
+) -> Result<HttpResponse, AppError> {
     // Validate the input
-    let login_user: UserLoginRequest = match user.into_inner().try_into() {
-        Ok(login_user) => login_user,
-        Err(e) => return validation_error(e.as_ref()),
-    };
+    let login_user: UserLoginRequest = user
+        .into_inner()
+        .try_into()
+        .map_err(AppError::InvalidFields)?;
+
+    // Refuse the attempt if the account or the client is locked
+    let account_key = account_key(login_user.email.as_ref());
+    let ip_key = req.peer_addr().map(|addr| ip_key(&addr.ip().to_string()));
+    let keys: Vec<String> = std::iter::once(account_key.clone())
+        .chain(ip_key.clone())
+        .collect();
+
+    check_not_locked(&pool, &keys).await?;
 
     // Get the user with its password
-    match get_user_with_password_by_email(&pool, login_user.email.as_ref()).await {
-        Ok(user) => {
-            // Try to match the passwords
-            if user.password == login_user.password {
-                // Return a JWT token if success
-                match create_jwt_for_user(&user.username, &jwt_secret.into_inner().0) {
-                    Ok(token) => HttpResponse::Ok().json(UserResponseDto::new(
-                        &user.username,
-                        &user.email,
-                        user.bio.as_deref(),
-                        user.image.as_deref(),
-                        &token,
-                    )),
-                    Err(_) => {
-                        HttpResponse::InternalServerError().body("Unexpected error happened.")
-                    },
-                }
-            } else {
-                HttpResponse::Forbidden().body("Incorrect email or password.")
+    let user = match get_user_with_password_by_email(&pool, login_user.email.as_ref()).await {
+        Ok(user) => Some(user),
+        Err(sqlx::Error::RowNotFound) => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    // Try to match the passwords (against a dummy hash for an unknown email,
+    // so that it takes as long as for a known one)
+    let stored_hash = user
+        .as_ref()
+        .map_or(DUMMY_PASSWORD_HASH, |user| &user.password)
+        .to_owned();
+    let password = login_user.password.clone();
+    let verification = web::block(move || verify_password(&password, &stored_hash)).await?;
+
+    let user = match (user, verification) {
+        (Some(user), PasswordVerification::Valid) => user,
+        (Some(user), PasswordVerification::ValidNeedsRehash) => {
+            // Upgrade the stored hash, without failing the authentication
+            let password = login_user.password.clone();
+            let rehashed = web::block(move || hash_password(&password))
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|rehashed| rehashed);
+            match rehashed {
+                Ok(hash) => {
+                    if let Err(e) = update_password(&pool, user.id, &hash).await {
+                        log::warn!("Unable to rehash the password of {}: {e}", user.username);
+                    }
+                },
+                Err(e) => log::warn!("Unable to rehash the password of {}: {e}", user.username),
             }
+            user
         },
-        Err(_) => HttpResponse::Forbidden().body("Incorrect email or password."),
+        _ => {
+            record_failure(&pool, &lockout, &account_key, ip_key.as_deref()).await?;
+            return Err(AppError::Forbidden("Incorrect email or password.".into()));
+        },
+    };
+
+    // Ask for a second factor if enabled
+    if is_two_factor_enabled(&pool, user.id).await? {
+        let challenge_token = generate_token();
+        let expires_at = OffsetDateTime::now_utc() + LOGIN_CHALLENGE_TTL;
+        insert_login_challenge(&pool, user.id, &hash_token(&challenge_token), expires_at).await?;
+
+        return Ok(
+            HttpResponse::Ok().json(LoginChallengeResponseDto::new(&challenge_token, expires_at))
+        );
+    }
+
+    reset_login_attempts(&pool, &[account_key]).await?;
+
+    // Return a JWT token and a refresh token if success
+    let refresh_token = NewRefreshToken::new_family(lifetimes.refresh);
+    insert_refresh_token(&pool, user.id, &refresh_token).await?;
+
+    let token = create_jwt_for_user(user.id, user.token_version, &jwt_keys, lifetimes.access)?;
+
+    Ok(HttpResponse::Ok().json(
+        UserResponseDto::new(
+            &user.username,
+            &user.email,
+            user.bio.as_deref(),
+            user.image.as_deref(),
+            &token,
+        )
+        .with_refresh_token(&refresh_token.token),
+    ))
+}
+
+/// Refuses the attempt with [`AppError::TooManyRequests`] if any of the given
+/// keys (see [`account_key`] and [`ip_key`]) is currently locked.
+pub(crate) async fn check_not_locked(pool: &PgPool, keys: &[String]) -> Result<(), AppError> {
+    if let Some(locked_until) = get_locked_until(pool, keys).await? {
+        return Err(AppError::TooManyRequests(
+            locked_until - OffsetDateTime::now_utc(),
+        ));
     }
+
+    Ok(())
+}
+
+/// Records a failed login attempt on the given account from the given IP
+/// address, and locks them if their threshold is reached.
+pub(crate) async fn record_failure(
+    pool: &PgPool,
+    lockout: &LoginLockoutPolicy,
+    account_key: &str,
+    ip_key: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    let thresholds = std::iter::once((account_key, lockout.account_threshold))
+        .chain(ip_key.map(|key| (key, lockout.ip_threshold)));
+
+    for (key, threshold) in thresholds {
+        let failed_attempts = record_failed_login(pool, key, lockout.max_lock).await?;
+        if let Some(duration) = lockout.lock_duration(failed_attempts, threshold) {
+            lock_login(pool, key, OffsetDateTime::now_utc() + duration).await?;
+        }
+    }
+
+    Ok(())
 }
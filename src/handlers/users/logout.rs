@@ -0,0 +1,82 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+use time::Duration;
+
+use crate::{
+    domain::{
+        auth::{opaque_token::hash_token, JwtKeys},
+        error::AppError,
+    },
+    dtos::users::TokenRefreshDto,
+    middlewares,
+    repositories::{
+        personal_access_tokens_repository::delete_user_personal_access_tokens,
+        refresh_tokens_repository::{
+            get_refresh_token_by_hash, revoke_refresh_token_family, revoke_user_refresh_tokens,
+        },
+        revoked_tokens_repository::revoke_token,
+        user_repository::revoke_user_tokens,
+    },
+};
+
+/// The `POST /api/user/logout` endpoint. **Requires authentication.**
+/// Revokes the access token used for this request and, if one is given in the
+/// body, the refresh token obtained with it (and the ones it was rotated
+/// from/to).
+/// Return 200 OK upon success.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+/// Return 403 Forbidden if the user is authenticated with a personal access
+/// token (which is revoked with `DELETE /api/user/tokens/:id` instead).
+#[post("/logout")]
+async fn logout(
+    user: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    jwt_keys: web::Data<JwtKeys>,
+    refresh: Option<web::Json<TokenRefreshDto>>,
+) -> Result<HttpResponse, AppError> {
+    let Some(claims) = &user.claims else {
+        return Err(AppError::Forbidden(
+            "A personal access token cannot be logged out.".into(),
+        ));
+    };
+
+    // The token is still accepted within the leeway after its expiration
+    let leeway = Duration::seconds(jwt_keys.leeway() as i64);
+    revoke_token(&pool, claims.token_id(), claims.expires_at() + leeway).await?;
+
+    if let Some(refresh) = refresh {
+        match get_refresh_token_by_hash(&pool, &hash_token(&refresh.user.refresh_token)).await {
+            Ok(stored) if stored.user_id == user.user.id => {
+                revoke_refresh_token_family(&pool, &stored.family).await?
+            },
+            // Unknown token or token of someone else: nothing to revoke
+            Ok(_) | Err(sqlx::Error::RowNotFound) => (),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// The `POST /api/user/logout/all` endpoint. **Requires authentication.**
+/// Revokes every access token, every refresh token and every personal access
+/// token of the authenticated user (i.e. log out everywhere).
+/// Return 200 OK upon success.
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+/// Return 403 Forbidden if the user is authenticated with a personal access
+/// token.
+#[post("/logout/all")]
+async fn logout_everywhere(
+    user: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, AppError> {
+    user.require_session()?;
+
+    revoke_user_tokens(&pool, user.user.id).await?;
+    revoke_user_refresh_tokens(&pool, user.user.id).await?;
+    delete_user_personal_access_tokens(&pool, user.user.id).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -1,28 +1,73 @@
-//! The Users request handlers are responsible for handling CRU_ actions on the
+//! The Users request handlers are responsible for handling CRUD actions on the
 //! users:
 //! - Create new users with registration (`POST /api/users`) with the
 //!   `register` module ;
 //! - Read user information (`GET /api/user`) with the `user_info` module ;
 //! - Update user information (`PUT /api/user`) with the `update` module ;
-//! - Authentication (`POST /api/users/login`) with the `login` module.
+//! - Delete the user (`DELETE /api/user`) with the `delete` module, and
+//!   export its data (`GET /api/user/export`) with the `export` module ;
+//! - List the users blocked by the user (`GET /api/user/blocks`) with the
+//!   `blocks` module ;
+//! - List the pending requests to follow the user
+//!   (`GET /api/user/follow-requests`), approve and reject them
+//!   (`POST /api/user/follow-requests/:username/approve` and
+//!   `POST /api/user/follow-requests/:username/reject`) with the
+//!   `follow_requests` module ;
+//! - Logout (`POST /api/user/logout`) and logout everywhere
+//!   (`POST /api/user/logout/all`) with the `logout` module ;
+//! - Authentication (`POST /api/users/login`) with the `login` module, and
+//!   its second step for users with two-factor authentication
+//!   (`POST /api/users/login/2fa`) with the `two_factor_login` module ;
+//! - Access token refresh (`POST /api/users/token/refresh`) with the
+//!   `refresh` module ;
+//! - Password reset (`POST /api/users/password/forgot` and
+//!   `POST /api/users/password/reset`) with the `password_reset` module ;
+//! - Email verification (`POST /api/users/verify-email`) and its resending
+//!   (`POST /api/user/verify-email/resend`) with the `verify_email` module.
 
 use actix_web::web;
 
+pub mod blocks;
+pub mod delete;
+pub mod export;
+pub mod follow_requests;
 pub mod login;
+pub mod logout;
+pub mod password_reset;
+pub mod refresh;
 pub mod register;
+pub mod two_factor_login;
 pub mod update;
 pub mod user_info;
+pub mod verify_email;
 
-/// Configure the Users service: registration and authentication.
-/// `/api/users/...` endpoints.
+/// Configure the Users service: registration, authentication (with or
+/// without two-factor authentication), token refresh, password reset and
+/// email verification. `/api/users/...` endpoints.
 pub fn config_users(cfg: &mut web::ServiceConfig) {
     cfg.service(register::register);
     cfg.service(login::login);
+    cfg.service(two_factor_login::two_factor_login);
+    cfg.service(refresh::refresh);
+    cfg.service(password_reset::forgot_password);
+    cfg.service(password_reset::reset_password);
+    cfg.service(verify_email::verify_email);
 }
 
-/// Configure the User service: Get user info and Update user.
+/// Configure the User service: Get user info, Update user, Delete user,
+/// Export user data, List blocked users, Manage follow requests, Logout and
+/// resend the email verification.
 /// `/api/user` endpoints.
 pub fn config_user(cfg: &mut web::ServiceConfig) {
     cfg.service(user_info::user_info);
     cfg.service(update::update);
+    cfg.service(delete::delete_account);
+    cfg.service(export::export);
+    cfg.service(blocks::list_blocked_users);
+    cfg.service(follow_requests::list_incoming_follow_requests);
+    cfg.service(follow_requests::approve);
+    cfg.service(follow_requests::reject);
+    cfg.service(logout::logout);
+    cfg.service(logout::logout_everywhere);
+    cfg.service(verify_email::resend_email_verification);
 }
@@ -0,0 +1,125 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::{
+        auth::{
+            lockout::account_key,
+            opaque_token::{generate_token, hash_token},
+            TokenLifetimes,
+        },
+        error::{AppError, ValidationErrors},
+        users::{
+            email::UserEmail,
+            password_reset::{password_reset_email, PasswordResetRequest},
+        },
+    },
+    dtos::users::{PasswordForgotDto, PasswordResetDto},
+    mailer::Mailer,
+    repositories::{
+        login_attempts_repository::reset_login_attempts,
+        password_reset_tokens_repository::{
+            consume_password_reset_token, insert_password_reset_token,
+        },
+        personal_access_tokens_repository::delete_user_personal_access_tokens,
+        refresh_tokens_repository::revoke_user_refresh_tokens,
+        user_repository::{
+            get_user_by_id, get_user_with_password_by_email, revoke_user_tokens, update_password,
+        },
+    },
+};
+
+/// The `POST /api/users/password/forgot` endpoint, used to request a password
+/// reset. If an user has the given email, a reset token is emailed to it
+/// (replacing any previous one) in the background.
+/// Return 202 Accepted whether the user exists or not, so that the registered
+/// emails cannot be enumerated.
+/// Return 422 Unprocessable Entity if the email is invalid.
+#[post("password/forgot")]
+async fn forgot_password(
+    pool: web::Data<PgPool>,
+    lifetimes: web::Data<TokenLifetimes>,
+    mailer: web::Data<dyn Mailer>,
+    forgot: web::Json<PasswordForgotDto>,
+) -> Result<HttpResponse, AppError> {
+    let mut errors = ValidationErrors::default();
+    let Some(email) = errors.check("email", UserEmail::parse(forgot.into_inner().user.email))
+    else {
+        return Err(AppError::InvalidFields(errors));
+    };
+
+    let user = match get_user_with_password_by_email(&pool, email.as_ref()).await {
+        Ok(user) => user,
+        Err(sqlx::Error::RowNotFound) => return Ok(HttpResponse::Accepted().finish()),
+        Err(e) => return Err(e.into()),
+    };
+
+    // The token is stored and emailed in the background: neither their
+    // duration nor a failure must tell a known email apart from an unknown one
+    let pool = pool.into_inner();
+    let mailer = mailer.into_inner();
+    let ttl = lifetimes.password_reset;
+    actix_web::rt::spawn(async move {
+        let token = generate_token();
+        let expires_at = OffsetDateTime::now_utc() + ttl;
+        if let Err(e) =
+            insert_password_reset_token(&pool, user.id, &hash_token(&token), expires_at).await
+        {
+            log::error!(
+                "Unable to store the password reset token of {}: {e}",
+                user.username
+            );
+            return;
+        }
+
+        let email = password_reset_email(&user.email, &token, ttl);
+        if let Err(e) = mailer.send(&email).await {
+            log::error!(
+                "Unable to send the password reset email of {}: {e}",
+                user.username
+            );
+        }
+    });
+
+    Ok(HttpResponse::Accepted().finish())
+}
+
+/// The `POST /api/users/password/reset` endpoint, used to choose a new
+/// password with a reset token. The token can only be used once, and every
+/// access token, refresh token and personal access token of the user is
+/// revoked (log out everywhere).
+/// Return 200 OK upon success.
+/// Return 401 Unauthorized if the reset token is unknown, expired or already
+/// used.
+/// Return 422 Unprocessable Entity if the token or the password is empty.
+#[post("password/reset")]
+async fn reset_password(
+    pool: web::Data<PgPool>,
+    reset: web::Json<PasswordResetDto>,
+) -> Result<HttpResponse, AppError> {
+    // Hashing the password blocks the thread
+    let reset = reset.into_inner();
+    let reset = web::block(move || PasswordResetRequest::try_from(reset))
+        .await?
+        .map_err(AppError::InvalidFields)?;
+
+    let user_id = consume_password_reset_token(&pool, &reset.token_hash)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => AppError::Unauthorized("Invalid reset token.".into()),
+            e => e.into(),
+        })?;
+
+    update_password(&pool, user_id, &reset.password).await?;
+    revoke_user_tokens(&pool, user_id).await?;
+    revoke_user_refresh_tokens(&pool, user_id).await?;
+    delete_user_personal_access_tokens(&pool, user_id).await?;
+
+    // The account is no longer locked by the failed attempts to guess the
+    // former password
+    let user = get_user_by_id(&pool, user_id).await?;
+    reset_login_attempts(&pool, &[account_key(&user.email)]).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
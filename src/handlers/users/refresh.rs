@@ -0,0 +1,75 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::{
+    domain::{
+        auth::{
+            create_jwt_for_user, opaque_token::hash_token, JwtKeys, NewRefreshToken, TokenLifetimes,
+        },
+        error::AppError,
+    },
+    dtos::users::{TokenRefreshDto, UserResponseDto},
+    repositories::{
+        refresh_tokens_repository::{
+            get_refresh_token_by_hash, insert_refresh_token, mark_refresh_token_used,
+            revoke_refresh_token_family,
+        },
+        user_repository::get_user_by_id,
+    },
+};
+
+/// The `POST /api/users/token/refresh` endpoint, used to obtain a new access
+/// token with a refresh token.
+/// Return 200 OK with an user response as JSON body, including a new refresh
+/// token: a refresh token can only be used once.
+/// Return 401 Unauthorized if the refresh token is unknown, expired, revoked
+/// or already used. Using a refresh token twice revokes every refresh token
+/// obtained from the same login.
+#[post("token/refresh")]
+async fn refresh(
+    pool: web::Data<PgPool>,
+    jwt_keys: web::Data<JwtKeys>,
+    lifetimes: web::Data<TokenLifetimes>,
+    refresh: web::Json<TokenRefreshDto>,
+) -> Result<HttpResponse, AppError> {
+    let invalid_token = || AppError::Unauthorized("Invalid refresh token.".into());
+
+    // Get the stored refresh token
+    let stored = get_refresh_token_by_hash(&pool, &hash_token(&refresh.user.refresh_token))
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => invalid_token(),
+            e => e.into(),
+        })?;
+
+    if stored.expires_at <= OffsetDateTime::now_utc() {
+        return Err(invalid_token());
+    }
+
+    // A refresh token already used has leaked: revoke the whole family
+    if !mark_refresh_token_used(&pool, stored.id).await? {
+        log::warn!("Reuse of a refresh token of user {}", stored.user_id);
+        revoke_refresh_token_family(&pool, &stored.family).await?;
+        return Err(invalid_token());
+    }
+
+    // Rotate the refresh token and issue a new access token
+    let user = get_user_by_id(&pool, stored.user_id).await?;
+
+    let refresh_token = NewRefreshToken::rotate(&stored.family, lifetimes.refresh);
+    insert_refresh_token(&pool, user.id, &refresh_token).await?;
+
+    let token = create_jwt_for_user(user.id, user.token_version, &jwt_keys, lifetimes.access)?;
+
+    Ok(HttpResponse::Ok().json(
+        UserResponseDto::new(
+            &user.username,
+            &user.email,
+            user.bio.as_deref(),
+            user.image.as_deref(),
+            &token,
+        )
+        .with_refresh_token(&refresh_token.token),
+    ))
+}
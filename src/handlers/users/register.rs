@@ -3,47 +3,109 @@ use sqlx::PgPool;
 
 use crate::{
     domain::{
-        auth::{create_jwt_for_user, JwtSecret},
-        error::validation_error,
+        auth::{create_jwt_for_user, JwtKeys, NewRefreshToken, TokenLifetimes},
+        error::{AppError, ValidationErrors},
         users::NewUser,
     },
     dtos::users::{UserRegistrationDto, UserResponseDto},
-    repositories::user_repository::insert_new_user,
+    handlers::users::verify_email::send_email_verification,
+    mailer::Mailer,
+    repositories::{
+        refresh_tokens_repository::insert_refresh_token,
+        user_repository::{
+            insert_new_user, is_email_taken, is_username_taken, USERS_UNIQUE_CONSTRAINTS,
+        },
+    },
 };
 
 /// The `POST /api/users` endpoint, used for user registration.
-/// Return 201 Created in case of success.
+/// Return 201 Created in case of success, with an access token and a refresh
+/// token. A token to verify the email is emailed to it.
+/// Return 422 with the errors keyed by field name if the input is invalid or
+/// if the username or email is already taken.
 #[post("")]
 async fn register(
     pool: web::Data<PgPool>,
-    jwt_secret: web::Data<JwtSecret>,
+    jwt_keys: web::Data<JwtKeys>,
+    lifetimes: web::Data<TokenLifetimes>,
+    mailer: web::Data<dyn Mailer>,
     user: web::Json<UserRegistrationDto>,
-) -> HttpResponse {
-    // Validate the input
-    let new_user: NewUser = match user.into_inner().try_into() {
-        Ok(new_user) => new_user,
-        Err(e) => return validation_error(e.as_ref()),
-    };
+) -> Result<HttpResponse, AppError> {
+    // Validate the input (hashing the password blocks the thread), reporting
+    // the taken username and email along with the invalid fields
+    let user = user.into_inner();
+    let mut errors = ValidationErrors::default();
+    check_not_taken(
+        &pool,
+        Some(&user.user.username),
+        Some(&user.user.email),
+        None,
+        &mut errors,
+    )
+    .await?;
 
-    // Store the result and respond
-    match insert_new_user(&pool, &new_user).await {
-        Ok(_) => {
-            match create_jwt_for_user(new_user.username.as_ref(), &jwt_secret.into_inner().0) {
-                Ok(token) => HttpResponse::Created().json(UserResponseDto::new(
-                    new_user.username.as_ref(),
-                    new_user.email.as_ref(),
-                    None,
-                    None,
-                    &token,
-                )),
-                Err(_) => HttpResponse::InternalServerError().body("Unexpected error happened."),
-            }
-        },
-        Err(e) => match e {
-            sqlx::Error::Database(_) => validation_error(
-                "Unable to create the user. The username or email might be already in use.",
-            ),
-            _ => HttpResponse::InternalServerError().body("Unexpected error happened."),
+    let new_user = match web::block(move || NewUser::try_from(user)).await? {
+        Ok(new_user) if errors.is_empty() => new_user,
+        Ok(_) => return Err(AppError::InvalidFields(errors)),
+        Err(invalid_fields) => {
+            errors.merge(invalid_fields);
+            return Err(AppError::InvalidFields(errors));
         },
+    };
+
+    // Store the result
+    let user_id = insert_new_user(&pool, &new_user)
+        .await
+        .map_err(AppError::when_unique_violation(USERS_UNIQUE_CONSTRAINTS))?;
+
+    send_email_verification(
+        &pool,
+        mailer.as_ref(),
+        lifetimes.email_verification,
+        user_id,
+        new_user.email.as_ref(),
+    )
+    .await?;
+
+    // Generate tokens and respond
+    let refresh_token = NewRefreshToken::new_family(lifetimes.refresh);
+    insert_refresh_token(&pool, user_id, &refresh_token).await?;
+
+    // A new user has not revoked any token yet: its token version is the first
+    let token = create_jwt_for_user(user_id, 0, &jwt_keys, lifetimes.access)?;
+
+    Ok(HttpResponse::Created().json(
+        UserResponseDto::new(
+            new_user.username.as_ref(),
+            new_user.email.as_ref(),
+            None,
+            None,
+            &token,
+        )
+        .with_refresh_token(&refresh_token.token),
+    ))
+}
+
+/// Records an error for the given username and email (if any) in `errors` if
+/// they are already taken by an user other than the given one (if any).
+pub(crate) async fn check_not_taken(
+    pool: &PgPool,
+    username: Option<&str>,
+    email: Option<&str>,
+    except_id: Option<i32>,
+    errors: &mut ValidationErrors,
+) -> Result<(), sqlx::Error> {
+    if let Some(username) = username {
+        if is_username_taken(pool, username, except_id).await? {
+            errors.add("username", "has already been taken");
+        }
     }
+
+    if let Some(email) = email {
+        if is_email_taken(pool, email, except_id).await? {
+            errors.add("email", "has already been taken");
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,104 @@
+use actix_web::{post, web, HttpRequest, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        auth::{
+            create_jwt_for_user,
+            lockout::{account_key, ip_key, LoginLockoutPolicy},
+            opaque_token::hash_token,
+            totp::LOGIN_CHALLENGE_MAX_ATTEMPTS,
+            JwtKeys, NewRefreshToken, TokenLifetimes,
+        },
+        error::AppError,
+    },
+    dtos::{two_factor::TwoFactorLoginDto, users::UserResponseDto},
+    handlers::{
+        two_factor::verify_second_factor,
+        users::login::{check_not_locked, record_failure},
+    },
+    repositories::{
+        login_attempts_repository::reset_login_attempts,
+        refresh_tokens_repository::insert_refresh_token,
+        two_factor_repository::{
+            delete_login_challenge, get_login_challenge_user, record_login_challenge_failure,
+        },
+        user_repository::get_user_by_id,
+    },
+};
+
+/// The `POST /api/users/login/2fa` endpoint used for completing the login of
+/// a user with two-factor authentication, with the challenge token obtained
+/// upon `POST /api/users/login` and a code of the authenticator app (or a
+/// recovery code).
+/// Return 200 OK in case of success, with an access token and a refresh
+/// token. The challenge token cannot be used again.
+/// Return 401 Unauthorized if the challenge token is unknown or expired, or
+/// has been revoked after too many invalid codes.
+/// Return 403 Forbidden if the code is not valid.
+/// The invalid codes are counted as failed login attempts of the account and
+/// of the client IP address, like the invalid passwords: past the configured
+/// thresholds, the login is refused with 429 Too Many Requests (see
+/// [`LoginLockoutPolicy`]).
+#[post("login/2fa")]
+async fn two_factor_login(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    jwt_keys: web::Data<JwtKeys>,
+    lifetimes: web::Data<TokenLifetimes>,
+    lockout: web::Data<LoginLockoutPolicy>,
+    login: web::Json<TwoFactorLoginDto>,
+) -> Result<HttpResponse, AppError> {
+    let challenge_hash = hash_token(&login.user.challenge_token);
+    let invalid_challenge = || AppError::Unauthorized("Invalid or expired challenge.".into());
+
+    let user_id = get_login_challenge_user(&pool, &challenge_hash)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => invalid_challenge(),
+            e => e.into(),
+        })?;
+
+    let user = get_user_by_id(&pool, user_id).await?;
+
+    // Refuse the attempt if the account or the client is locked
+    let account_key = account_key(&user.email);
+    let ip_key = req.peer_addr().map(|addr| ip_key(&addr.ip().to_string()));
+    let keys: Vec<String> = std::iter::once(account_key.clone())
+        .chain(ip_key.clone())
+        .collect();
+
+    check_not_locked(&pool, &keys).await?;
+
+    // Check the code
+    if !verify_second_factor(&pool, user_id, &login.user.code).await? {
+        record_login_challenge_failure(&pool, &challenge_hash, LOGIN_CHALLENGE_MAX_ATTEMPTS)
+            .await?;
+        record_failure(&pool, &lockout, &account_key, ip_key.as_deref()).await?;
+        return Err(AppError::Forbidden("Invalid authentication code.".into()));
+    }
+
+    // The challenge is single-use, even with concurrent requests
+    if !delete_login_challenge(&pool, &challenge_hash).await? {
+        return Err(invalid_challenge());
+    }
+
+    reset_login_attempts(&pool, &[account_key]).await?;
+
+    // Return a JWT token and a refresh token
+    let refresh_token = NewRefreshToken::new_family(lifetimes.refresh);
+    insert_refresh_token(&pool, user.id, &refresh_token).await?;
+
+    let token = create_jwt_for_user(user.id, user.token_version, &jwt_keys, lifetimes.access)?;
+
+    Ok(HttpResponse::Ok().json(
+        UserResponseDto::new(
+            &user.username,
+            &user.email,
+            user.bio.as_deref(),
+            user.image.as_deref(),
+            &token,
+        )
+        .with_refresh_token(&refresh_token.token),
+    ))
+}
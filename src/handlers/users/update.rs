@@ -3,59 +3,121 @@ use sqlx::PgPool;
 
 use crate::{
     domain::{
-        auth::{create_jwt_for_user, JwtSecret},
-        error::validation_error,
+        auth::{create_jwt_for_user, JwtKeys, TokenLifetimes},
+        error::{AppError, ValidationErrors},
         users::UserUpdateRequest,
     },
     dtos::users::{UserResponseDto, UserUpdateDto},
+    handlers::users::{register::check_not_taken, verify_email::send_email_verification},
+    mailer::Mailer,
     middlewares,
-    repositories::user_repository::{get_user_by_username, update_user},
+    repositories::{
+        follow_requests_repository::approve_all_follow_requests,
+        user_repository::{get_user_by_id, update_user, USERS_UNIQUE_CONSTRAINTS},
+    },
 };
 
 /// The `PUT /api/user` endpoint. **Requires authentication.**
 /// Return 200 OK with an user response as JSON body.
+/// Return 422 with the errors keyed by field name if the input is invalid or
+/// if the new username or email is already taken.
 /// Return 401 Unauthorized (by the authentication middleware) if there is not
 /// a valid authentication.
+/// Return 403 Forbidden if the password or the email is changed with a
+/// personal access token: only a session can change the credentials.
+/// A new JWT is only issued for a session: with a personal access token, the
+/// same token is sent back.
+/// Making the account public approves its pending follow requests.
+/// A new email is not set right away: a verification token is emailed to it,
+/// and the current email is kept until the new one is verified.
 #[put("")]
 async fn update(
-    user: middlewares::AuthenticatedUser,
-    jwt_secret: web::Data<JwtSecret>,
+    auth: middlewares::AuthenticatedUser,
+    jwt_keys: web::Data<JwtKeys>,
+    lifetimes: web::Data<TokenLifetimes>,
     pool: web::Data<PgPool>,
+    mailer: web::Data<dyn Mailer>,
     update: web::Json<UserUpdateDto>,
-) -> HttpResponse {
-    // Validate the input
-    let updated_user: UserUpdateRequest = match update.into_inner().try_into() {
-        Ok(updated_user) => updated_user,
-        Err(e) => return validation_error(e.as_ref()),
+) -> Result<HttpResponse, AppError> {
+    // Validate the input (hashing the password blocks the thread), reporting
+    // the taken username and email along with the invalid fields
+    let update = update.into_inner();
+    let mut errors = ValidationErrors::default();
+    check_not_taken(
+        &pool,
+        update.user.username.as_deref(),
+        update
+            .user
+            .email
+            .as_deref()
+            .filter(|email| *email != auth.user.email),
+        Some(auth.user.id),
+        &mut errors,
+    )
+    .await?;
+
+    let mut updated_user = match web::block(move || UserUpdateRequest::try_from(update)).await? {
+        Ok(updated_user) if errors.is_empty() => updated_user,
+        Ok(_) => return Err(AppError::InvalidFields(errors)),
+        Err(invalid_fields) => {
+            errors.merge(invalid_fields);
+            return Err(AppError::InvalidFields(errors));
+        },
     };
 
     if updated_user.is_all_none() {
-        return validation_error("No update provided!");
+        return Err(AppError::Validation("No update provided!".into()));
     }
 
-    // Update in the database and respond
-    match update_user(&pool, &user.user.username, &updated_user).await {
-        Ok(new_username) => {
-            // Get the new user
-            match get_user_by_username(&pool, new_username.as_ref()).await {
-                Ok(user) => {
-                    // Generate token and respond
-                    match create_jwt_for_user(user.username.as_ref(), &jwt_secret.into_inner().0) {
-                        Ok(token) => HttpResponse::Ok().json(UserResponseDto::new(
-                            user.username.as_ref(),
-                            user.email.as_ref(),
-                            user.bio.as_deref(),
-                            user.image.as_deref(),
-                            &token,
-                        )),
-                        Err(_) => {
-                            HttpResponse::InternalServerError().body("Unexpected error happened.")
-                        },
-                    }
-                },
-                Err(_) => HttpResponse::InternalServerError().body("Unexpected error happened."),
-            }
-        },
-        Err(_) => HttpResponse::InternalServerError().body("Unexpected error happened."),
+    if updated_user.password.is_some() || updated_user.email.is_some() {
+        auth.require_session()?;
+    }
+
+    // The new email is only set once verified
+    let new_email = updated_user
+        .email
+        .take()
+        .filter(|email| email.as_ref() != &auth.user.email);
+    // Update in the database
+    if !updated_user.is_all_none() {
+        update_user(&pool, auth.user.id, &updated_user)
+            .await
+            .map_err(AppError::when_unique_violation(USERS_UNIQUE_CONSTRAINTS))?;
+    }
+
+    // A public account has no follow requests left to approve
+    if updated_user.private == Some(false) {
+        approve_all_follow_requests(&pool, auth.user.id).await?;
+    }
+
+    if let Some(email) = &new_email {
+        send_email_verification(
+            &pool,
+            mailer.as_ref(),
+            lifetimes.email_verification,
+            auth.user.id,
+            email.as_ref(),
+        )
+        .await?;
     }
+
+    let user = get_user_by_id(&pool, auth.user.id).await?;
+
+    // Generate token and respond
+    let token = if auth.is_session() {
+        create_jwt_for_user(user.id, user.token_version, &jwt_keys, lifetimes.access)?
+    } else {
+        auth.token.clone()
+    };
+
+    Ok(HttpResponse::Ok().json(
+        UserResponseDto::new(
+            user.username.as_ref(),
+            user.email.as_ref(),
+            user.bio.as_deref(),
+            user.image.as_deref(),
+            &token,
+        )
+        .with_private(user.private),
+    ))
 }
@@ -8,11 +8,14 @@ use crate::{dtos::users::UserResponseDto, middlewares};
 /// a valid authentication.
 #[get("")]
 async fn user_info(user: middlewares::AuthenticatedUser) -> HttpResponse {
-    HttpResponse::Ok().json(UserResponseDto::new(
-        &user.user.username,
-        &user.user.email,
-        user.user.bio.as_deref(),
-        user.user.image.as_deref(),
-        &user.token,
-    ))
+    HttpResponse::Ok().json(
+        UserResponseDto::new(
+            &user.user.username,
+            &user.user.email,
+            user.user.bio.as_deref(),
+            user.user.image.as_deref(),
+            &user.token,
+        )
+        .with_private(user.user.private),
+    )
 }
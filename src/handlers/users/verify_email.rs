@@ -0,0 +1,115 @@
+use actix_web::{post, web, HttpResponse};
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    domain::{
+        auth::{
+            opaque_token::{generate_token, hash_token},
+            TokenLifetimes,
+        },
+        error::AppError,
+        users::email_verification::email_verification_email,
+    },
+    dtos::users::EmailVerificationDto,
+    mailer::Mailer,
+    middlewares,
+    repositories::{
+        email_verifications_repository::{
+            consume_email_verification, get_pending_email, insert_email_verification,
+        },
+        user_repository::{set_verified_email, USERS_UNIQUE_CONSTRAINTS},
+    },
+};
+
+/// Emails a new verification token to the given address of an user,
+/// replacing its pending verification if any. A delivery failure is only
+/// logged: the user can ask for the email again.
+pub(crate) async fn send_email_verification(
+    pool: &PgPool,
+    mailer: &dyn Mailer,
+    ttl: Duration,
+    user_id: i32,
+    email: &str,
+) -> Result<(), AppError> {
+    let token = generate_token();
+    let expires_at = OffsetDateTime::now_utc() + ttl;
+    insert_email_verification(pool, user_id, email, &hash_token(&token), expires_at).await?;
+
+    if let Err(e) = mailer
+        .send(&email_verification_email(email, &token, ttl))
+        .await
+    {
+        log::error!("Unable to send the verification email of user {user_id}: {e}");
+    }
+
+    Ok(())
+}
+
+/// The `POST /api/users/verify-email` endpoint, used to verify an email
+/// address with the token emailed to it. The token can only be used once.
+/// If it was sent after an email change, the verified address replaces the
+/// current one.
+/// Return 200 OK upon success.
+/// Return 401 Unauthorized if the token is unknown, expired or already used.
+/// Return 422 with the errors keyed by field name if the address has been
+/// taken by another user since.
+#[post("verify-email")]
+async fn verify_email(
+    pool: web::Data<PgPool>,
+    verification: web::Json<EmailVerificationDto>,
+) -> Result<HttpResponse, AppError> {
+    let verification = consume_email_verification(&pool, &hash_token(&verification.user.token))
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => {
+                AppError::Unauthorized("Invalid verification token.".into())
+            },
+            e => e.into(),
+        })?;
+
+    set_verified_email(&pool, verification.user_id, &verification.email)
+        .await
+        .map_err(AppError::when_unique_violation(USERS_UNIQUE_CONSTRAINTS))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// The `POST /api/user/verify-email/resend` endpoint. **Requires
+/// authentication.**
+/// Emails a new verification token: to the new address of the user if it
+/// changed its email, to its current address otherwise.
+/// Return 202 Accepted upon success.
+/// Return 409 Conflict if the email of the user is already verified (and not
+/// being changed).
+/// Return 401 Unauthorized (by the authentication middleware) if there is not
+/// a valid authentication.
+#[post("/verify-email/resend")]
+async fn resend_email_verification(
+    user: middlewares::AuthenticatedUser,
+    pool: web::Data<PgPool>,
+    lifetimes: web::Data<TokenLifetimes>,
+    mailer: web::Data<dyn Mailer>,
+) -> Result<HttpResponse, AppError> {
+    let email = match get_pending_email(&pool, user.user.id).await {
+        Ok(email) => email,
+        Err(sqlx::Error::RowNotFound) if !user.user.email_verified => user.user.email.clone(),
+        Err(sqlx::Error::RowNotFound) => {
+            return Err(AppError::Conflict(
+                "Your email address is already verified.".into(),
+            ))
+        },
+        Err(e) => return Err(e.into()),
+    };
+
+    send_email_verification(
+        &pool,
+        mailer.as_ref(),
+        lifetimes.email_verification,
+        user.user.id,
+        &email,
+    )
+    .await?;
+
+    Ok(HttpResponse::Accepted().finish())
+}
@@ -0,0 +1,21 @@
+//! The well-known request handlers (RFC 8615) are served at the root of the
+//! application, outside of the `/api` prefix:
+//! - Get the public keys used to verify the JWTs
+//!   (`GET /.well-known/jwks.json`).
+
+use actix_web::{get, web, HttpResponse};
+
+use crate::{domain::auth::JwtKeys, dtos::jwks_response_dto::JwksResponseDto};
+
+/// The `GET /.well-known/jwks.json` endpoint.
+/// Returns 200 with the public keys used to verify the JWTs (empty if the
+/// JWTs are signed with a shared secret).
+#[get("/jwks.json")]
+async fn jwks(jwt_keys: web::Data<JwtKeys>) -> HttpResponse {
+    HttpResponse::Ok().json(JwksResponseDto::new(jwt_keys.public_keys()))
+}
+
+/// Configure the well-known services (`/.well-known/...` endpoints).
+pub fn config_well_known(cfg: &mut web::ServiceConfig) {
+    cfg.service(jwks);
+}
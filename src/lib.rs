@@ -2,6 +2,7 @@ pub mod configuration;
 pub mod domain;
 pub mod dtos;
 pub mod handlers;
+pub mod mailer;
 pub mod middlewares;
 pub mod repositories;
 pub mod startup;
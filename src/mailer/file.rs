@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use actix_web::web;
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use super::{Email, Mailer};
+use crate::domain::auth::opaque_token::generate_token;
+
+/// A [`Mailer`] writing each email to a new file of a directory (created if
+/// needed), in a format close to the one of an actual email:
+///
+/// ```text
+/// From: Conduit <noreply@conduit.local>
+/// To: jake@jake.com
+/// Subject: ...
+///
+/// ...
+/// ```
+///
+/// The files are named after the date at which the emails are sent, so that
+/// they are listed in order.
+pub struct FileMailer {
+    sender: String,
+    directory: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(sender: &str, directory: &str) -> Self {
+        Self {
+            sender: sender.to_string(),
+            directory: PathBuf::from(directory),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, email: &Email) -> Result<(), String> {
+        let content = format!(
+            "From: {}\nTo: {}\nSubject: {}\n\n{}\n",
+            self.sender, email.to, email.subject, email.body
+        );
+        let directory = self.directory.clone();
+        let path = directory.join(format!(
+            "{}-{}.eml",
+            OffsetDateTime::now_utc().unix_timestamp_nanos(),
+            &generate_token()[..8]
+        ));
+
+        web::block(move || {
+            std::fs::create_dir_all(&directory)?;
+            std::fs::write(&path, content)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Unable to write the email: {e}"))
+    }
+}
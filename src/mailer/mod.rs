@@ -0,0 +1,44 @@
+//! This module deals with sending emails to the users (e.g. password reset
+//! tokens). The handlers send them through a [`Mailer`], chosen at startup
+//! from the configuration:
+//! - An [`SmtpMailer`] relaying them to an SMTP server ;
+//! - A [`FileMailer`] writing them to a directory instead, for development
+//!   and tests.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::configuration::MailerSettings;
+
+pub mod file;
+pub mod smtp;
+
+pub use file::FileMailer;
+pub use smtp::SmtpMailer;
+
+/// An email (in plain text) to send to a user.
+#[derive(Debug)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// A way of delivering emails.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    /// Sends the given email. Returns an error if it could not be delivered.
+    async fn send(&self, email: &Email) -> Result<(), String>;
+}
+
+/// Returns the [`Mailer`] described by the given settings: an [`SmtpMailer`]
+/// if an SMTP server is configured, a [`FileMailer`] otherwise.
+pub fn mailer_from_settings(settings: &MailerSettings) -> Result<Arc<dyn Mailer>, String> {
+    let mailer: Arc<dyn Mailer> = match &settings.smtp {
+        Some(smtp) => Arc::new(SmtpMailer::new(&settings.sender, smtp)?),
+        None => Arc::new(FileMailer::new(&settings.sender, &settings.directory)),
+    };
+
+    Ok(mailer)
+}
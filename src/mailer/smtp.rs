@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+
+use super::{Email, Mailer};
+use crate::configuration::SmtpSettings;
+
+/// A [`Mailer`] relaying the emails to an SMTP server.
+pub struct SmtpMailer {
+    sender: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    /// Creates a mailer sending the emails from the given sender (e.g.
+    /// `Conduit <noreply@conduit.io>`) through the given SMTP server. The
+    /// connection is only opened when the first email is sent.
+    pub fn new(sender: &str, settings: &SmtpSettings) -> Result<Self, String> {
+        let sender = sender
+            .parse()
+            .map_err(|e| format!("Invalid email sender: {e}"))?;
+
+        let mut builder = if settings.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&settings.host)
+                .map_err(|e| format!("Invalid SMTP server: {e}"))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&settings.host)
+        }
+        .port(settings.port);
+
+        if let (Some(username), Some(password)) = (&settings.username, &settings.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            sender,
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: &Email) -> Result<(), String> {
+        let to = email
+            .to
+            .parse()
+            .map_err(|e| format!("Invalid email recipient: {e}"))?;
+
+        let message = Message::builder()
+            .from(self.sender.clone())
+            .to(to)
+            .subject(&email.subject)
+            .body(email.body.clone())
+            .map_err(|e| format!("Unable to build the email: {e}"))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Unable to send the email: {e}"))
+    }
+}
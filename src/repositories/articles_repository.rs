@@ -0,0 +1,403 @@
+//! This module interacts primarily with the "articles" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use super::tags_repository::set_article_tags;
+use crate::domain::{
+    articles::{ArticleUpdateRequest, ArticlesFilter, NewArticle},
+    pagination::Pagination,
+};
+
+/// This struct represents an Article as stored in the database (without the
+/// table's unique ID), along with its author's profile and its favorites.
+/// `favorited` tells whether the viewer of the article has favorited it
+/// (always false when there is no viewer).
+/// `author_following` tells whether the viewer of the article follows its
+/// author. It is [`None`] when there is no viewer (anonymous request).
+pub struct Article {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub tag_list: Vec<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub favorited: bool,
+    pub favorites_count: i64,
+    pub author_username: String,
+    pub author_bio: Option<String>,
+    pub author_image: Option<String>,
+    pub author_following: Option<bool>,
+}
+
+/// Inserts a new valid article written by `author_id` in the database, along
+/// with its tags. If an article with the same slug already exists, this
+/// operation fails.
+pub async fn insert_new_article(
+    pool: &PgPool,
+    author_id: i32,
+    article: &NewArticle,
+) -> Result<(), sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let record = sqlx::query!(
+        r#"
+        INSERT INTO articles (slug, title, description, body, author_id)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+        article.slug.as_ref(),
+        article.title.as_ref(),
+        article.description,
+        article.body,
+        author_id,
+    )
+    .fetch_one(&mut transaction)
+    .await?;
+
+    set_article_tags(&mut transaction, record.id, &article.tag_list).await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+/// Returns an article by its slug, as seen by the (optional) `viewer`.
+/// Returns an error if the article does not exist, or if its author has
+/// blocked the viewer.
+pub async fn get_article_by_slug(
+    pool: &PgPool,
+    slug: &str,
+    viewer: Option<i32>,
+) -> Result<Article, sqlx::Error> {
+    let article = sqlx::query_as!(
+        Article,
+        r#"
+        SELECT a.slug, a.title, a.description, a.body,
+            ARRAY(
+                SELECT t.name
+                FROM article_tags at
+                JOIN tags t ON t.id = at.tag_id
+                WHERE at.article_id = a.id
+                ORDER BY t.name
+            ) AS "tag_list!",
+            a.created_at, a.updated_at,
+            EXISTS(
+                SELECT 1
+                FROM favorites fav
+                WHERE fav.article_id = a.id
+                    AND fav.user_id = $2
+            ) AS "favorited!",
+            (
+                SELECT COUNT(*)
+                FROM favorites fav
+                WHERE fav.article_id = a.id
+            ) AS "favorites_count!",
+            u.username AS author_username,
+            u.bio AS author_bio,
+            u.image AS author_image,
+            CASE WHEN $2::INTEGER IS NULL THEN NULL
+                ELSE EXISTS(
+                    SELECT 1
+                    FROM followers f
+                    WHERE f.follower_id = $2
+                        AND f.followed_id = a.author_id
+                )
+            END AS author_following
+        FROM articles a
+        JOIN users u ON u.id = a.author_id
+        WHERE a.slug = $1
+            AND NOT EXISTS(
+                SELECT 1
+                FROM blocks b
+                WHERE b.blocker_id = a.author_id
+                    AND b.blocked_id = $2
+            )
+        "#,
+        slug,
+        viewer,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(article)
+}
+
+/// Returns a page of articles matching the given filter, most recent first, as
+/// seen by the (optional) `viewer`: the articles of the authors who have
+/// blocked the viewer are left out. The `following` flag of each author is
+/// computed within the same query.
+pub async fn list_articles(
+    pool: &PgPool,
+    filter: &ArticlesFilter,
+    pagination: &Pagination,
+    viewer: Option<i32>,
+) -> Result<Vec<Article>, sqlx::Error> {
+    let articles = sqlx::query_as!(
+        Article,
+        r#"
+        SELECT a.slug, a.title, a.description, a.body,
+            ARRAY(
+                SELECT t.name
+                FROM article_tags at
+                JOIN tags t ON t.id = at.tag_id
+                WHERE at.article_id = a.id
+                ORDER BY t.name
+            ) AS "tag_list!",
+            a.created_at, a.updated_at,
+            EXISTS(
+                SELECT 1
+                FROM favorites fav
+                WHERE fav.article_id = a.id
+                    AND fav.user_id = $4
+            ) AS "favorited!",
+            (
+                SELECT COUNT(*)
+                FROM favorites fav
+                WHERE fav.article_id = a.id
+            ) AS "favorites_count!",
+            u.username AS author_username,
+            u.bio AS author_bio,
+            u.image AS author_image,
+            CASE WHEN $4::INTEGER IS NULL THEN NULL
+                ELSE EXISTS(
+                    SELECT 1
+                    FROM followers f
+                    WHERE f.follower_id = $4
+                        AND f.followed_id = a.author_id
+                )
+            END AS author_following
+        FROM articles a
+        JOIN users u ON u.id = a.author_id
+        WHERE ($1::TEXT IS NULL OR EXISTS(
+                SELECT 1
+                FROM article_tags at
+                JOIN tags t ON t.id = at.tag_id
+                WHERE at.article_id = a.id
+                    AND t.name = $1
+            ))
+            AND ($2::TEXT IS NULL OR EXISTS(
+                SELECT 1
+                FROM users au
+                WHERE au.id = a.author_id
+                    AND au.username = $2
+            ))
+            AND ($3::TEXT IS NULL OR EXISTS(
+                SELECT 1
+                FROM favorites fav
+                JOIN users fu ON fu.id = fav.user_id
+                WHERE fav.article_id = a.id
+                    AND fu.username = $3
+            ))
+            AND NOT EXISTS(
+                SELECT 1
+                FROM blocks b
+                WHERE b.blocker_id = a.author_id
+                    AND b.blocked_id = $4
+            )
+        ORDER BY a.created_at DESC, a.id DESC
+        LIMIT $5
+        OFFSET $6
+        "#,
+        filter.tag,
+        filter.author,
+        filter.favorited,
+        viewer,
+        pagination.limit,
+        pagination.offset,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(articles)
+}
+
+/// Returns the total count of articles matching the given filter, as seen by
+/// the (optional) `viewer` (see [`list_articles`]).
+pub async fn count_articles(
+    pool: &PgPool,
+    filter: &ArticlesFilter,
+    viewer: Option<i32>,
+) -> Result<i64, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "count!"
+        FROM articles a
+        WHERE ($1::TEXT IS NULL OR EXISTS(
+                SELECT 1
+                FROM article_tags at
+                JOIN tags t ON t.id = at.tag_id
+                WHERE at.article_id = a.id
+                    AND t.name = $1
+            ))
+            AND ($2::TEXT IS NULL OR EXISTS(
+                SELECT 1
+                FROM users au
+                WHERE au.id = a.author_id
+                    AND au.username = $2
+            ))
+            AND ($3::TEXT IS NULL OR EXISTS(
+                SELECT 1
+                FROM favorites fav
+                JOIN users fu ON fu.id = fav.user_id
+                WHERE fav.article_id = a.id
+                    AND fu.username = $3
+            ))
+            AND NOT EXISTS(
+                SELECT 1
+                FROM blocks b
+                WHERE b.blocker_id = a.author_id
+                    AND b.blocked_id = $4
+            )
+        "#,
+        filter.tag,
+        filter.author,
+        filter.favorited,
+        viewer,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.count)
+}
+
+/// Returns a page of articles written by the users followed by `follower_id`,
+/// most recent first.
+pub async fn list_feed_articles(
+    pool: &PgPool,
+    follower_id: i32,
+    pagination: &Pagination,
+) -> Result<Vec<Article>, sqlx::Error> {
+    let articles = sqlx::query_as!(
+        Article,
+        r#"
+        SELECT a.slug, a.title, a.description, a.body,
+            ARRAY(
+                SELECT t.name
+                FROM article_tags at
+                JOIN tags t ON t.id = at.tag_id
+                WHERE at.article_id = a.id
+                ORDER BY t.name
+            ) AS "tag_list!",
+            a.created_at, a.updated_at,
+            EXISTS(
+                SELECT 1
+                FROM favorites fav
+                WHERE fav.article_id = a.id
+                    AND fav.user_id = $1
+            ) AS "favorited!",
+            (
+                SELECT COUNT(*)
+                FROM favorites fav
+                WHERE fav.article_id = a.id
+            ) AS "favorites_count!",
+            u.username AS author_username,
+            u.bio AS author_bio,
+            u.image AS author_image,
+            TRUE AS author_following
+        FROM followers f
+        JOIN articles a ON a.author_id = f.followed_id
+        JOIN users u ON u.id = a.author_id
+        WHERE f.follower_id = $1
+        ORDER BY a.created_at DESC, a.id DESC
+        LIMIT $2
+        OFFSET $3
+        "#,
+        follower_id,
+        pagination.limit,
+        pagination.offset,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(articles)
+}
+
+/// Returns the total count of articles written by the users followed by
+/// `follower_id`.
+pub async fn count_feed_articles(pool: &PgPool, follower_id: i32) -> Result<i64, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "count!"
+        FROM followers f
+        JOIN articles a ON a.author_id = f.followed_id
+        WHERE f.follower_id = $1
+        "#,
+        follower_id,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.count)
+}
+
+/// Returns the ID of the author of an article given its slug.
+/// Returns an error if the article does not exist.
+pub async fn get_article_author_id(pool: &PgPool, slug: &str) -> Result<i32, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT author_id
+        FROM articles
+        WHERE slug = $1
+        "#,
+        slug
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.author_id)
+}
+
+/// Update the values (and the tags) of an article given its current slug.
+/// Return the slug of the article affected (may be new).
+pub async fn update_article_by_slug(
+    pool: &PgPool,
+    slug: &str,
+    updated: &ArticleUpdateRequest,
+) -> Result<String, sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let record = sqlx::query!(
+        r#"
+        UPDATE articles
+        SET slug = COALESCE($2, slug),
+            title = COALESCE($3, title),
+            description = COALESCE($4, description),
+            body = COALESCE($5, body),
+            updated_at = now()
+        WHERE slug = $1
+        RETURNING id, slug
+        "#,
+        slug,
+        updated.slug.as_ref().map(AsRef::<String>::as_ref),
+        updated.title.as_ref().map(AsRef::<String>::as_ref),
+        updated.description,
+        updated.body,
+    )
+    .fetch_one(&mut transaction)
+    .await?;
+
+    if let Some(tag_list) = &updated.tag_list {
+        set_article_tags(&mut transaction, record.id, tag_list).await?;
+    }
+
+    transaction.commit().await?;
+
+    Ok(record.slug)
+}
+
+/// Deletes an article given its slug.
+pub async fn delete_article_by_slug(pool: &PgPool, slug: &str) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM articles
+        WHERE slug = $1
+        "#,
+        slug
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
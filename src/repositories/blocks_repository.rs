@@ -0,0 +1,123 @@
+//! This module interacts primarily with the "blocks" table.
+
+use sqlx::PgPool;
+
+use super::followers_repository::ListedProfile;
+use crate::domain::pagination::CursorPagination;
+
+/// Returns true if `user1` has blocked `user2` (given their IDs).
+pub async fn is_blocking(pool: &PgPool, user1: i32, user2: i32) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1
+            FROM blocks
+            WHERE blocker_id = $1
+                AND blocked_id = $2
+        ) AS "blocking!"
+        "#,
+        user1,
+        user2
+    )
+    .fetch_one(pool)
+    .await
+}
+
+/// Makes `user1` block `user2` (given their IDs): the follow relationships
+/// (and the pending follow requests) between them, in both directions, are
+/// removed. Blocking an user already blocked does nothing.
+pub async fn block(pool: &PgPool, user1: i32, user2: i32) -> Result<(), sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO blocks (blocker_id, blocked_id)
+        VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+        user1,
+        user2
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM followers
+        WHERE (follower_id = $1 AND followed_id = $2)
+            OR (follower_id = $2 AND followed_id = $1)
+        "#,
+        user1,
+        user2
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM follow_requests
+        WHERE (requester_id = $1 AND requested_id = $2)
+            OR (requester_id = $2 AND requested_id = $1)
+        "#,
+        user1,
+        user2
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    transaction.commit().await
+}
+
+/// Makes `user1` unblock `user2` (given their IDs).
+pub async fn unblock(pool: &PgPool, user1: i32, user2: i32) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM blocks
+        WHERE blocker_id = $1
+            AND blocked_id = $2
+        "#,
+        user1,
+        user2
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns a page of the users blocked by the user with the given ID, most
+/// recently blocked first. At most `pagination.limit + 1` profiles are
+/// returned, so that the caller can tell whether there is a next page. The
+/// blocker does not follow them, so their `following` field is always false.
+pub async fn list_blocks(
+    pool: &PgPool,
+    user_id: i32,
+    pagination: &CursorPagination,
+) -> Result<Vec<ListedProfile>, sqlx::Error> {
+    let (after_date, after_id) = pagination
+        .after
+        .as_ref()
+        .map(|cursor| (cursor.date, cursor.id))
+        .unzip();
+
+    sqlx::query_as!(
+        ListedProfile,
+        r#"
+        SELECT u.id, u.username, u.bio, u.image,
+            false AS following,
+            b.created_at AS listed_at
+        FROM blocks b
+        JOIN users u ON u.id = b.blocked_id
+        WHERE b.blocker_id = $1
+            AND ($2::TIMESTAMPTZ IS NULL OR (b.created_at, b.blocked_id) < ($2, $3))
+        ORDER BY b.created_at DESC, b.blocked_id DESC
+        LIMIT $4
+        "#,
+        user_id,
+        after_date,
+        after_id,
+        pagination.limit + 1,
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,163 @@
+//! This module interacts primarily with the "comments" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::domain::comments::NewComment;
+
+/// This struct represents a Comment as stored in the database (without the
+/// article it belongs to), along with its author's profile.
+/// `author_following` tells whether the viewer of the comment follows its
+/// author. It is [`None`] when there is no viewer (anonymous request).
+pub struct Comment {
+    pub id: i32,
+    pub body: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub author_username: String,
+    pub author_bio: Option<String>,
+    pub author_image: Option<String>,
+    pub author_following: Option<bool>,
+}
+
+/// Inserts a new valid comment written by `author_id` on the article with the
+/// given slug. Returns the ID of the new comment, or
+/// [`sqlx::Error::RowNotFound`] if the article does not exist.
+pub async fn insert_new_comment(
+    pool: &PgPool,
+    slug: &str,
+    author_id: i32,
+    comment: &NewComment,
+) -> Result<i32, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        INSERT INTO comments (body, article_id, author_id)
+        SELECT $1, id, $3
+        FROM articles
+        WHERE slug = $2
+        RETURNING id
+        "#,
+        comment.body,
+        slug,
+        author_id,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.id)
+}
+
+/// Returns a comment by its ID, as seen by the (optional) `viewer`.
+/// Returns an error if the comment does not exist.
+pub async fn get_comment_by_id(
+    pool: &PgPool,
+    id: i32,
+    viewer: Option<i32>,
+) -> Result<Comment, sqlx::Error> {
+    let comment = sqlx::query_as!(
+        Comment,
+        r#"
+        SELECT c.id, c.body, c.created_at, c.updated_at,
+            u.username AS author_username,
+            u.bio AS author_bio,
+            u.image AS author_image,
+            CASE WHEN $2::INTEGER IS NULL THEN NULL
+                ELSE EXISTS(
+                    SELECT 1
+                    FROM followers f
+                    WHERE f.follower_id = $2
+                        AND f.followed_id = c.author_id
+                )
+            END AS author_following
+        FROM comments c
+        JOIN users u ON u.id = c.author_id
+        WHERE c.id = $1
+        "#,
+        id,
+        viewer,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(comment)
+}
+
+/// Returns all the comments of the article with the given slug, oldest
+/// first, as seen by the (optional) `viewer`: none if the author of the
+/// article has blocked the viewer, and never those of the commenters who have
+/// blocked the viewer.
+pub async fn list_comments_by_slug(
+    pool: &PgPool,
+    slug: &str,
+    viewer: Option<i32>,
+) -> Result<Vec<Comment>, sqlx::Error> {
+    let comments = sqlx::query_as!(
+        Comment,
+        r#"
+        SELECT c.id, c.body, c.created_at, c.updated_at,
+            u.username AS author_username,
+            u.bio AS author_bio,
+            u.image AS author_image,
+            CASE WHEN $2::INTEGER IS NULL THEN NULL
+                ELSE EXISTS(
+                    SELECT 1
+                    FROM followers f
+                    WHERE f.follower_id = $2
+                        AND f.followed_id = c.author_id
+                )
+            END AS author_following
+        FROM comments c
+        JOIN articles a ON a.id = c.article_id
+        JOIN users u ON u.id = c.author_id
+        WHERE a.slug = $1
+            AND NOT EXISTS(
+                SELECT 1
+                FROM blocks b
+                WHERE b.blocker_id IN (a.author_id, c.author_id)
+                    AND b.blocked_id = $2
+            )
+        ORDER BY c.created_at, c.id
+        "#,
+        slug,
+        viewer,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(comments)
+}
+
+/// Returns the ID of the author of a comment given its ID and the slug of its
+/// article. Returns an error if there is no such comment on this article.
+pub async fn get_comment_author_id(pool: &PgPool, slug: &str, id: i32) -> Result<i32, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT c.author_id
+        FROM comments c
+        JOIN articles a ON a.id = c.article_id
+        WHERE a.slug = $1
+            AND c.id = $2
+        "#,
+        slug,
+        id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.author_id)
+}
+
+/// Deletes a comment given its ID.
+pub async fn delete_comment_by_id(pool: &PgPool, id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM comments
+        WHERE id = $1
+        "#,
+        id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! This module interacts primarily with the "email_verifications" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+/// This struct represents a pending email verification, without its token.
+pub struct EmailVerification {
+    pub user_id: i32,
+    pub email: String,
+}
+
+/// Inserts the (hashed) token verifying the given email of an user, replacing
+/// its pending verification if any. The expired verifications are cleaned
+/// up.
+pub async fn insert_email_verification(
+    pool: &PgPool,
+    user_id: i32,
+    email: &str,
+    token_hash: &str,
+    expires_at: OffsetDateTime,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO email_verifications (token_hash, user_id, email, expires_at)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id) DO UPDATE
+        SET token_hash = EXCLUDED.token_hash,
+            email = EXCLUDED.email,
+            expires_at = EXCLUDED.expires_at,
+            created_at = now()
+        "#,
+        token_hash,
+        user_id,
+        email,
+        expires_at
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM email_verifications
+        WHERE expires_at < now()
+        "#
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns the email of the pending verification of an user. Returns an error
+/// if there is none (or if it has expired).
+pub async fn get_pending_email(pool: &PgPool, user_id: i32) -> Result<String, sqlx::Error> {
+    let email = sqlx::query!(
+        r#"
+        SELECT email
+        FROM email_verifications
+        WHERE user_id = $1 AND expires_at > now()
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?
+    .email;
+
+    Ok(email)
+}
+
+/// Consumes the email verification with the given token hash: it is deleted,
+/// so that it cannot be used twice. Returns an error if the token does not
+/// exist or has expired.
+pub async fn consume_email_verification(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<EmailVerification, sqlx::Error> {
+    let verification = sqlx::query!(
+        r#"
+        DELETE FROM email_verifications
+        WHERE token_hash = $1
+        RETURNING user_id, email, expires_at
+        "#,
+        token_hash
+    )
+    .fetch_optional(pool)
+    .await?
+    .filter(|verification| verification.expires_at > OffsetDateTime::now_utc())
+    .map(|verification| EmailVerification {
+        user_id: verification.user_id,
+        email: verification.email,
+    })
+    .ok_or(sqlx::Error::RowNotFound)?;
+
+    Ok(verification)
+}
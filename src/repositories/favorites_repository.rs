@@ -0,0 +1,40 @@
+//! This module interacts primarily with the "favorites" table.
+
+use sqlx::PgPool;
+
+/// Make favorite the article with the given slug by `user_id`.
+pub async fn favorite(pool: &PgPool, user_id: i32, slug: &str) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO favorites (user_id, article_id)
+        SELECT $1, id
+        FROM articles
+        WHERE slug = $2
+        "#,
+        user_id,
+        slug
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Make unfavorite the article with the given slug by `user_id`.
+pub async fn unfavorite(pool: &PgPool, user_id: i32, slug: &str) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM favorites
+        USING articles
+        WHERE favorites.article_id = articles.id
+            AND favorites.user_id = $1
+            AND articles.slug = $2
+        "#,
+        user_id,
+        slug
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! This module interacts primarily with the "follow_requests" table.
+
+use sqlx::PgPool;
+
+use super::followers_repository::ListedProfile;
+use crate::domain::pagination::CursorPagination;
+
+/// Returns true if `user1` has requested to follow `user2` (given their IDs),
+/// and the request is still pending.
+pub async fn is_follow_pending(pool: &PgPool, user1: i32, user2: i32) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1
+            FROM follow_requests
+            WHERE requester_id = $1
+                AND requested_id = $2
+        ) AS "pending!"
+        "#,
+        user1,
+        user2
+    )
+    .fetch_one(pool)
+    .await
+}
+
+/// Makes `user1` request to follow `user2` (given their IDs). Fails if such a
+/// request is already pending.
+pub async fn request_follow(pool: &PgPool, user1: i32, user2: i32) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO follow_requests (requester_id, requested_id)
+        VALUES ($1, $2)
+        "#,
+        user1,
+        user2
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Cancels the request of `user1` to follow `user2` (given their IDs), if
+/// any.
+pub async fn cancel_follow_request(
+    pool: &PgPool,
+    user1: i32,
+    user2: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM follow_requests
+        WHERE requester_id = $1
+            AND requested_id = $2
+        "#,
+        user1,
+        user2
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Approves the request of `requester` to follow `requested` (given their IDs):
+/// `requester` now follows `requested`. Returns an error if there is no such
+/// request.
+pub async fn approve_follow_request(
+    pool: &PgPool,
+    requested: i32,
+    requester: i32,
+) -> Result<(), sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM follow_requests
+        WHERE requester_id = $1
+            AND requested_id = $2
+        "#,
+        requester,
+        requested
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+        requester,
+        requested
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    transaction.commit().await
+}
+
+/// Rejects the request of `requester` to follow `requested` (given their
+/// IDs). Returns an error if there is no such request.
+pub async fn reject_follow_request(
+    pool: &PgPool,
+    requested: i32,
+    requester: i32,
+) -> Result<(), sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM follow_requests
+        WHERE requester_id = $1
+            AND requested_id = $2
+        "#,
+        requester,
+        requested
+    )
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    Ok(())
+}
+
+/// Approves every pending request to follow `requested` (given its ID).
+pub async fn approve_all_follow_requests(pool: &PgPool, requested: i32) -> Result<(), sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT requester_id, requested_id
+        FROM follow_requests
+        WHERE requested_id = $1
+        ON CONFLICT DO NOTHING
+        "#,
+        requested
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM follow_requests
+        WHERE requested_id = $1
+        "#,
+        requested
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    transaction.commit().await
+}
+
+/// Returns a page of the pending requests to follow the user with the given
+/// ID, most recent first. At most `pagination.limit + 1` profiles are
+/// returned, so that the caller can tell whether there is a next page.
+/// `following` tells whether this user follows the requesters.
+pub async fn list_follow_requests(
+    pool: &PgPool,
+    user_id: i32,
+    pagination: &CursorPagination,
+) -> Result<Vec<ListedProfile>, sqlx::Error> {
+    let (after_date, after_id) = pagination
+        .after
+        .as_ref()
+        .map(|cursor| (cursor.date, cursor.id))
+        .unzip();
+
+    sqlx::query_as!(
+        ListedProfile,
+        r#"
+        SELECT u.id, u.username, u.bio, u.image,
+            EXISTS(
+                SELECT 1
+                FROM followers f
+                WHERE f.follower_id = $1
+                    AND f.followed_id = u.id
+            ) AS following,
+            r.created_at AS listed_at
+        FROM follow_requests r
+        JOIN users u ON u.id = r.requester_id
+        WHERE r.requested_id = $1
+            AND ($2::TIMESTAMPTZ IS NULL OR (r.created_at, r.requester_id) < ($2, $3))
+        ORDER BY r.created_at DESC, r.requester_id DESC
+        LIMIT $4
+        "#,
+        user_id,
+        after_date,
+        after_id,
+        pagination.limit + 1,
+    )
+    .fetch_all(pool)
+    .await
+}
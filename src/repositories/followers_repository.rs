@@ -1,15 +1,32 @@
 //! This module interacts primarily with the "followers" table.
 
 use sqlx::PgPool;
+use time::OffsetDateTime;
 
-/// Returns true if `user1` is following `user2`.
-pub async fn is_following(pool: &PgPool, user1: &str, user2: &str) -> Result<bool, sqlx::Error> {
+use crate::domain::pagination::CursorPagination;
+
+/// This struct represents a profile listed among the followers, the followed
+/// users or the blocked users of a user, along with the date it was added to
+/// the list (e.g. the date of the follow relationship).
+/// `following` tells whether the viewer of the list follows this profile. It
+/// is [`None`] when there is no viewer (anonymous request).
+pub struct ListedProfile {
+    pub id: i32,
+    pub username: String,
+    pub bio: Option<String>,
+    pub image: Option<String>,
+    pub following: Option<bool>,
+    pub listed_at: OffsetDateTime,
+}
+
+/// Returns true if `user1` is following `user2` (given their IDs).
+pub async fn is_following(pool: &PgPool, user1: i32, user2: i32) -> Result<bool, sqlx::Error> {
     match sqlx::query!(
         r#"
         SELECT *
         FROM followers
-        WHERE follower = $1
-            AND followed = $2
+        WHERE follower_id = $1
+            AND followed_id = $2
         "#,
         user1,
         user2
@@ -25,11 +42,11 @@ pub async fn is_following(pool: &PgPool, user1: &str, user2: &str) -> Result<boo
     }
 }
 
-/// Make follow `user2` by `user1`.
-pub async fn follow(pool: &PgPool, user1: &str, user2: &str) -> Result<(), sqlx::Error> {
+/// Make follow `user2` by `user1` (given their IDs).
+pub async fn follow(pool: &PgPool, user1: i32, user2: i32) -> Result<(), sqlx::Error> {
     sqlx::query!(
         r#"
-        INSERT INTO followers (follower, followed)
+        INSERT INTO followers (follower_id, followed_id)
         VALUES ($1, $2)
         "#,
         user1,
@@ -41,13 +58,13 @@ pub async fn follow(pool: &PgPool, user1: &str, user2: &str) -> Result<(), sqlx:
     Ok(())
 }
 
-/// Make unfollow `user2` by `user1`.
-pub async fn unfollow(pool: &PgPool, user1: &str, user2: &str) -> Result<(), sqlx::Error> {
+/// Make unfollow `user2` by `user1` (given their IDs).
+pub async fn unfollow(pool: &PgPool, user1: i32, user2: i32) -> Result<(), sqlx::Error> {
     sqlx::query!(
         r#"
         DELETE FROM followers
-        WHERE follower = $1
-            AND followed = $2
+        WHERE follower_id = $1
+            AND followed_id = $2
         "#,
         user1,
         user2
@@ -57,3 +74,110 @@ pub async fn unfollow(pool: &PgPool, user1: &str, user2: &str) -> Result<(), sql
 
     Ok(())
 }
+
+/// Returns a page of the followers of the user with the given ID, most recent
+/// first. At most `pagination.limit + 1` profiles are returned, so that the
+/// caller can tell whether there is a next page.
+pub async fn list_followers(
+    pool: &PgPool,
+    user_id: i32,
+    viewer: Option<i32>,
+    pagination: &CursorPagination,
+) -> Result<Vec<ListedProfile>, sqlx::Error> {
+    let (after_date, after_id) = pagination
+        .after
+        .as_ref()
+        .map(|cursor| (cursor.date, cursor.id))
+        .unzip();
+
+    sqlx::query_as!(
+        ListedProfile,
+        r#"
+        SELECT u.id, u.username, u.bio, u.image,
+            CASE WHEN $2::INTEGER IS NULL THEN NULL
+                ELSE EXISTS(
+                    SELECT 1
+                    FROM followers vf
+                    WHERE vf.follower_id = $2
+                        AND vf.followed_id = u.id
+                )
+            END AS following,
+            f.created_at AS listed_at
+        FROM followers f
+        JOIN users u ON u.id = f.follower_id
+        WHERE f.followed_id = $1
+            AND ($3::TIMESTAMPTZ IS NULL OR (f.created_at, f.follower_id) < ($3, $4))
+        ORDER BY f.created_at DESC, f.follower_id DESC
+        LIMIT $5
+        "#,
+        user_id,
+        viewer,
+        after_date,
+        after_id,
+        pagination.limit + 1,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Returns a page of the users followed by the user with the given ID, most
+/// recent first. At most `pagination.limit + 1` profiles are returned, so that
+/// the caller can tell whether there is a next page.
+pub async fn list_following(
+    pool: &PgPool,
+    user_id: i32,
+    viewer: Option<i32>,
+    pagination: &CursorPagination,
+) -> Result<Vec<ListedProfile>, sqlx::Error> {
+    let (after_date, after_id) = pagination
+        .after
+        .as_ref()
+        .map(|cursor| (cursor.date, cursor.id))
+        .unzip();
+
+    sqlx::query_as!(
+        ListedProfile,
+        r#"
+        SELECT u.id, u.username, u.bio, u.image,
+            CASE WHEN $2::INTEGER IS NULL THEN NULL
+                ELSE EXISTS(
+                    SELECT 1
+                    FROM followers vf
+                    WHERE vf.follower_id = $2
+                        AND vf.followed_id = u.id
+                )
+            END AS following,
+            f.created_at AS listed_at
+        FROM followers f
+        JOIN users u ON u.id = f.followed_id
+        WHERE f.follower_id = $1
+            AND ($3::TIMESTAMPTZ IS NULL OR (f.created_at, f.followed_id) < ($3, $4))
+        ORDER BY f.created_at DESC, f.followed_id DESC
+        LIMIT $5
+        "#,
+        user_id,
+        viewer,
+        after_date,
+        after_id,
+        pagination.limit + 1,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Returns the number of followers of the user with the given ID and the
+/// number of users it follows.
+pub async fn count_follows(pool: &PgPool, user_id: i32) -> Result<(i64, i64), sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM followers WHERE followed_id = $1) AS "followers!",
+            (SELECT COUNT(*) FROM followers WHERE follower_id = $1) AS "following!"
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok((record.followers, record.following))
+}
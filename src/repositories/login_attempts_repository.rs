@@ -0,0 +1,119 @@
+//! This module interacts primarily with the "login_attempts" table.
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+
+/// This struct represents the failed login attempts counted for a key, as
+/// stored in the database.
+pub struct LoginAttempts {
+    pub failed_attempts: i32,
+    pub last_failure_at: OffsetDateTime,
+    pub locked_until: Option<OffsetDateTime>,
+}
+
+/// Returns the failed login attempts counted for the given key, if any.
+pub async fn get_login_attempts(
+    pool: &PgPool,
+    key: &str,
+) -> Result<Option<LoginAttempts>, sqlx::Error> {
+    sqlx::query_as!(
+        LoginAttempts,
+        r#"
+        SELECT failed_attempts, last_failure_at, locked_until
+        FROM login_attempts
+        WHERE key = $1
+        "#,
+        key
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+/// Returns the latest date until which any of the given keys is locked, if
+/// one of them is currently locked.
+pub async fn get_locked_until(
+    pool: &PgPool,
+    keys: &[String],
+) -> Result<Option<OffsetDateTime>, sqlx::Error> {
+    let locked_until = sqlx::query!(
+        r#"
+        SELECT MAX(locked_until) AS locked_until
+        FROM login_attempts
+        WHERE key = ANY($1) AND locked_until > now()
+        "#,
+        keys
+    )
+    .fetch_one(pool)
+    .await?
+    .locked_until;
+
+    Ok(locked_until)
+}
+
+/// Records a failed login attempt for the given key and returns the number of
+/// consecutive failed attempts. The previous failures are forgotten if the
+/// last one is older than `forget_after`.
+pub async fn record_failed_login(
+    pool: &PgPool,
+    key: &str,
+    forget_after: Duration,
+) -> Result<i32, sqlx::Error> {
+    let forget_before = OffsetDateTime::now_utc() - forget_after;
+
+    let failed_attempts = sqlx::query!(
+        r#"
+        INSERT INTO login_attempts (key, failed_attempts)
+        VALUES ($1, 1)
+        ON CONFLICT (key) DO UPDATE
+        SET failed_attempts = CASE
+                WHEN login_attempts.last_failure_at < $2 THEN 1
+                ELSE login_attempts.failed_attempts + 1
+            END,
+            last_failure_at = now()
+        RETURNING failed_attempts
+        "#,
+        key,
+        forget_before
+    )
+    .fetch_one(pool)
+    .await?
+    .failed_attempts;
+
+    Ok(failed_attempts)
+}
+
+/// Locks the given key until the given date.
+pub async fn lock_login(
+    pool: &PgPool,
+    key: &str,
+    locked_until: OffsetDateTime,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE login_attempts
+        SET locked_until = $2
+        WHERE key = $1
+        "#,
+        key,
+        locked_until
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Forgets the failed login attempts of the given keys.
+pub async fn reset_login_attempts(pool: &PgPool, keys: &[String]) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM login_attempts
+        WHERE key = ANY($1)
+        "#,
+        keys
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -1,5 +1,19 @@
 //! This module is dealing with interactions with the underlying PostgreSQL
 //! database.
 
+pub mod articles_repository;
+pub mod blocks_repository;
+pub mod comments_repository;
+pub mod email_verifications_repository;
+pub mod favorites_repository;
+pub mod follow_requests_repository;
 pub mod followers_repository;
+pub mod login_attempts_repository;
+pub mod password_reset_tokens_repository;
+pub mod personal_access_tokens_repository;
+pub mod refresh_tokens_repository;
+pub mod revoked_tokens_repository;
+pub mod tags_repository;
+pub mod two_factor_repository;
+pub mod user_export_repository;
 pub mod user_repository;
@@ -0,0 +1,64 @@
+//! This module interacts primarily with the "password_reset_tokens" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+/// Inserts the (hashed) password reset token of an user, replacing its
+/// previous one if any. The expired tokens are cleaned up.
+pub async fn insert_password_reset_token(
+    pool: &PgPool,
+    user_id: i32,
+    token_hash: &str,
+    expires_at: OffsetDateTime,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO password_reset_tokens (token_hash, user_id, expires_at)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id) DO UPDATE
+        SET token_hash = EXCLUDED.token_hash,
+            expires_at = EXCLUDED.expires_at,
+            created_at = now()
+        "#,
+        token_hash,
+        user_id,
+        expires_at
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM password_reset_tokens
+        WHERE expires_at < now()
+        "#
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Consumes the password reset token with the given hash: the token is
+/// deleted, so that it cannot be used twice. Returns the ID of its user, or an
+/// error if the token does not exist or has expired.
+pub async fn consume_password_reset_token(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<i32, sqlx::Error> {
+    let user_id = sqlx::query!(
+        r#"
+        DELETE FROM password_reset_tokens
+        WHERE token_hash = $1
+        RETURNING user_id, expires_at
+        "#,
+        token_hash
+    )
+    .fetch_optional(pool)
+    .await?
+    .filter(|token| token.expires_at > OffsetDateTime::now_utc())
+    .map(|token| token.user_id)
+    .ok_or(sqlx::Error::RowNotFound)?;
+
+    Ok(user_id)
+}
@@ -0,0 +1,141 @@
+//! This module interacts primarily with the "personal_access_tokens" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::domain::auth::personal_access_token::NewPersonalAccessToken;
+
+/// The unique constraints of the "personal_access_tokens" table, with the
+/// field each one applies to.
+pub const PERSONAL_ACCESS_TOKENS_UNIQUE_CONSTRAINTS: &[(&str, &str)] =
+    &[("personal_access_tokens_user_id_name_key", "name")];
+
+/// This struct represents a personal access token as stored in the database
+/// (without its hash).
+pub struct PersonalAccessToken {
+    pub id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<OffsetDateTime>,
+    pub last_used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+/// The owner and the scopes of a personal access token used for
+/// authentication.
+pub struct PersonalAccessTokenGrant {
+    pub user_id: i32,
+    pub scopes: Vec<String>,
+}
+
+/// Stores a new personal access token of the given user. Returns the stored
+/// token.
+pub async fn insert_personal_access_token(
+    pool: &PgPool,
+    user_id: i32,
+    token: &NewPersonalAccessToken,
+) -> Result<PersonalAccessToken, sqlx::Error> {
+    let scopes: Vec<String> = token.scopes.iter().map(|s| s.as_str().to_owned()).collect();
+
+    let token = sqlx::query_as!(
+        PersonalAccessToken,
+        r#"
+        INSERT INTO personal_access_tokens (user_id, name, token_hash, scopes, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, name, scopes, expires_at, last_used_at, created_at
+        "#,
+        user_id,
+        token.name,
+        token.hash,
+        &scopes,
+        token.expires_at,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(token)
+}
+
+/// Returns the personal access tokens of the given user, newest first
+/// (expired ones included).
+pub async fn list_user_personal_access_tokens(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<PersonalAccessToken>, sqlx::Error> {
+    let tokens = sqlx::query_as!(
+        PersonalAccessToken,
+        r#"
+        SELECT id, name, scopes, expires_at, last_used_at, created_at
+        FROM personal_access_tokens
+        WHERE user_id = $1
+        ORDER BY created_at DESC, id DESC
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(tokens)
+}
+
+/// Deletes the personal access token with the given ID, if it belongs to the
+/// given user. Returns [`sqlx::Error::RowNotFound`] otherwise.
+pub async fn delete_personal_access_token(
+    pool: &PgPool,
+    id: i32,
+    user_id: i32,
+) -> Result<(), sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM personal_access_tokens WHERE id = $1 AND user_id = $2",
+        id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    Ok(())
+}
+
+/// Deletes every personal access token of the given user (e.g. when it logs
+/// out everywhere).
+pub async fn delete_user_personal_access_tokens(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "DELETE FROM personal_access_tokens WHERE user_id = $1",
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns the owner and the scopes of the unexpired personal access token
+/// with the given hash, and records its use. Returns an error if there is no
+/// such token.
+pub async fn use_personal_access_token(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<PersonalAccessTokenGrant, sqlx::Error> {
+    let grant = sqlx::query_as!(
+        PersonalAccessTokenGrant,
+        r#"
+        UPDATE personal_access_tokens
+        SET last_used_at = now()
+        WHERE token_hash = $1
+            AND (expires_at IS NULL OR expires_at > now())
+        RETURNING user_id, scopes
+        "#,
+        token_hash
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(grant)
+}
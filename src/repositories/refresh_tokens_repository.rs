@@ -0,0 +1,109 @@
+//! This module interacts primarily with the "refresh_tokens" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::domain::auth::NewRefreshToken;
+
+/// This struct represents a refresh token as stored in the database.
+pub struct RefreshToken {
+    pub id: i32,
+    pub family: String,
+    pub user_id: i32,
+    pub expires_at: OffsetDateTime,
+}
+
+/// Stores a newly issued refresh token of the given user.
+pub async fn insert_refresh_token(
+    pool: &PgPool,
+    user_id: i32,
+    refresh_token: &NewRefreshToken,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO refresh_tokens (token_hash, family, user_id, expires_at)
+        VALUES ($1, $2, $3, $4)
+        "#,
+        refresh_token.hash,
+        refresh_token.family,
+        user_id,
+        refresh_token.expires_at,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns a refresh token by its hash. Returns an error if it does not exist.
+pub async fn get_refresh_token_by_hash(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<RefreshToken, sqlx::Error> {
+    let refresh_token = sqlx::query_as!(
+        RefreshToken,
+        r#"
+        SELECT id, family, user_id, expires_at
+        FROM refresh_tokens
+        WHERE token_hash = $1
+        "#,
+        token_hash
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(refresh_token)
+}
+
+/// Marks the refresh token with the given ID as used, so that it cannot be
+/// used again. Returns false if it was already used or has been revoked.
+pub async fn mark_refresh_token_used(pool: &PgPool, id: i32) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE refresh_tokens
+        SET used_at = now()
+        WHERE id = $1
+            AND used_at IS NULL
+            AND revoked_at IS NULL
+        "#,
+        id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+/// Revokes every refresh token of the given family.
+pub async fn revoke_refresh_token_family(pool: &PgPool, family: &str) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE refresh_tokens
+        SET revoked_at = now()
+        WHERE family = $1
+            AND revoked_at IS NULL
+        "#,
+        family
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Revokes every refresh token of the given user.
+pub async fn revoke_user_refresh_tokens(pool: &PgPool, user_id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE refresh_tokens
+        SET revoked_at = now()
+        WHERE user_id = $1
+            AND revoked_at IS NULL
+        "#,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! This module interacts primarily with the "revoked_tokens" table.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+/// Revokes the access token with the given ID (`jti` claim) until the given
+/// date, i.e. until it is not accepted anymore: its expiration plus the
+/// tolerated clock skew. The revoked tokens that have expired since are
+/// cleaned up.
+pub async fn revoke_token(
+    pool: &PgPool,
+    jti: &str,
+    expires_at: OffsetDateTime,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO revoked_tokens (jti, expires_at)
+        VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+        jti,
+        expires_at
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM revoked_tokens
+        WHERE expires_at < now()
+        "#
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns true if the access token with the given ID (`jti` claim) has been
+/// revoked by itself (the tokens revoked all at once are told apart by their
+/// version instead, see [`crate::repositories::user_repository::revoke_user_tokens`]).
+pub async fn is_token_revoked(pool: &PgPool, jti: &str) -> Result<bool, sqlx::Error> {
+    let revoked = sqlx::query!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM revoked_tokens WHERE jti = $1
+        ) AS "revoked!"
+        "#,
+        jti
+    )
+    .fetch_one(pool)
+    .await?
+    .revoked;
+
+    Ok(revoked)
+}
@@ -0,0 +1,72 @@
+//! This module interacts primarily with the "tags" and "article_tags" tables.
+
+use sqlx::{PgConnection, PgPool};
+
+use crate::domain::articles::Tag;
+
+/// Returns every tag in use (i.e. attached to at least one article), in
+/// alphabetical order.
+pub async fn get_tags(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+    let records = sqlx::query!(
+        r#"
+        SELECT t.name
+        FROM tags t
+        WHERE EXISTS(
+            SELECT 1
+            FROM article_tags at
+            WHERE at.tag_id = t.id
+        )
+        ORDER BY t.name
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records.into_iter().map(|r| r.name).collect())
+}
+
+/// Replaces the tags of the article with the given ID. Missing tags are
+/// created on the fly. Meant to be called within a transaction.
+pub async fn set_article_tags(
+    connection: &mut PgConnection,
+    article_id: i32,
+    tags: &[Tag],
+) -> Result<(), sqlx::Error> {
+    let names: Vec<String> = tags.iter().map(|tag| tag.as_ref().to_owned()).collect();
+
+    sqlx::query!(
+        r#"
+        DELETE FROM article_tags
+        WHERE article_id = $1
+        "#,
+        article_id
+    )
+    .execute(&mut *connection)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO tags (name)
+        SELECT * FROM UNNEST($1::TEXT[])
+        ON CONFLICT (name) DO NOTHING
+        "#,
+        &names
+    )
+    .execute(&mut *connection)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO article_tags (article_id, tag_id)
+        SELECT $1, id
+        FROM tags
+        WHERE name = ANY($2)
+        "#,
+        article_id,
+        &names
+    )
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
+}
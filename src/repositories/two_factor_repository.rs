@@ -0,0 +1,268 @@
+//! This module interacts primarily with the "user_totp",
+//! "totp_recovery_codes" and "login_challenges" tables.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+/// This struct represents the TOTP secret of a user as stored in the
+/// database.
+pub struct UserTotp {
+    /// The base32-encoded secret.
+    pub secret: String,
+    /// The date at which two-factor authentication has been enabled, [`None`]
+    /// while the secret is pending confirmation.
+    pub enabled_at: Option<OffsetDateTime>,
+    /// The time step of the last code used.
+    pub last_used_step: Option<i64>,
+}
+
+/// Returns the TOTP secret of the given user. Returns an error if the user
+/// has none.
+pub async fn get_user_totp(pool: &PgPool, user_id: i32) -> Result<UserTotp, sqlx::Error> {
+    let totp = sqlx::query_as!(
+        UserTotp,
+        r#"
+        SELECT secret, enabled_at, last_used_step
+        FROM user_totp
+        WHERE user_id = $1
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(totp)
+}
+
+/// Returns true if the given user has enabled two-factor authentication.
+pub async fn is_two_factor_enabled(pool: &PgPool, user_id: i32) -> Result<bool, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM user_totp WHERE user_id = $1 AND enabled_at IS NOT NULL
+        ) AS "enabled!"
+        "#,
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.enabled)
+}
+
+/// Stores a new pending TOTP secret for the given user, replacing any
+/// pending one. Returns [`sqlx::Error::RowNotFound`] if two-factor
+/// authentication is already enabled.
+pub async fn set_pending_totp_secret(
+    pool: &PgPool,
+    user_id: i32,
+    secret: &str,
+) -> Result<(), sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO user_totp (user_id, secret)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id) DO UPDATE
+        SET secret = EXCLUDED.secret, last_used_step = NULL
+        WHERE user_totp.enabled_at IS NULL
+        "#,
+        user_id,
+        secret
+    )
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    Ok(())
+}
+
+/// Records the time step of a code just used by the given user, unless a
+/// code of this step (or a later one) has already been used. Returns false
+/// in that case (i.e. the code is replayed).
+pub async fn record_totp_step(pool: &PgPool, user_id: i32, step: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE user_totp
+        SET last_used_step = $2
+        WHERE user_id = $1
+            AND (last_used_step IS NULL OR last_used_step < $2)
+        "#,
+        user_id,
+        step
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+/// Enables two-factor authentication for the given user with its pending
+/// secret, and replaces its recovery codes with the given ones (hashed).
+pub async fn enable_two_factor(
+    pool: &PgPool,
+    user_id: i32,
+    recovery_code_hashes: &[String],
+) -> Result<(), sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query!(
+        "UPDATE user_totp SET enabled_at = now() WHERE user_id = $1",
+        user_id
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM totp_recovery_codes WHERE user_id = $1",
+        user_id
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO totp_recovery_codes (user_id, code_hash)
+        SELECT $1, UNNEST($2::TEXT[])
+        "#,
+        user_id,
+        recovery_code_hashes
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    transaction.commit().await
+}
+
+/// Disables two-factor authentication for the given user: its secret, its
+/// recovery codes and its pending login challenges are deleted.
+pub async fn disable_two_factor(pool: &PgPool, user_id: i32) -> Result<(), sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    for query in [
+        sqlx::query!("DELETE FROM user_totp WHERE user_id = $1", user_id),
+        sqlx::query!(
+            "DELETE FROM totp_recovery_codes WHERE user_id = $1",
+            user_id
+        ),
+        sqlx::query!("DELETE FROM login_challenges WHERE user_id = $1", user_id),
+    ] {
+        query.execute(&mut transaction).await?;
+    }
+
+    transaction.commit().await
+}
+
+/// Marks the unused recovery code with the given hash of the given user as
+/// used. Returns false if there is no such code.
+pub async fn use_recovery_code(
+    pool: &PgPool,
+    user_id: i32,
+    code_hash: &str,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE totp_recovery_codes
+        SET used_at = now()
+        WHERE id = (
+            SELECT id FROM totp_recovery_codes
+            WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+            LIMIT 1
+        )
+        "#,
+        user_id,
+        code_hash
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+/// Stores a new login challenge for the given user. Expired challenges are
+/// cleaned up.
+pub async fn insert_login_challenge(
+    pool: &PgPool,
+    user_id: i32,
+    token_hash: &str,
+    expires_at: OffsetDateTime,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!("DELETE FROM login_challenges WHERE expires_at < now()")
+        .execute(pool)
+        .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO login_challenges (token_hash, user_id, expires_at)
+        VALUES ($1, $2, $3)
+        "#,
+        token_hash,
+        user_id,
+        expires_at
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns the ID of the user of the unexpired login challenge with the given
+/// hash. Returns an error if there is no such challenge.
+pub async fn get_login_challenge_user(pool: &PgPool, token_hash: &str) -> Result<i32, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT user_id
+        FROM login_challenges
+        WHERE token_hash = $1 AND expires_at > now()
+        "#,
+        token_hash
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(record.user_id)
+}
+
+/// Records a failed attempt on the login challenge with the given hash, and
+/// deletes it once it reaches the given maximum number of attempts.
+pub async fn record_login_challenge_failure(
+    pool: &PgPool,
+    token_hash: &str,
+    max_attempts: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE login_challenges
+        SET failed_attempts = failed_attempts + 1
+        WHERE token_hash = $1
+        "#,
+        token_hash
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM login_challenges WHERE token_hash = $1 AND failed_attempts >= $2",
+        token_hash,
+        max_attempts
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Deletes the login challenge with the given hash (i.e. once it has been
+/// completed). Returns false if it did not exist anymore.
+pub async fn delete_login_challenge(pool: &PgPool, token_hash: &str) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM login_challenges WHERE token_hash = $1",
+        token_hash
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() == 1)
+}
@@ -0,0 +1,236 @@
+//! This module gathers everything stored about an user, for its data export.
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use super::{
+    login_attempts_repository::{get_login_attempts, LoginAttempts},
+    personal_access_tokens_repository::{list_user_personal_access_tokens, PersonalAccessToken},
+    two_factor_repository::is_two_factor_enabled,
+    user_repository::{get_user_by_id, User},
+};
+use crate::domain::auth::lockout::account_key;
+
+/// Everything stored about an user (except its secrets: password and token
+/// hashes, TOTP secret...).
+pub struct UserExport {
+    pub user: User,
+    /// The new email address it has yet to verify, if any.
+    pub pending_email: Option<String>,
+    pub two_factor_enabled: bool,
+    /// The usernames of the users it follows.
+    pub following: Vec<String>,
+    /// The usernames of the users following it.
+    pub followers: Vec<String>,
+    /// The usernames of the users it blocked.
+    pub blocks: Vec<String>,
+    /// The usernames of the users it requested to follow (still pending).
+    pub follow_requests_sent: Vec<String>,
+    /// The usernames of the users requesting to follow it (still pending).
+    pub follow_requests_received: Vec<String>,
+    pub articles: Vec<ExportedArticle>,
+    pub comments: Vec<ExportedComment>,
+    /// The slugs of the articles it favorited.
+    pub favorites: Vec<String>,
+    pub personal_access_tokens: Vec<PersonalAccessToken>,
+    /// Its login sessions (one per refresh token), oldest first.
+    pub sessions: Vec<ExportedSession>,
+    /// The failed login attempts on its account, if any.
+    pub login_attempts: Option<LoginAttempts>,
+}
+
+/// An article written by the exported user.
+pub struct ExportedArticle {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub tag_list: Vec<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+/// A comment written by the exported user, with the slug of its article.
+pub struct ExportedComment {
+    pub id: i32,
+    pub article_slug: String,
+    pub body: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+/// A login session of the exported user, i.e. a refresh token (without its
+/// hash).
+pub struct ExportedSession {
+    pub created_at: OffsetDateTime,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+/// Returns everything stored about the given user. Returns an error if the
+/// user does not exist.
+pub async fn get_user_export(pool: &PgPool, user_id: i32) -> Result<UserExport, sqlx::Error> {
+    let user = get_user_by_id(pool, user_id).await?;
+    let two_factor_enabled = is_two_factor_enabled(pool, user_id).await?;
+
+    let pending_email = sqlx::query_scalar!(
+        r#"
+        SELECT email
+        FROM email_verifications
+        WHERE user_id = $1
+            AND email <> $2
+        "#,
+        user_id,
+        user.email
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let following = sqlx::query_scalar!(
+        r#"
+        SELECT u.username
+        FROM followers f
+        JOIN users u ON u.id = f.followed_id
+        WHERE f.follower_id = $1
+        ORDER BY u.username
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let followers = sqlx::query_scalar!(
+        r#"
+        SELECT u.username
+        FROM followers f
+        JOIN users u ON u.id = f.follower_id
+        WHERE f.followed_id = $1
+        ORDER BY u.username
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let blocks = sqlx::query_scalar!(
+        r#"
+        SELECT u.username
+        FROM blocks b
+        JOIN users u ON u.id = b.blocked_id
+        WHERE b.blocker_id = $1
+        ORDER BY u.username
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let follow_requests_sent = sqlx::query_scalar!(
+        r#"
+        SELECT u.username
+        FROM follow_requests r
+        JOIN users u ON u.id = r.requested_id
+        WHERE r.requester_id = $1
+        ORDER BY u.username
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let follow_requests_received = sqlx::query_scalar!(
+        r#"
+        SELECT u.username
+        FROM follow_requests r
+        JOIN users u ON u.id = r.requester_id
+        WHERE r.requested_id = $1
+        ORDER BY u.username
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let articles = sqlx::query_as!(
+        ExportedArticle,
+        r#"
+        SELECT a.slug, a.title, a.description, a.body,
+            ARRAY(
+                SELECT t.name
+                FROM article_tags at
+                JOIN tags t ON t.id = at.tag_id
+                WHERE at.article_id = a.id
+                ORDER BY t.name
+            ) AS "tag_list!",
+            a.created_at, a.updated_at
+        FROM articles a
+        WHERE a.author_id = $1
+        ORDER BY a.created_at
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let comments = sqlx::query_as!(
+        ExportedComment,
+        r#"
+        SELECT c.id, a.slug AS article_slug, c.body, c.created_at, c.updated_at
+        FROM comments c
+        JOIN articles a ON a.id = c.article_id
+        WHERE c.author_id = $1
+        ORDER BY c.created_at
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let favorites = sqlx::query_scalar!(
+        r#"
+        SELECT a.slug
+        FROM favorites fav
+        JOIN articles a ON a.id = fav.article_id
+        WHERE fav.user_id = $1
+        ORDER BY a.slug
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let personal_access_tokens = list_user_personal_access_tokens(pool, user_id).await?;
+
+    let sessions = sqlx::query_as!(
+        ExportedSession,
+        r#"
+        SELECT created_at, expires_at, used_at, revoked_at
+        FROM refresh_tokens
+        WHERE user_id = $1
+        ORDER BY created_at, id
+        "#,
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let login_attempts = get_login_attempts(pool, &account_key(&user.email)).await?;
+
+    Ok(UserExport {
+        user,
+        pending_email,
+        two_factor_enabled,
+        following,
+        followers,
+        blocks,
+        follow_requests_sent,
+        follow_requests_received,
+        articles,
+        comments,
+        favorites,
+        personal_access_tokens,
+        sessions,
+        login_attempts,
+    })
+}
@@ -1,44 +1,80 @@
 //! This module interacts primarily with the "users" table.
 
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, QueryBuilder};
 
 use crate::domain::users::{NewUser, UserUpdateRequest};
 
-/// This struct represents an User as stored in the database (without
-/// the table's unique ID and the password).
+/// The unique constraints of the "users" table, associated to the field they
+/// apply to.
+pub const USERS_UNIQUE_CONSTRAINTS: &[(&str, &str)] = &[
+    ("users_username_key", "username"),
+    ("users_email_key", "email"),
+];
+
+/// This struct represents an User as stored in the database (without the
+/// password).
 pub struct User {
+    pub id: i32,
     pub username: String,
     pub email: String,
     pub bio: Option<String>,
     pub image: Option<String>,
+    /// Whether the user has proved that it owns its email address.
+    pub email_verified: bool,
+    /// Whether the user approves its followers (see the follow requests).
+    pub private: bool,
+    /// The version of the access tokens of the user, incremented to revoke
+    /// them all at once.
+    pub token_version: i32,
 }
 
 /// This struct represents an User as stored in the database with its hashed
-/// password (without the table's unique ID).
+/// password.
 pub struct UserWithPassword {
+    pub id: i32,
     pub username: String,
     pub email: String,
     pub password: String,
     pub bio: Option<String>,
     pub image: Option<String>,
+    pub token_version: i32,
 }
 
 /// Inserts a new valid user in the database. If the user already exists in the
 /// database, this operation might fail (same username and/or same email).
-pub async fn insert_new_user(pool: &PgPool, user: &NewUser) -> Result<(), sqlx::Error> {
-    sqlx::query!(
+/// Returns the ID of the new user.
+pub async fn insert_new_user(pool: &PgPool, user: &NewUser) -> Result<i32, sqlx::Error> {
+    let id = sqlx::query_scalar!(
         r#"
         INSERT INTO users (username, email, password)
         VALUES ($1, $2, $3)
+        RETURNING id
         "#,
         user.username.as_ref(),
         user.email.as_ref(),
         user.password,
     )
-    .execute(pool)
+    .fetch_one(pool)
     .await?;
 
-    Ok(())
+    Ok(id)
+}
+
+/// Returns a user by its ID. Returns an error if the user does not exist.
+pub async fn get_user_by_id(pool: &PgPool, id: i32) -> Result<User, sqlx::Error> {
+    let user = sqlx::query_as!(
+        User,
+        r#"
+        SELECT id, username, email, bio, image, email_verified, private, token_version
+        FROM users
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(user)
 }
 
 /// Returns a user by its username. Returns an error if the user does not exist.
@@ -46,7 +82,7 @@ pub async fn get_user_by_username(pool: &PgPool, username: &str) -> Result<User,
     let user = sqlx::query_as!(
         User,
         r#"
-        SELECT username, email, bio, image
+        SELECT id, username, email, bio, image, email_verified, private, token_version
         FROM users
         WHERE username = $1
         "#,
@@ -67,7 +103,7 @@ pub async fn get_user_with_password_by_email(
     let user = sqlx::query_as!(
         UserWithPassword,
         r#"
-        SELECT username, email, password, bio, image
+        SELECT id, username, email, password, bio, image, token_version
         FROM users
         WHERE email = $1
         "#,
@@ -79,43 +115,186 @@ pub async fn get_user_with_password_by_email(
     Ok(user)
 }
 
-/// Update the values of an user given its current username.
-/// Return the username of the user affected (may be new).
-pub async fn update_user(
+/// Replaces the (hashed) password of an user given its ID.
+pub async fn update_password(
+    pool: &PgPool,
+    user_id: i32,
+    password: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET password = $2
+        WHERE id = $1
+        "#,
+        user_id,
+        password
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Returns true if an user other than the given one (if any) has the given
+/// username.
+pub async fn is_username_taken(
     pool: &PgPool,
     username: &str,
+    except_id: Option<i32>,
+) -> Result<bool, sqlx::Error> {
+    let taken = sqlx::query!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM users WHERE username = $1 AND id IS DISTINCT FROM $2
+        ) AS "taken!"
+        "#,
+        username,
+        except_id
+    )
+    .fetch_one(pool)
+    .await?
+    .taken;
+
+    Ok(taken)
+}
+
+/// Returns true if an user other than the given one (if any) has the given
+/// email.
+pub async fn is_email_taken(
+    pool: &PgPool,
+    email: &str,
+    except_id: Option<i32>,
+) -> Result<bool, sqlx::Error> {
+    let taken = sqlx::query!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM users WHERE email = $1 AND id IS DISTINCT FROM $2
+        ) AS "taken!"
+        "#,
+        email,
+        except_id
+    )
+    .fetch_one(pool)
+    .await?
+    .taken;
+
+    Ok(taken)
+}
+
+/// Sets the email of an user given its ID, as verified. This operation
+/// might fail if another user has this email.
+pub async fn set_verified_email(
+    pool: &PgPool,
+    user_id: i32,
+    email: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET email = $2, email_verified = true
+        WHERE id = $1
+        "#,
+        user_id,
+        email
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Deletes an user given its ID, along with everything that belongs to it
+/// (follow relationships, articles, comments, favorites, tokens...). Returns
+/// an error if the user does not exist.
+pub async fn delete_user(pool: &PgPool, user_id: i32) -> Result<(), sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM users
+        WHERE id = $1
+        "#,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
+    }
+
+    Ok(())
+}
+
+/// Revokes every access token of an user issued until now, given its ID, by
+/// incrementing its token version: the tokens carrying the former version are
+/// rejected from now on.
+pub async fn revoke_user_tokens(pool: &PgPool, user_id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET token_version = token_version + 1
+        WHERE id = $1
+        "#,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Update the values of an user given its ID.
+/// Only the provided values are updated, and every value is bound as a query
+/// parameter.
+pub async fn update_user(
+    pool: &PgPool,
+    user_id: i32,
     updated: &UserUpdateRequest,
-) -> Result<String, sqlx::Error> {
-    // Generate the `SET ...` string
-    let mut properties_to_set = Vec::new();
+) -> Result<(), sqlx::Error> {
+    // Gather the columns to update with their new value
+    let mut properties_to_set: Vec<(&str, &String)> = Vec::new();
+    let mut flags_to_set: Vec<(&str, bool)> = Vec::new();
     if let Some(updated_username) = &updated.username {
-        properties_to_set.push(format!("username = '{}'", updated_username.as_ref()));
+        properties_to_set.push(("username", updated_username.as_ref()));
     }
     if let Some(updated_email) = &updated.email {
-        properties_to_set.push(format!("email = '{}'", updated_email.as_ref()));
+        properties_to_set.push(("email", updated_email.as_ref()));
     }
     if let Some(updated_password) = &updated.password {
-        properties_to_set.push(format!("password = '{updated_password}'"));
+        properties_to_set.push(("password", updated_password));
     }
     if let Some(updated_bio) = &updated.bio {
-        properties_to_set.push(format!("bio = '{updated_bio}'"));
+        properties_to_set.push(("bio", updated_bio));
     }
     if let Some(updated_image) = &updated.image {
-        properties_to_set.push(format!("image = '{updated_image}'"));
+        properties_to_set.push(("image", updated_image));
+    }
+    if let Some(updated_private) = updated.private {
+        flags_to_set.push(("private", updated_private));
     }
-    let properties_to_set = properties_to_set.join(",");
 
-    sqlx::query(&format!(
-        "UPDATE users SET {} WHERE username = $1",
-        properties_to_set
-    ))
-    .bind(username)
-    .execute(pool)
-    .await?;
+    // Generate the `SET ...` clause, binding every value
+    let mut query = QueryBuilder::<Postgres>::new("UPDATE users SET ");
+    let mut separator = "";
+    for (column, value) in properties_to_set {
+        query
+            .push(separator)
+            .push(column)
+            .push(" = ")
+            .push_bind(value);
+        separator = ", ";
+    }
+    for (column, value) in flags_to_set {
+        query
+            .push(separator)
+            .push(column)
+            .push(" = ")
+            .push_bind(value);
+        separator = ", ";
+    }
+    query.push(" WHERE id = ").push_bind(user_id);
 
-    Ok(if let Some(new_username) = &updated.username {
-        new_username.as_ref().into()
-    } else {
-        username.into()
-    })
+    query.build().execute(pool).await?;
+
+    Ok(())
 }
@@ -6,15 +6,21 @@
 //! To summarize, an [`Application`] structure is built from the ground up given
 //! a specific configuration (address to bind to, database settings...).
 
-use std::net::TcpListener;
+use std::{net::TcpListener, sync::Arc};
 
-use actix_web::{dev::Server, error, middleware::Logger, web, App, HttpServer};
+use actix_web::{dev::Server, middleware::Logger, web, App, HttpServer};
 use sqlx::{postgres::PgPoolOptions, PgPool};
 
 use crate::{
     configuration::{DatabaseSettings, Settings},
-    domain::{auth::JwtSecret, error::validation_error},
-    handlers, middlewares,
+    domain::{
+        auth::{lockout::LoginLockoutPolicy, JwtKeys, TokenLifetimes},
+        error::AppError,
+        users::email_verification::UnverifiedEmailPolicy,
+    },
+    handlers,
+    mailer::{mailer_from_settings, Mailer},
+    middlewares,
 };
 
 /// This structure mainly holds the server ready to serve requests, as well as
@@ -34,9 +40,31 @@ impl Application {
         let listener = TcpListener::bind(address)?;
         let port = listener.local_addr().unwrap().port();
 
-        let jwt_secret = JwtSecret(configuration.app.jwt_secret);
-
-        let server = build_server(listener, db_pool, jwt_secret)?;
+        let jwt_keys = JwtKeys::from_settings(&configuration.app)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+        let lifetimes = TokenLifetimes {
+            access: time::Duration::seconds(configuration.app.access_token_ttl),
+            refresh: time::Duration::seconds(configuration.app.refresh_token_ttl),
+            password_reset: time::Duration::seconds(configuration.app.password_reset_token_ttl),
+            email_verification: time::Duration::seconds(
+                configuration.app.email_verification_token_ttl,
+            ),
+        };
+
+        let lockout = LoginLockoutPolicy::from(&configuration.app.login_lockout);
+
+        let mailer = mailer_from_settings(&configuration.mailer)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+
+        let server = build_server(
+            listener,
+            db_pool,
+            jwt_keys,
+            lifetimes,
+            lockout,
+            mailer,
+            configuration.app.unverified_email_policy,
+        )?;
 
         Ok(Self { port, server })
     }
@@ -60,14 +88,24 @@ pub fn get_connection_pool(configuration: &DatabaseSettings) -> PgPool {
 }
 
 /// Builds a server ready to serve, listening on the given listener and
-/// encapsulating data like a database connection pool and a JWT shared secret.
+/// encapsulating data like a database connection pool, the JWT keys, the
+/// lifetimes of the issued tokens, the login lockout policy, the mailer and
+/// the policy applied to the users whose email is not verified.
 fn build_server(
     listener: TcpListener,
     db_pool: PgPool,
-    jwt_secret: JwtSecret,
+    jwt_keys: JwtKeys,
+    lifetimes: TokenLifetimes,
+    lockout: LoginLockoutPolicy,
+    mailer: Arc<dyn Mailer>,
+    unverified_email_policy: UnverifiedEmailPolicy,
 ) -> Result<Server, std::io::Error> {
     let db_pool = web::Data::new(db_pool);
-    let jwt_secret = web::Data::new(jwt_secret);
+    let jwt_keys = web::Data::new(jwt_keys);
+    let lifetimes = web::Data::new(lifetimes);
+    let lockout = web::Data::new(lockout);
+    let mailer: web::Data<dyn Mailer> = web::Data::from(mailer);
+    let unverified_email_policy = web::Data::new(unverified_email_policy);
 
     // Custom Json extractor configuration
     let json_cfg = web::JsonConfig::default()
@@ -75,19 +113,26 @@ fn build_server(
         .content_type(|mime| mime == mime::APPLICATION_JSON)
         // Use custom error handler that returns 422 status code and proper
         // error response
-        .error_handler(|err, _| {
-            let response = validation_error(&format!("{}", err));
-            error::InternalError::from_response(err, response).into()
-        });
+        .error_handler(|err, _| AppError::Validation(err.to_string()).into());
+
+    // Custom Query extractor configuration, with the same error handler
+    let query_cfg = web::QueryConfig::default()
+        .error_handler(|err, _| AppError::Validation(err.to_string()).into());
 
     let server = HttpServer::new(move || {
         App::new()
             .wrap(Logger::default())
             .wrap(middlewares::AuthenticationMiddlewareFactory)
             .service(web::scope("/api").configure(handlers::config))
+            .service(web::scope("/.well-known").configure(handlers::well_known::config_well_known))
             .app_data(json_cfg.clone())
+            .app_data(query_cfg.clone())
             .app_data(db_pool.clone())
-            .app_data(jwt_secret.clone())
+            .app_data(jwt_keys.clone())
+            .app_data(lifetimes.clone())
+            .app_data(lockout.clone())
+            .app_data(mailer.clone())
+            .app_data(unverified_email_policy.clone())
     })
     .listen(listener)?
     .run();
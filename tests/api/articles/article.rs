@@ -0,0 +1,114 @@
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body, helpers::spawn_app, profiles::block::block_user,
+};
+
+async fn get_article(address: &str, slug: &str, token: Option<&str>) -> reqwest::Response {
+    let mut request = reqwest::Client::new().get(format!("{address}/api/articles/{slug}"));
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Token {token}"));
+    }
+
+    request.send().await.expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn get_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_article(app.address(), "unknown", None).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn get_article_not_authenticated_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = get_article(app.address(), "title", None).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("Title", body["article"]["title"]);
+    assert_eq!("Desc", body["article"]["description"]);
+    assert_eq!("Body", body["article"]["body"]);
+    assert_eq!("jack", body["article"]["author"]["username"]);
+    assert_eq!(Value::Null, body["article"]["author"]["following"]);
+}
+
+#[actix_rt::test]
+async fn get_article_of_followed_author_should_return_following() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT follower.id, followed.id
+        FROM users follower, users followed
+        WHERE follower.username = $1 AND followed.username = $2
+        "#,
+        "john",
+        "jack"
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to follow.");
+
+    // Act
+    let response = get_article(app.address(), "title", Some(&john_token)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(true), body["article"]["author"]["following"]);
+}
+
+#[actix_rt::test]
+async fn get_article_of_a_blocker_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+    block_user(app.address(), "john", &jack_token).await;
+
+    // Act
+    let response = get_article(app.address(), "title", Some(&john_token)).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    // The article is still visible to the others
+    let response = get_article(app.address(), "title", None).await;
+    assert_eq!(200, response.status().as_u16());
+}
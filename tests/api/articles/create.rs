@@ -0,0 +1,166 @@
+use claim::assert_ok;
+use serde_json::Value;
+
+use crate::helpers::spawn_app;
+
+pub(crate) async fn post_article_with_body(
+    address: &str,
+    body: &str,
+    token: &str,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{address}/api/articles"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(body.to_owned())
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_create_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/articles", app.address()))
+        .header("Content-Type", "application/json")
+        .body(r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn create_valid_article_should_return_201() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = post_article_with_body(
+        app.address(),
+        r#"
+        {
+            "article": {
+                "title": "How to train your dragon",
+                "description": "Ever wonder how?",
+                "body": "You have to believe",
+                "tagList": ["reactjs", "angularjs", "dragons"]
+            }
+        }"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("how-to-train-your-dragon", body["article"]["slug"]);
+    assert_eq!("How to train your dragon", body["article"]["title"]);
+    assert_eq!(3, body["article"]["tagList"].as_array().unwrap().len());
+    assert_eq!("jack", body["article"]["author"]["username"]);
+    assert_eq!(Value::Bool(false), body["article"]["favorited"]);
+    assert_ok!(body["article"]["createdAt"].as_str().ok_or(()));
+
+    let saved = sqlx::query!(
+        r#"
+        SELECT a.title, u.username AS author
+        FROM articles a
+        JOIN users u ON u.id = a.author_id
+        WHERE a.slug = 'how-to-train-your-dragon'
+        "#
+    )
+    .fetch_one(app.db_pool())
+    .await
+    .expect("Failed to fetch article");
+
+    assert_eq!("How to train your dragon", saved.title);
+    assert_eq!("jack", saved.author);
+}
+
+#[actix_rt::test]
+async fn create_article_without_tags_should_return_201() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(0, body["article"]["tagList"].as_array().unwrap().len());
+}
+
+#[actix_rt::test]
+async fn create_invalid_article_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    let test_cases = vec![
+        (
+            r#"{"article":{"title":"","description":"Desc","body":"Body"}}"#,
+            "empty title",
+        ),
+        (
+            r#"{"article":{"title":"Title","description":"","body":"Body"}}"#,
+            "empty description",
+        ),
+        (
+            r#"{"article":{"title":"Title","description":"Desc","body":""}}"#,
+            "empty body",
+        ),
+        (
+            r#"{"article":{"title":"Title","description":"Desc"}}"#,
+            "missing body",
+        ),
+        (
+            r#"{"article":{"title":"Title","description":"Desc","body":"Body","tagList":[""]}}"#,
+            "empty tag",
+        ),
+    ];
+
+    for (body, description) in test_cases {
+        // Act
+        let response = post_article_with_body(app.address(), body, &token).await;
+
+        // Assert
+        assert_eq!(
+            422,
+            response.status().as_u16(),
+            "The API did not fail with 422 when the payload was {}.",
+            description
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn create_article_with_same_title_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let body = r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#;
+
+    // Act
+    let response1 = post_article_with_body(app.address(), body, &token).await;
+    let response2 = post_article_with_body(app.address(), body, &token).await;
+
+    // Assert
+    assert_eq!(201, response1.status().as_u16());
+    assert_eq!(422, response2.status().as_u16());
+}
@@ -0,0 +1,85 @@
+use claim::assert_err;
+
+use crate::{articles::create::post_article_with_body, helpers::spawn_app};
+
+async fn delete_article(address: &str, slug: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{address}/api/articles/{slug}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_delete_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .delete(format!("{}/api/articles/title", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = delete_article(app.address(), "unknown", &token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_article_of_another_author_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+
+    // Act
+    let response = delete_article(app.address(), "title", &john_token).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_own_article_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = delete_article(app.address(), "title", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+    assert_err!(
+        sqlx::query!("SELECT slug FROM articles WHERE slug = 'title'")
+            .fetch_one(app.db_pool())
+            .await
+    );
+}
@@ -0,0 +1,147 @@
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body, helpers::spawn_app, profiles::block::block_user,
+};
+
+pub(crate) async fn favorite_article(address: &str, slug: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{address}/api/articles/{slug}/favorite"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_favorite_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/articles/title/favorite", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn favorite_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = favorite_article(app.address(), "unknown", &token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn regular_favorite_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &jack_token,
+    )
+    .await;
+
+    // Act
+    let response1 = favorite_article(app.address(), "title", &john_token).await;
+    let response2 = favorite_article(app.address(), "title", &jack_token).await;
+
+    // Assert
+    assert_eq!(200, response1.status().as_u16());
+    assert_eq!(200, response2.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response1.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(true), body["article"]["favorited"]);
+    assert_eq!(1, body["article"]["favoritesCount"]);
+
+    let body: Value = serde_json::from_str(&response2.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(true), body["article"]["favorited"]);
+    assert_eq!(2, body["article"]["favoritesCount"]);
+}
+
+#[actix_rt::test]
+async fn already_favorite_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response1 = favorite_article(app.address(), "title", &token).await;
+    let response2 = favorite_article(app.address(), "title", &token).await;
+
+    // Assert
+    assert_eq!(200, response1.status().as_u16());
+    assert_eq!(422, response2.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn favorites_count_is_visible_to_anonymous_users() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &token,
+    )
+    .await;
+    favorite_article(app.address(), "title", &token).await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/articles", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(false), body["articles"][0]["favorited"]);
+    assert_eq!(1, body["articles"][0]["favoritesCount"]);
+}
+
+#[actix_rt::test]
+async fn favorite_article_of_a_blocker_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+    block_user(app.address(), "john", &jack_token).await;
+
+    // Act
+    let response = favorite_article(app.address(), "title", &john_token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    let count = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM favorites"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count favorites.");
+    assert_eq!(0, count);
+}
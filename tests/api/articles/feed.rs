@@ -0,0 +1,99 @@
+use serde_json::Value;
+
+use crate::{articles::create::post_article_with_body, helpers::spawn_app};
+
+async fn get_feed(address: &str, query: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/articles/feed{query}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_feed_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/articles/feed", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn feed_without_follows_should_return_empty_list() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &john_token,
+    )
+    .await;
+
+    // Act
+    let response = get_feed(app.address(), "", &jack_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(0, body["articles"].as_array().unwrap().len());
+    assert_eq!(0, body["articlesCount"]);
+}
+
+#[actix_rt::test]
+async fn feed_should_return_articles_of_followed_users_only() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    let jane_token = app.insert_user("jane").await;
+
+    for (title, token) in [
+        ("One", &john_token),
+        ("Two", &jane_token),
+        ("Three", &john_token),
+    ] {
+        let body = format!(r#"{{"article":{{"title":"{title}","description":"D","body":"B"}}}}"#);
+        post_article_with_body(app.address(), &body, token).await;
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT follower.id, followed.id
+        FROM users follower, users followed
+        WHERE follower.username = $1 AND followed.username = $2
+        "#,
+        "jack",
+        "john"
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to follow.");
+
+    // Act
+    let response = get_feed(app.address(), "?limit=1", &jack_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(1, body["articles"].as_array().unwrap().len());
+    assert_eq!("three", body["articles"][0]["slug"]);
+    assert_eq!(
+        Value::Bool(true),
+        body["articles"][0]["author"]["following"]
+    );
+    assert_eq!(2, body["articlesCount"]);
+}
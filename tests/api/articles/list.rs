@@ -0,0 +1,221 @@
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body, helpers::spawn_app, profiles::block::block_user,
+};
+
+async fn get_articles(address: &str, query: &str, token: Option<&str>) -> reqwest::Response {
+    let mut request = reqwest::Client::new().get(format!("{address}/api/articles{query}"));
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Token {token}"));
+    }
+
+    request.send().await.expect("Failed to execute request.")
+}
+
+/// Publishes three articles: two by jack (tagged `rust`) and one by john.
+async fn publish_articles(address: &str, jack_token: &str, john_token: &str) {
+    post_article_with_body(
+        address,
+        r#"{"article":{"title":"First","description":"D","body":"B","tagList":["rust"]}}"#,
+        jack_token,
+    )
+    .await;
+    post_article_with_body(
+        address,
+        r#"{"article":{"title":"Second","description":"D","body":"B","tagList":["web"]}}"#,
+        john_token,
+    )
+    .await;
+    post_article_with_body(
+        address,
+        r#"{"article":{"title":"Third","description":"D","body":"B","tagList":["rust"]}}"#,
+        jack_token,
+    )
+    .await;
+}
+
+#[actix_rt::test]
+async fn list_without_articles_should_return_empty_list() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_articles(app.address(), "", None).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(0, body["articles"].as_array().unwrap().len());
+    assert_eq!(0, body["articlesCount"]);
+}
+
+#[actix_rt::test]
+async fn list_should_return_most_recent_first() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    publish_articles(app.address(), &jack_token, &john_token).await;
+
+    // Act
+    let response = get_articles(app.address(), "", None).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let slugs: Vec<&str> = body["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["slug"].as_str().unwrap())
+        .collect();
+    assert_eq!(vec!["third", "second", "first"], slugs);
+    assert_eq!(3, body["articlesCount"]);
+}
+
+#[actix_rt::test]
+async fn list_with_filters_should_return_matching_articles() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    publish_articles(app.address(), &jack_token, &john_token).await;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO favorites (user_id, article_id)
+        SELECT u.id, a.id
+        FROM users u, articles a
+        WHERE u.username = 'john' AND a.slug = 'first'
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to favorite.");
+
+    let test_cases = vec![
+        ("?tag=rust", 2, "tag"),
+        ("?tag=unknown", 0, "unknown tag"),
+        ("?author=john", 1, "author"),
+        ("?favorited=john", 1, "favorited"),
+        ("?tag=web&author=jack", 0, "tag and author"),
+    ];
+
+    for (query, expected_count, description) in test_cases {
+        // Act
+        let response = get_articles(app.address(), query, None).await;
+
+        // Assert
+        assert_eq!(200, response.status().as_u16());
+
+        let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+        assert_eq!(
+            expected_count, body["articlesCount"],
+            "Wrong count of articles when filtering by {}.",
+            description
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn list_with_pagination_should_return_a_page() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    publish_articles(app.address(), &jack_token, &john_token).await;
+
+    // Act
+    let response = get_articles(app.address(), "?limit=1&offset=1", None).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(1, body["articles"].as_array().unwrap().len());
+    assert_eq!("second", body["articles"][0]["slug"]);
+    assert_eq!(3, body["articlesCount"]);
+}
+
+#[actix_rt::test]
+async fn list_with_invalid_pagination_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+
+    for query in ["?limit=0", "?limit=1000", "?offset=-1", "?limit=abc"] {
+        // Act
+        let response = get_articles(app.address(), query, None).await;
+
+        // Assert
+        assert_eq!(
+            422,
+            response.status().as_u16(),
+            "The API did not fail with 422 for {}.",
+            query
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn list_authenticated_should_return_following_flags() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    publish_articles(app.address(), &jack_token, &john_token).await;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT follower.id, followed.id
+        FROM users follower, users followed
+        WHERE follower.username = $1 AND followed.username = $2
+        "#,
+        "john",
+        "jack"
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to follow.");
+
+    // Act
+    let response = get_articles(app.address(), "", Some(&john_token)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    for article in body["articles"].as_array().unwrap() {
+        let expected = article["author"]["username"] == "jack";
+        assert_eq!(Value::Bool(expected), article["author"]["following"]);
+    }
+}
+
+#[actix_rt::test]
+async fn list_should_leave_out_the_articles_of_blockers() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    publish_articles(app.address(), &jack_token, &john_token).await;
+    block_user(app.address(), "jack", &john_token).await;
+
+    // Act
+    let response = get_articles(app.address(), "", Some(&jack_token)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let slugs: Vec<&str> = body["articles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["slug"].as_str().unwrap())
+        .collect();
+    assert_eq!(vec!["third", "first"], slugs);
+    assert_eq!(2, body["articlesCount"]);
+}
@@ -0,0 +1,8 @@
+mod article;
+pub(crate) mod create;
+mod delete;
+pub(crate) mod favorite;
+mod feed;
+mod list;
+mod unfavorite;
+mod update;
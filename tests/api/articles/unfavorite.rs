@@ -0,0 +1,87 @@
+use serde_json::Value;
+
+use crate::{
+    articles::{create::post_article_with_body, favorite::favorite_article},
+    helpers::spawn_app,
+};
+
+async fn unfavorite_article(address: &str, slug: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{address}/api/articles/{slug}/favorite"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_unfavorite_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .delete(format!("{}/api/articles/title/favorite", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn unfavorite_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = unfavorite_article(app.address(), "unknown", &token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn regular_unfavorite_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &token,
+    )
+    .await;
+    favorite_article(app.address(), "title", &token).await;
+
+    // Act
+    let response = unfavorite_article(app.address(), "title", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(false), body["article"]["favorited"]);
+    assert_eq!(0, body["article"]["favoritesCount"]);
+}
+
+#[actix_rt::test]
+async fn unfavorite_not_favorited_article_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = unfavorite_article(app.address(), "title", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+}
@@ -0,0 +1,121 @@
+use serde_json::Value;
+
+use crate::{articles::create::post_article_with_body, helpers::spawn_app};
+
+async fn put_article_with_body(
+    address: &str,
+    slug: &str,
+    body: &'static str,
+    token: &str,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .put(format!("{address}/api/articles/{slug}"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn update_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = put_article_with_body(
+        app.address(),
+        "unknown",
+        r#"{"article":{"body":"New"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn update_article_of_another_author_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+
+    // Act
+    let response = put_article_with_body(
+        app.address(),
+        "title",
+        r#"{"article":{"body":"New"}}"#,
+        &john_token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn update_nothing_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = put_article_with_body(app.address(), "title", r#"{"article":{}}"#, &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn update_title_should_change_slug_and_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = put_article_with_body(
+        app.address(),
+        "title",
+        r#"{"article":{"title":"A new title","body":"New body"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("a-new-title", body["article"]["slug"]);
+
+    let saved =
+        sqlx::query!("SELECT title, description, body FROM articles WHERE slug = 'a-new-title'")
+            .fetch_one(app.db_pool())
+            .await
+            .expect("Failed to fetch article");
+
+    assert_eq!("A new title", saved.title);
+    assert_eq!("Desc", saved.description);
+    assert_eq!("New body", saved.body);
+}
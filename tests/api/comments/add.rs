@@ -0,0 +1,152 @@
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body, helpers::spawn_app, profiles::block::block_user,
+};
+
+pub(crate) async fn post_comment_with_body(
+    address: &str,
+    slug: &str,
+    body: &'static str,
+    token: &str,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{address}/api/articles/{slug}/comments"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_add_comment_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/articles/title/comments", app.address()))
+        .header("Content-Type", "application/json")
+        .body(r#"{"comment":{"body":"Nice!"}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn add_comment_to_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = post_comment_with_body(
+        app.address(),
+        "unknown",
+        r#"{"comment":{"body":"Nice!"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn add_empty_comment_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response =
+        post_comment_with_body(app.address(), "title", r#"{"comment":{"body":""}}"#, &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn add_valid_comment_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &jack_token,
+    )
+    .await;
+
+    // Act
+    let response = post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"Nice!"}}"#,
+        &john_token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("Nice!", body["comment"]["body"]);
+    assert_eq!("john", body["comment"]["author"]["username"]);
+    assert!(body["comment"]["id"].is_i64());
+
+    let saved = sqlx::query!(
+        "SELECT c.body, u.username AS author FROM comments c JOIN users u ON u.id = c.author_id"
+    )
+    .fetch_one(app.db_pool())
+    .await
+    .expect("Failed to fetch comment");
+
+    assert_eq!("Nice!", saved.body);
+    assert_eq!("john", saved.author);
+}
+
+#[actix_rt::test]
+async fn add_comment_to_article_of_a_blocker_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+    block_user(app.address(), "john", &jack_token).await;
+
+    // Act
+    let response = post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"Nice!"}}"#,
+        &john_token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    let count = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM comments"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count comments.");
+    assert_eq!(0, count);
+}
@@ -0,0 +1,105 @@
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body, comments::add::post_comment_with_body,
+    helpers::spawn_app,
+};
+
+async fn delete_comment(address: &str, slug: &str, id: i64, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{address}/api/articles/{slug}/comments/{id}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Publishes an article by jack commented by john. Returns the comment ID.
+async fn publish_commented_article(address: &str, jack_token: &str, john_token: &str) -> i64 {
+    post_article_with_body(
+        address,
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        jack_token,
+    )
+    .await;
+    let response = post_comment_with_body(
+        address,
+        "title",
+        r#"{"comment":{"body":"Nice!"}}"#,
+        john_token,
+    )
+    .await;
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    body["comment"]["id"].as_i64().unwrap()
+}
+
+#[actix_rt::test]
+async fn not_authenticated_delete_comment_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .delete(format!("{}/api/articles/title/comments/1", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_unknown_comment_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    let id = publish_commented_article(app.address(), &jack_token, &john_token).await;
+
+    // Act
+    let response1 = delete_comment(app.address(), "title", id + 1, &john_token).await;
+    let response2 = delete_comment(app.address(), "unknown", id, &john_token).await;
+
+    // Assert
+    assert_eq!(404, response1.status().as_u16());
+    assert_eq!(404, response2.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_comment_of_another_author_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    let id = publish_commented_article(app.address(), &jack_token, &john_token).await;
+
+    // Act
+    let response = delete_comment(app.address(), "title", id, &jack_token).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_own_comment_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    let id = publish_commented_article(app.address(), &jack_token, &john_token).await;
+
+    // Act
+    let response = delete_comment(app.address(), "title", id, &john_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let count = sqlx::query!(r#"SELECT COUNT(*) AS "count!" FROM comments"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count comments")
+        .count;
+    assert_eq!(0, count);
+}
@@ -0,0 +1,176 @@
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body, comments::add::post_comment_with_body,
+    helpers::spawn_app, profiles::block::block_user,
+};
+
+async fn get_comments(address: &str, slug: &str, token: Option<&str>) -> reqwest::Response {
+    let mut request = reqwest::Client::new().get(format!("{address}/api/articles/{slug}/comments"));
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Token {token}"));
+    }
+
+    request.send().await.expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn get_comments_of_unknown_article_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_comments(app.address(), "unknown", None).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn get_comments_not_authenticated_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &token,
+    )
+    .await;
+    post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"One"}}"#,
+        &token,
+    )
+    .await;
+    post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"Two"}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = get_comments(app.address(), "title", None).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let comments = body["comments"].as_array().unwrap();
+    assert_eq!(2, comments.len());
+    assert_eq!("One", comments[0]["body"]);
+    assert_eq!("Two", comments[1]["body"]);
+    assert_eq!(Value::Null, comments[0]["author"]["following"]);
+}
+
+#[actix_rt::test]
+async fn get_comments_authenticated_should_return_following() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &jack_token,
+    )
+    .await;
+    post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"One"}}"#,
+        &jack_token,
+    )
+    .await;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT follower.id, followed.id
+        FROM users follower, users followed
+        WHERE follower.username = $1 AND followed.username = $2
+        "#,
+        "john",
+        "jack"
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to follow.");
+
+    // Act
+    let response = get_comments(app.address(), "title", Some(&john_token)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(
+        Value::Bool(true),
+        body["comments"][0]["author"]["following"]
+    );
+}
+
+#[actix_rt::test]
+async fn get_comments_of_article_of_a_blocker_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#,
+        &jack_token,
+    )
+    .await;
+    block_user(app.address(), "john", &jack_token).await;
+
+    // Act
+    let response = get_comments(app.address(), "title", Some(&john_token)).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn get_comments_should_leave_out_the_comments_of_blockers() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+    let jane_token = app.insert_user("jane").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Title","description":"D","body":"B"}}"#,
+        &jack_token,
+    )
+    .await;
+    post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"First"}}"#,
+        &jack_token,
+    )
+    .await;
+    post_comment_with_body(
+        app.address(),
+        "title",
+        r#"{"comment":{"body":"Second"}}"#,
+        &john_token,
+    )
+    .await;
+    block_user(app.address(), "jane", &john_token).await;
+
+    // Act
+    let response = get_comments(app.address(), "title", Some(&jane_token)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let comments = body["comments"].as_array().unwrap();
+    assert_eq!(1, comments.len());
+    assert_eq!("First", comments[0]["body"]);
+}
@@ -0,0 +1,3 @@
+pub(crate) mod add;
+mod delete;
+mod list;
@@ -8,7 +8,7 @@ async fn health_check_works() {
 
     // Act
     let response = client
-        .get(&format!("{}/api/health_check", app.address()))
+        .get(format!("{}/api/health_check", app.address()))
         .send()
         .await
         .expect("Failed to execute request.");
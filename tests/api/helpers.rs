@@ -1,5 +1,8 @@
+use std::path::PathBuf;
+
 use conduit::{
-    configuration::{read_configuration, DatabaseSettings},
+    configuration::{read_configuration, DatabaseSettings, Settings},
+    domain::auth::{create_jwt_for_user, JwtKeys},
     Application,
 };
 use fake::{Fake, StringFaker};
@@ -9,7 +12,8 @@ use uuid::Uuid;
 pub(crate) struct TestApp {
     address: String,
     db_pool: PgPool,
-    jwt_secret: String,
+    jwt_keys: JwtKeys,
+    mail_directory: PathBuf,
 }
 
 impl TestApp {
@@ -23,25 +27,107 @@ impl TestApp {
         &self.db_pool
     }
 
-    /// Get a reference to the test app's jwt secret.
-    pub(crate) fn jwt_secret(&self) -> &str {
-        self.jwt_secret.as_ref()
+    /// Insert a new user with the given username directly in the database
+    /// (its email is `username@username.com`, already verified). Returns a
+    /// valid JWT for it.
+    pub(crate) async fn insert_user(&self, username: &str) -> String {
+        sqlx::query!(
+            "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
+            username,
+            format!("{username}@{username}.com"),
+            "test1234"
+        )
+        .execute(self.db_pool())
+        .await
+        .expect("Failed to insert user.");
+
+        self.create_token(username).await
+    }
+
+    /// Returns the emails sent by the application so far (written to its
+    /// mail directory), oldest first.
+    pub(crate) fn sent_emails(&self) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(&self.mail_directory) else {
+            return Vec::new();
+        };
+
+        let mut paths: Vec<PathBuf> = entries.map(|entry| entry.unwrap().path()).collect();
+        paths.sort();
+
+        paths
+            .iter()
+            .map(|path| std::fs::read_to_string(path).expect("Failed to read email."))
+            .collect()
+    }
+
+    /// Waits until the application has sent the given number of emails (some
+    /// are sent in the background), and returns them like [`Self::sent_emails`].
+    pub(crate) async fn wait_for_emails(&self, count: usize) -> Vec<String> {
+        for _ in 0..100 {
+            let emails = self.sent_emails();
+            if emails.len() >= count && emails.iter().all(|email| !email.is_empty()) {
+                return emails;
+            }
+
+            actix_rt::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+
+        panic!("Expected {count} emails to be sent.");
+    }
+
+    /// Returns a valid JWT (access token) for the existing user with the
+    /// given username.
+    pub(crate) async fn create_token(&self, username: &str) -> String {
+        self.create_token_with_ttl(username, time::Duration::hours(1))
+            .await
+    }
+
+    /// Returns a JWT (access token) for the existing user with the given
+    /// username, expiring after the given duration (possibly negative).
+    pub(crate) async fn create_token_with_ttl(
+        &self,
+        username: &str,
+        ttl: time::Duration,
+    ) -> String {
+        let user = sqlx::query!(
+            "SELECT id, token_version FROM users WHERE username = $1",
+            username
+        )
+        .fetch_one(self.db_pool())
+        .await
+        .expect("Failed to fetch user.");
+
+        create_jwt_for_user(user.id, user.token_version, &self.jwt_keys, ttl)
+            .expect("JWT generation failed.")
     }
 }
 
 /// Spawn a [`TestApp`] with a new random database, bind to a random port on
 /// localhost, with a random JWT shared secret.
 pub(crate) async fn spawn_app() -> TestApp {
+    spawn_app_with(|_| {}).await
+}
+
+/// Same as [`spawn_app`], but the configuration can be customized with the
+/// given function before the application is built.
+pub(crate) async fn spawn_app_with(configure: impl FnOnce(&mut Settings)) -> TestApp {
     // Randomize configuration to ensure test isolation
     let configuration = {
         let mut c = read_configuration().expect("Failed to read configuration.");
         // Use a different database for each test case
-        c.database.database_name = format!("conduit_test_{}", Uuid::new_v4().to_string());
+        c.database.database_name = format!("conduit_test_{}", Uuid::new_v4());
         // Use a random OS port
         c.app.port = 0;
         // Generate a random dummy secret for JWT
         const ALPHA_NUM: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        c.app.jwt_secret = StringFaker::with(Vec::from(ALPHA_NUM), 8..12).fake();
+        c.app.jwt_secret = Some(StringFaker::with(Vec::from(ALPHA_NUM), 8..12).fake());
+        // Write the emails to a different directory for each test case
+        c.mailer.smtp = None;
+        c.mailer.directory = std::env::temp_dir()
+            .join(format!("conduit_test_mails_{}", Uuid::new_v4()))
+            .to_string_lossy()
+            .into_owned();
+        configure(&mut c);
         c
     };
 
@@ -52,12 +138,13 @@ pub(crate) async fn spawn_app() -> TestApp {
         .expect("Failed to build the application.");
 
     let port = application.port();
-    let _ = tokio::spawn(application.run_until_stopped());
+    tokio::spawn(application.run_until_stopped());
 
     TestApp {
         address: format!("http://127.0.0.1:{}", port),
         db_pool: get_test_connection_pool(&configuration.database),
-        jwt_secret: configuration.app.jwt_secret,
+        jwt_keys: JwtKeys::from_settings(&configuration.app).expect("Invalid JWT keys."),
+        mail_directory: PathBuf::from(&configuration.mailer.directory),
     }
 }
 
@@ -92,3 +179,13 @@ fn get_test_connection_pool(configuration: &DatabaseSettings) -> PgPool {
         .acquire_timeout(std::time::Duration::from_secs(10))
         .connect_lazy_with(configuration.with_db())
 }
+
+/// Returns the token written on its own line in the given email (an opaque
+/// token, 64 hexadecimal characters).
+pub(crate) fn token_in_email(email: &str) -> String {
+    email
+        .lines()
+        .find(|line| line.len() == 64 && line.chars().all(|c| c.is_ascii_hexdigit()))
+        .expect("No token in the email.")
+        .to_string()
+}
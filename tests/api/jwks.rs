@@ -0,0 +1,182 @@
+use conduit::{
+    configuration::JwtKeySettings,
+    domain::auth::{create_jwt_for_user, JwtKeys},
+};
+use jsonwebtoken::Algorithm;
+use serde_json::Value;
+
+use crate::helpers::{spawn_app, spawn_app_with, TestApp};
+
+/// Returns the settings of the test key with the given name (`rsa` or
+/// `ed25519`), found in the `tests/keys` folder.
+fn test_key(name: &str, algorithm: Algorithm, with_private_key: bool) -> JwtKeySettings {
+    let path = |kind: &str| {
+        format!(
+            "{}/tests/keys/{name}_{kind}.pem",
+            env!("CARGO_MANIFEST_DIR")
+        )
+    };
+
+    JwtKeySettings {
+        kid: format!("{name}-1"),
+        algorithm,
+        public_key_path: path("public"),
+        private_key_path: with_private_key.then(|| path("private")),
+    }
+}
+
+async fn get_jwks(app: &TestApp) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{}/.well-known/jwks.json", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn get_user_with_token(app: &TestApp, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn jwks_with_shared_secret_should_be_empty() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_jwks(&app).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(serde_json::json!({ "keys": [] }), body);
+}
+
+#[actix_rt::test]
+async fn jwks_should_list_every_verification_key() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.jwt_keys = vec![
+            test_key("rsa", Algorithm::RS256, true),
+            test_key("ed25519", Algorithm::EdDSA, false),
+        ];
+    })
+    .await;
+
+    // Act
+    let response = get_jwks(&app).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let keys = body["keys"].as_array().unwrap();
+    assert_eq!(2, keys.len());
+
+    assert_eq!("RSA", keys[0]["kty"]);
+    assert_eq!("sig", keys[0]["use"]);
+    assert_eq!("RS256", keys[0]["alg"]);
+    assert_eq!("rsa-1", keys[0]["kid"]);
+    assert_eq!("AQAB", keys[0]["e"]);
+    assert!(keys[0]["n"].is_string());
+
+    assert_eq!("OKP", keys[1]["kty"]);
+    assert_eq!("EdDSA", keys[1]["alg"]);
+    assert_eq!("ed25519-1", keys[1]["kid"]);
+    assert_eq!("Ed25519", keys[1]["crv"]);
+    assert!(keys[1]["x"].is_string());
+}
+
+#[actix_rt::test]
+async fn token_signed_with_asymmetric_key_should_be_accepted() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.jwt_keys = vec![test_key("ed25519", Algorithm::EdDSA, true)];
+    })
+    .await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = get_user_with_token(&app, &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let header = jsonwebtoken::decode_header(&token).unwrap();
+    assert_eq!(Algorithm::EdDSA, header.alg);
+    assert_eq!(Some("ed25519-1".into()), header.kid);
+}
+
+#[actix_rt::test]
+async fn token_signed_with_rotated_key_should_still_be_accepted() {
+    // Arrange
+
+    // The token was signed with the former RSA key...
+    let former_keys = JwtKeys::load(&[test_key("rsa", Algorithm::RS256, true)], None).unwrap();
+    let token = create_jwt_for_user(1, 0, &former_keys, time::Duration::hours(1)).unwrap();
+
+    // ...which is now only used for verification
+    let app = spawn_app_with(|c| {
+        c.app.jwt_keys = vec![
+            test_key("ed25519", Algorithm::EdDSA, true),
+            test_key("rsa", Algorithm::RS256, false),
+        ];
+    })
+    .await;
+    // The first user of the database, i.e. of ID 1
+    app.insert_user("jake").await;
+
+    // Act
+    let response = get_user_with_token(&app, &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn token_signed_with_unknown_key_should_return_401() {
+    // Arrange
+    let unknown_keys = JwtKeys::load(&[test_key("rsa", Algorithm::RS256, true)], None).unwrap();
+    let token = create_jwt_for_user(1, 0, &unknown_keys, time::Duration::hours(1)).unwrap();
+
+    let app = spawn_app_with(|c| {
+        c.app.jwt_keys = vec![test_key("ed25519", Algorithm::EdDSA, true)];
+    })
+    .await;
+    app.insert_user("jake").await;
+
+    // Act
+    let response = get_user_with_token(&app, &token).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn shared_secret_token_should_return_401_with_asymmetric_keys() {
+    // Arrange
+    let token = create_jwt_for_user(
+        1,
+        0,
+        &JwtKeys::from_secret("2DgSjrVwFXLYFz"),
+        time::Duration::hours(1),
+    )
+    .unwrap();
+
+    let app = spawn_app_with(|c| {
+        c.app.jwt_keys = vec![test_key("rsa", Algorithm::RS256, true)];
+    })
+    .await;
+    app.insert_user("jake").await;
+
+    // Act
+    let response = get_user_with_token(&app, &token).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
@@ -1,4 +1,10 @@
+mod articles;
+mod comments;
 mod health_check;
 mod helpers;
+mod jwks;
 mod profiles;
+mod tags;
+mod tokens;
+mod two_factor;
 mod users;
@@ -0,0 +1,201 @@
+use serde_json::Value;
+
+use crate::{
+    helpers::spawn_app,
+    profiles::{follow::follow_user, followers::insert_follow},
+};
+
+pub(crate) async fn block_user(address: &str, username: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{address}/api/profiles/{username}/block"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn get_profile_with_token(address: &str, username: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/profiles/{username}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_block_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/profiles/username/block", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn unknown_user_block_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = block_user(app.address(), "unknown", &token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn self_block_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = block_user(app.address(), "jack", &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn block_should_remove_follows_in_both_directions() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    insert_follow(&app, "jack", "john", 10).await;
+    insert_follow(&app, "john", "jack", 5).await;
+
+    // Act
+    let response = block_user(app.address(), "john", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("john", body["profile"]["username"]);
+    assert_eq!(Value::Bool(false), body["profile"]["following"]);
+
+    let follows = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM followers"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count followers.");
+    assert_eq!(0, follows);
+}
+
+#[actix_rt::test]
+async fn block_twice_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = block_user(app.address(), "john", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn blocked_user_should_not_see_the_blocker_profile() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = get_profile_with_token(app.address(), "jack", &john_token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    // The blocker still sees the blocked user
+    let response = get_profile_with_token(app.address(), "john", &token).await;
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn blocked_user_should_not_see_the_blocker_followers() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/profiles/jack/followers", app.address()))
+        .header("Authorization", format!("Token {john_token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn blocked_user_follow_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = follow_user(app.address(), "jack", &john_token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn blocked_user_block_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = block_user(app.address(), "jack", &john_token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    let body = response.text().await.unwrap();
+    assert!(!body.contains("profile"));
+}
+
+#[actix_rt::test]
+async fn following_a_blocked_user_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = follow_user(app.address(), "john", &token).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
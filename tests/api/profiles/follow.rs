@@ -1,12 +1,11 @@
 use claim::assert_ok;
-use conduit::domain::auth::create_jwt_for_user;
 use serde_json::Value;
 
 use crate::helpers::spawn_app;
 
-async fn follow_user(address: &str, username: &str, token: &str) -> reqwest::Response {
+pub(crate) async fn follow_user(address: &str, username: &str, token: &str) -> reqwest::Response {
     reqwest::Client::new()
-        .post(&format!("{address}/api/profiles/{username}/follow"))
+        .post(format!("{address}/api/profiles/{username}/follow"))
         .header("Authorization", format!("Token {token}"))
         .send()
         .await
@@ -20,7 +19,7 @@ async fn not_authenticated_follow_should_return_401() {
 
     // Act
     let response = reqwest::Client::new()
-        .post(&format!("{}/api/profiles/username/follow", app.address()))
+        .post(format!("{}/api/profiles/username/follow", app.address()))
         .send()
         .await
         .expect("Failed to execute request.");
@@ -35,7 +34,7 @@ async fn unknown_user_follow_should_return_404() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -44,7 +43,7 @@ async fn unknown_user_follow_should_return_404() {
     .await
     .expect("Failed to insert user.");
 
-    let token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let token = app.create_token("jack").await;
 
     // Act
     let response = follow_user(app.address(), "unknown", &token).await;
@@ -59,7 +58,7 @@ async fn self_follow_should_return_422() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -68,7 +67,7 @@ async fn self_follow_should_return_422() {
     .await
     .expect("Failed to insert user.");
 
-    let token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let token = app.create_token("jack").await;
 
     // Act
     let response = follow_user(app.address(), "jack", &token).await;
@@ -83,7 +82,7 @@ async fn regular_follow_should_return_200() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -92,10 +91,10 @@ async fn regular_follow_should_return_200() {
     .await
     .expect("Failed to insert user 1.");
 
-    let jack_token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let jack_token = app.create_token("jack").await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "john",
         "john@john.com",
         "test1234"
@@ -112,14 +111,15 @@ async fn regular_follow_should_return_200() {
 
     let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
     assert_eq!(Value::Bool(true), body["profile"]["following"]);
+    assert_eq!(Value::Bool(false), body["profile"]["pending"]);
 
     assert_ok!(
         sqlx::query!(
             r#"
             SELECT *
             FROM followers
-            WHERE follower = $1
-                AND followed = $2
+            WHERE follower_id = (SELECT id FROM users WHERE username = $1)
+                AND followed_id = (SELECT id FROM users WHERE username = $2)
             "#,
             "jack",
             "john"
@@ -135,7 +135,7 @@ async fn already_follow_should_return_422() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -144,10 +144,10 @@ async fn already_follow_should_return_422() {
     .await
     .expect("Failed to insert user 1.");
 
-    let jack_token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let jack_token = app.create_token("jack").await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "john",
         "john@john.com",
         "test1234"
@@ -172,8 +172,8 @@ async fn already_follow_should_return_422() {
             r#"
             SELECT *
             FROM followers
-            WHERE follower = $1
-                AND followed = $2
+            WHERE follower_id = (SELECT id FROM users WHERE username = $1)
+                AND followed_id = (SELECT id FROM users WHERE username = $2)
             "#,
             "jack",
             "john"
@@ -182,3 +182,41 @@ async fn already_follow_should_return_422() {
         .await
     );
 }
+
+#[actix_rt::test]
+async fn follow_private_account_should_be_pending() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    sqlx::query!("UPDATE users SET private = true WHERE username = 'john'")
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to update user.");
+
+    // Act
+    let response = follow_user(app.address(), "john", &jack_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(false), body["profile"]["following"]);
+    assert_eq!(Value::Bool(true), body["profile"]["pending"]);
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/profiles/john", app.address()))
+        .header("Authorization", format!("Token {jack_token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(false), body["profile"]["following"]);
+    assert_eq!(Value::Bool(true), body["profile"]["pending"]);
+    assert_eq!(0, body["profile"]["followersCount"]);
+
+    // Requesting again is refused
+    let response = follow_user(app.address(), "john", &jack_token).await;
+    assert_eq!(422, response.status().as_u16());
+}
@@ -0,0 +1,157 @@
+use serde_json::Value;
+
+use crate::helpers::{spawn_app, TestApp};
+
+async fn get_followers(
+    address: &str,
+    username: &str,
+    query: &str,
+    token: Option<&str>,
+) -> reqwest::Response {
+    let mut request = reqwest::Client::new().get(format!(
+        "{address}/api/profiles/{username}/followers{query}"
+    ));
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Token {token}"));
+    }
+
+    request.send().await.expect("Failed to execute request.")
+}
+
+/// Makes `follower` follow `followed`, `minutes_ago` minutes ago.
+pub(crate) async fn insert_follow(app: &TestApp, follower: &str, followed: &str, minutes_ago: i32) {
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id, created_at)
+        SELECT follower.id, followed.id, now() - make_interval(mins => $3)
+        FROM users follower, users followed
+        WHERE follower.username = $1 AND followed.username = $2
+        "#,
+        follower,
+        followed,
+        minutes_ago
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert follower.");
+}
+
+fn usernames(body: &Value) -> Vec<&str> {
+    body["profiles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["username"].as_str().unwrap())
+        .collect()
+}
+
+#[actix_rt::test]
+async fn unknown_user_followers_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_followers(app.address(), "unknown", "", None).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn invalid_cursor_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    app.insert_user("jack").await;
+
+    // Act
+    let response = get_followers(app.address(), "jack", "?cursor=invalid", None).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn followers_should_be_listed_most_recent_first() {
+    // Arrange
+    let app = spawn_app().await;
+
+    for username in ["jack", "john", "jane", "joe"] {
+        app.insert_user(username).await;
+    }
+    insert_follow(&app, "john", "jack", 30).await;
+    insert_follow(&app, "jane", "jack", 20).await;
+    insert_follow(&app, "joe", "jack", 10).await;
+    // Not a follower of jack
+    insert_follow(&app, "jack", "john", 5).await;
+
+    // Act
+    let response = get_followers(app.address(), "jack", "", None).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(vec!["joe", "jane", "john"], usernames(&body));
+    assert_eq!(Value::Null, body["profiles"][0]["following"]);
+    assert_eq!(Value::Null, body["nextCursor"]);
+}
+
+#[actix_rt::test]
+async fn followers_should_tell_if_the_viewer_follows_them() {
+    // Arrange
+    let app = spawn_app().await;
+
+    for username in ["jack", "john", "jane"] {
+        app.insert_user(username).await;
+    }
+    insert_follow(&app, "john", "jack", 20).await;
+    insert_follow(&app, "jane", "jack", 10).await;
+    insert_follow(&app, "jane", "john", 5).await;
+
+    let token = app.create_token("jane").await;
+
+    // Act
+    let response = get_followers(app.address(), "jack", "", Some(&token)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(vec!["jane", "john"], usernames(&body));
+    assert_eq!(Value::Bool(false), body["profiles"][0]["following"]);
+    assert_eq!(Value::Bool(true), body["profiles"][1]["following"]);
+}
+
+#[actix_rt::test]
+async fn followers_should_be_paginated_with_a_cursor() {
+    // Arrange
+    let app = spawn_app().await;
+
+    for username in ["jack", "john", "jane", "joe"] {
+        app.insert_user(username).await;
+    }
+    insert_follow(&app, "john", "jack", 30).await;
+    insert_follow(&app, "jane", "jack", 20).await;
+    insert_follow(&app, "joe", "jack", 10).await;
+
+    // Act
+    let response = get_followers(app.address(), "jack", "?limit=2", None).await;
+    let first_page: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+
+    let cursor = first_page["nextCursor"].as_str().unwrap();
+    let response = get_followers(
+        app.address(),
+        "jack",
+        &format!("?limit=2&cursor={cursor}"),
+        None,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let second_page: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(vec!["joe", "jane"], usernames(&first_page));
+    assert_eq!(vec!["john"], usernames(&second_page));
+    assert_eq!(Value::Null, second_page["nextCursor"]);
+}
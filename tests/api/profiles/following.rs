@@ -0,0 +1,70 @@
+use serde_json::Value;
+
+use crate::{helpers::spawn_app, profiles::followers::insert_follow};
+
+async fn get_following(address: &str, username: &str, query: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!(
+            "{address}/api/profiles/{username}/following{query}"
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn unknown_user_following_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_following(app.address(), "unknown", "").await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn invalid_limit_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    app.insert_user("jack").await;
+
+    // Act
+    let response = get_following(app.address(), "jack", "?limit=0").await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn followed_users_should_be_paginated_most_recent_first() {
+    // Arrange
+    let app = spawn_app().await;
+
+    for username in ["jack", "john", "jane", "joe"] {
+        app.insert_user(username).await;
+    }
+    insert_follow(&app, "jack", "john", 30).await;
+    insert_follow(&app, "jack", "jane", 20).await;
+    insert_follow(&app, "jack", "joe", 10).await;
+    // Not followed by jack
+    insert_follow(&app, "john", "jack", 5).await;
+
+    // Act
+    let response = get_following(app.address(), "jack", "?limit=2").await;
+    let first_page: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+
+    let cursor = first_page["nextCursor"].as_str().unwrap();
+    let response = get_following(app.address(), "jack", &format!("?limit=2&cursor={cursor}")).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let second_page: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("joe", first_page["profiles"][0]["username"]);
+    assert_eq!("jane", first_page["profiles"][1]["username"]);
+    assert_eq!(1, second_page["profiles"].as_array().unwrap().len());
+    assert_eq!("john", second_page["profiles"][0]["username"]);
+    assert_eq!(Value::Null, second_page["nextCursor"]);
+}
@@ -1,3 +1,7 @@
-mod follow;
+pub(crate) mod block;
+pub(crate) mod follow;
+pub(crate) mod followers;
+mod following;
 mod profile;
+mod unblock;
 mod unfollow;
@@ -1,11 +1,10 @@
-use conduit::domain::auth::create_jwt_for_user;
 use serde_json::Value;
 
 use crate::helpers::spawn_app;
 
 async fn get_profile(address: &str, username: &str) -> reqwest::Response {
     reqwest::Client::new()
-        .get(&format!("{address}/api/profiles/{username}"))
+        .get(format!("{address}/api/profiles/{username}"))
         .send()
         .await
         .expect("Failed to execute request.")
@@ -66,11 +65,11 @@ async fn get_known_profile_authenticated_should_return_200() {
     .await
     .expect("Failed to insert user.");
 
-    let token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let token = app.create_token("jack").await;
 
     // Act
     let response = reqwest::Client::new()
-        .get(&format!("{}/api/profiles/jack", app.address()))
+        .get(format!("{}/api/profiles/jack", app.address()))
         .header("Authorization", format!("Token {token}"))
         .send()
         .await
@@ -86,3 +85,38 @@ async fn get_known_profile_authenticated_should_return_200() {
     assert_eq!(Value::Null, body["profile"]["image"]);
     assert_eq!(Value::Bool(false), body["profile"]["following"])
 }
+
+#[actix_rt::test]
+async fn get_profile_should_return_follow_counts() {
+    // Arrange
+    let app = spawn_app().await;
+
+    for username in ["jack", "john", "jane"] {
+        app.insert_user(username).await;
+    }
+    for (follower, followed) in [("john", "jack"), ("jane", "jack"), ("jack", "jane")] {
+        sqlx::query!(
+            r#"
+            INSERT INTO followers (follower_id, followed_id)
+            SELECT follower.id, followed.id
+            FROM users follower, users followed
+            WHERE follower.username = $1 AND followed.username = $2
+            "#,
+            follower,
+            followed
+        )
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to insert follower.");
+    }
+
+    // Act
+    let response = get_profile(app.address(), "jack").await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(2, body["profile"]["followersCount"]);
+    assert_eq!(1, body["profile"]["followingCount"]);
+}
@@ -0,0 +1,63 @@
+use crate::{helpers::spawn_app, profiles::block::block_user};
+
+async fn unblock_user(address: &str, username: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{address}/api/profiles/{username}/block"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_unblock_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .delete(format!("{}/api/profiles/username/block", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn unknown_user_unblock_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response = unblock_user(app.address(), "unknown", &token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn unblocked_user_should_see_the_profile_again() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = unblock_user(app.address(), "john", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/profiles/jack", app.address()))
+        .header("Authorization", format!("Token {john_token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(200, response.status().as_u16());
+}
@@ -1,12 +1,11 @@
 use claim::assert_err;
-use conduit::domain::auth::create_jwt_for_user;
 use serde_json::Value;
 
-use crate::helpers::spawn_app;
+use crate::{helpers::spawn_app, profiles::block::block_user};
 
 async fn unfollow_user(address: &str, username: &str, token: &str) -> reqwest::Response {
     reqwest::Client::new()
-        .delete(&format!("{address}/api/profiles/{username}/follow"))
+        .delete(format!("{address}/api/profiles/{username}/follow"))
         .header("Authorization", format!("Token {token}"))
         .send()
         .await
@@ -20,7 +19,7 @@ async fn not_authenticated_unfollow_should_return_401() {
 
     // Act
     let response = reqwest::Client::new()
-        .delete(&format!("{}/api/profiles/username/follow", app.address()))
+        .delete(format!("{}/api/profiles/username/follow", app.address()))
         .send()
         .await
         .expect("Failed to execute request.");
@@ -35,7 +34,7 @@ async fn unknown_user_unfollow_should_return_404() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -44,7 +43,7 @@ async fn unknown_user_unfollow_should_return_404() {
     .await
     .expect("Failed to insert user.");
 
-    let token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let token = app.create_token("jack").await;
 
     // Act
     let response = unfollow_user(app.address(), "unknown", &token).await;
@@ -59,7 +58,7 @@ async fn self_unfollow_should_return_422() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -68,7 +67,7 @@ async fn self_unfollow_should_return_422() {
     .await
     .expect("Failed to insert user.");
 
-    let token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let token = app.create_token("jack").await;
 
     // Act
     let response = unfollow_user(app.address(), "jack", &token).await;
@@ -83,7 +82,7 @@ async fn regular_unfollow_should_return_200() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -92,10 +91,10 @@ async fn regular_unfollow_should_return_200() {
     .await
     .expect("Failed to insert user 1.");
 
-    let jack_token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let jack_token = app.create_token("jack").await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "john",
         "john@john.com",
         "test1234"
@@ -104,10 +103,17 @@ async fn regular_unfollow_should_return_200() {
     .await
     .expect("Failed to insert user 2.");
 
-    sqlx::query!("INSERT INTO followers VALUES ('jack', 'john')")
-        .execute(app.db_pool())
-        .await
-        .expect("Failed to insert in followers.");
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT follower.id, followed.id
+        FROM users follower, users followed
+        WHERE follower.username = 'jack' AND followed.username = 'john'
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert in followers.");
 
     // Act
     let response = unfollow_user(app.address(), "john", &jack_token).await;
@@ -123,8 +129,8 @@ async fn regular_unfollow_should_return_200() {
             r#"
             SELECT *
             FROM followers
-            WHERE follower = $1
-                AND followed = $2
+            WHERE follower_id = (SELECT id FROM users WHERE username = $1)
+                AND followed_id = (SELECT id FROM users WHERE username = $2)
             "#,
             "jack",
             "john"
@@ -140,7 +146,7 @@ async fn unfollow_not_following_should_return_200() {
     let app = spawn_app().await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "jack",
         "jack@jack.com",
         "test1234"
@@ -149,10 +155,10 @@ async fn unfollow_not_following_should_return_200() {
     .await
     .expect("Failed to insert user 1.");
 
-    let jack_token = create_jwt_for_user("jack", app.jwt_secret()).expect("JWT generation failed.");
+    let jack_token = app.create_token("jack").await;
 
     sqlx::query!(
-        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "INSERT INTO users (username, email, password, email_verified) VALUES ($1, $2, $3, true)",
         "john",
         "john@john.com",
         "test1234"
@@ -170,3 +176,22 @@ async fn unfollow_not_following_should_return_200() {
     let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
     assert_eq!(Value::Bool(false), body["profile"]["following"]);
 }
+
+#[actix_rt::test]
+async fn blocked_user_unfollow_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    block_user(app.address(), "john", &token).await;
+
+    // Act
+    let response = unfollow_user(app.address(), "jack", &john_token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    let body = response.text().await.unwrap();
+    assert!(!body.contains("profile"));
+}
@@ -0,0 +1,83 @@
+use serde_json::Value;
+
+use crate::{articles::create::post_article_with_body, helpers::spawn_app};
+
+async fn get_tags(address: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/tags"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn get_tags_without_articles_should_return_empty_list() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = get_tags(app.address()).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(0, body["tags"].as_array().unwrap().len());
+}
+
+#[actix_rt::test]
+async fn get_tags_should_return_normalized_tags_in_use() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"One","description":"D","body":"B","tagList":["Rust","web"]}}"#,
+        &token,
+    )
+    .await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Two","description":"D","body":"B","tagList":["rust","actix"]}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let response = get_tags(app.address()).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(serde_json::json!(["actix", "rust", "web"]), body["tags"]);
+}
+
+#[actix_rt::test]
+async fn get_tags_should_not_return_unused_tags() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"One","description":"D","body":"B","tagList":["rust"]}}"#,
+        &token,
+    )
+    .await;
+
+    reqwest::Client::new()
+        .put(format!("{}/api/articles/one", app.address()))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(r#"{"article":{"tagList":["web"]}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = get_tags(app.address()).await;
+
+    // Assert
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(serde_json::json!(["web"]), body["tags"]);
+}
@@ -0,0 +1,159 @@
+use serde_json::Value;
+
+use crate::helpers::spawn_app;
+
+pub(crate) async fn post_token_with_body(
+    address: &str,
+    body: &'static str,
+    token: &str,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{address}/api/user/tokens"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Creates a personal access token with the given body and returns it.
+pub(crate) async fn create_personal_access_token(
+    address: &str,
+    body: &'static str,
+    token: &str,
+) -> String {
+    let response = post_token_with_body(address, body, token).await;
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    body["token"]["token"].as_str().unwrap().to_owned()
+}
+
+#[actix_rt::test]
+async fn not_authenticated_create_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/user/tokens", app.address()))
+        .header("Content-Type", "application/json")
+        .body(r#"{"token":{"name":"ci"}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn valid_create_token_should_return_201() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_token_with_body(
+        app.address(),
+        r#"{"token":{"name":"ci","scopes":["read"],"expiresAt":"2100-01-01T00:00:00Z"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("ci", body["token"]["name"]);
+    assert_eq!(serde_json::json!(["read"]), body["token"]["scopes"]);
+    assert_eq!("2100-01-01T00:00:00Z", body["token"]["expiresAt"]);
+    assert_eq!(Value::Null, body["token"]["lastUsedAt"]);
+    assert!(body["token"]["token"]
+        .as_str()
+        .unwrap()
+        .starts_with("cpat_"));
+
+    // Only the hash of the token is stored
+    let stored = sqlx::query!("SELECT token_hash FROM personal_access_tokens")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch token.");
+    assert_ne!(body["token"]["token"], stored.token_hash.as_str());
+}
+
+#[actix_rt::test]
+async fn create_token_without_scopes_should_grant_every_scope() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_token_with_body(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Assert
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(
+        serde_json::json!(["read", "write"]),
+        body["token"]["scopes"]
+    );
+    assert_eq!(Value::Null, body["token"]["expiresAt"]);
+}
+
+#[actix_rt::test]
+async fn invalid_create_token_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_token_with_body(
+        app.address(),
+        r#"{"token":{"name":" ","scopes":["admin"],"expiresAt":"2000-01-01T00:00:00Z"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_ne!(Value::Null, body["errors"]["name"]);
+    assert_ne!(Value::Null, body["errors"]["scopes"]);
+    assert_ne!(Value::Null, body["errors"]["expiresAt"]);
+}
+
+#[actix_rt::test]
+async fn create_token_with_taken_name_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = post_token_with_body(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["name"][0]);
+}
+
+#[actix_rt::test]
+async fn create_token_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = post_token_with_body(app.address(), r#"{"token":{"name":"other"}}"#, &pat).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
@@ -0,0 +1,59 @@
+use serde_json::Value;
+
+use crate::{helpers::spawn_app, tokens::create::create_personal_access_token};
+
+pub(crate) async fn get_tokens(address: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/user/tokens"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn list_tokens_should_return_the_user_tokens() {
+    // Arrange
+    let app = spawn_app().await;
+    let jake_token = app.insert_user("jake").await;
+    let john_token = app.insert_user("john").await;
+    create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &jake_token).await;
+    create_personal_access_token(
+        app.address(),
+        r#"{"token":{"name":"backup","scopes":["read"]}}"#,
+        &jake_token,
+    )
+    .await;
+    create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &john_token).await;
+
+    // Act
+    let response = get_tokens(app.address(), &jake_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let tokens = body["tokens"].as_array().unwrap();
+    assert_eq!(2, tokens.len());
+
+    // Newest first, without the token itself
+    assert_eq!("backup", tokens[0]["name"]);
+    assert_eq!(serde_json::json!(["read"]), tokens[0]["scopes"]);
+    assert_eq!("ci", tokens[1]["name"]);
+    assert!(tokens.iter().all(|t| t["token"] == Value::Null));
+}
+
+#[actix_rt::test]
+async fn list_tokens_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = get_tokens(app.address(), &pat).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
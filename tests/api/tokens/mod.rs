@@ -0,0 +1,4 @@
+pub(crate) mod create;
+mod list;
+mod revoke;
+mod usage;
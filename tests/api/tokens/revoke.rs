@@ -0,0 +1,68 @@
+use serde_json::Value;
+
+use crate::{
+    helpers::spawn_app,
+    tokens::{create::create_personal_access_token, list::get_tokens},
+};
+
+async fn delete_token(address: &str, id: i64, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{address}/api/user/tokens/{id}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Returns the ID of the first token listed for the given user.
+async fn first_token_id(address: &str, token: &str) -> i64 {
+    let response = get_tokens(address, token).await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    body["tokens"][0]["id"].as_i64().unwrap()
+}
+
+#[actix_rt::test]
+async fn revoked_token_should_not_be_accepted_anymore() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+    let id = first_token_id(app.address(), &token).await;
+
+    // Act
+    let response = delete_token(app.address(), id, &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Bearer {pat}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn revoke_token_of_someone_else_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let jake_token = app.insert_user("jake").await;
+    let john_token = app.insert_user("john").await;
+    create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &jake_token).await;
+    let id = first_token_id(app.address(), &jake_token).await;
+
+    // Act
+    let response = delete_token(app.address(), id, &john_token).await;
+
+    // Assert
+    assert_eq!(404, response.status().as_u16());
+
+    let stored = sqlx::query!("SELECT COUNT(*) AS count FROM personal_access_tokens")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count tokens.");
+    assert_eq!(Some(1), stored.count);
+}
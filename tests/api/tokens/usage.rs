@@ -0,0 +1,159 @@
+use serde_json::Value;
+
+use crate::{helpers::spawn_app, tokens::create::create_personal_access_token};
+
+async fn get_user(address: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/user"))
+        .header("Authorization", format!("Bearer {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn put_user(address: &str, body: &'static str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .put(format!("{address}/api/user"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {token}"))
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn personal_access_token_should_authenticate_the_user() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = get_user(app.address(), &pat).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("jake", body["user"]["username"]);
+    assert_eq!(pat.as_str(), body["user"]["token"]);
+
+    // Its use is recorded
+    let stored = sqlx::query!("SELECT last_used_at FROM personal_access_tokens")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch token.");
+    assert!(stored.last_used_at.is_some());
+}
+
+#[actix_rt::test]
+async fn update_with_personal_access_token_should_not_issue_a_jwt() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = put_user(app.address(), r#"{"user":{"bio":"Scripted"}}"#, &pat).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("Scripted", body["user"]["bio"]);
+    assert_eq!(pat.as_str(), body["user"]["token"]);
+}
+
+#[actix_rt::test]
+async fn credentials_update_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    for body in [
+        r#"{"user":{"password":"takeover"}}"#,
+        r#"{"user":{"email":"attacker@attacker.com"}}"#,
+    ] {
+        // Act
+        let response = put_user(app.address(), body, &pat).await;
+
+        // Assert
+        assert_eq!(403, response.status().as_u16(), "{body}");
+    }
+
+    let saved = sqlx::query!("SELECT password FROM users WHERE username = 'jake'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user.");
+    assert_eq!("test1234", saved.password);
+}
+
+#[actix_rt::test]
+async fn read_only_token_should_not_allow_writes() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat = create_personal_access_token(
+        app.address(),
+        r#"{"token":{"name":"ci","scopes":["read"]}}"#,
+        &token,
+    )
+    .await;
+
+    // Act
+    let read = get_user(app.address(), &pat).await;
+    let write = put_user(app.address(), r#"{"user":{"bio":"Scripted"}}"#, &pat).await;
+
+    // Assert
+    assert_eq!(200, read.status().as_u16());
+    assert_eq!(403, write.status().as_u16());
+    assert_eq!(
+        r#"Bearer realm="conduit", error="insufficient_scope", error_description="This token does not allow this request.""#,
+        write.headers()["WWW-Authenticate"]
+    );
+}
+
+#[actix_rt::test]
+async fn expired_personal_access_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    sqlx::query!("UPDATE personal_access_tokens SET expires_at = now() - interval '1 second'")
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to expire token.");
+
+    // Act
+    let response = get_user(app.address(), &pat).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn logout_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/user/logout", app.address()))
+        .header("Authorization", format!("Bearer {pat}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
@@ -0,0 +1,198 @@
+use conduit::domain::auth::totp::TotpSecret;
+use serde_json::Value;
+
+use crate::{
+    helpers::{spawn_app, spawn_app_with},
+    tokens::create::create_personal_access_token,
+    two_factor::{code, code_body, enable_two_factor, post_two_factor},
+};
+
+#[actix_rt::test]
+async fn enroll_should_return_a_secret_and_its_uri() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_two_factor(app.address(), "enroll", String::new(), &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let secret = body["twoFactor"]["secret"].as_str().unwrap();
+    assert!(TotpSecret::from_base32(secret).is_ok());
+    assert_eq!(
+        format!(
+            "otpauth://totp/Conduit:jake?secret={secret}&issuer=Conduit&algorithm=SHA1&digits=6&period=30"
+        ),
+        body["twoFactor"]["otpauthUri"]
+    );
+}
+
+#[actix_rt::test]
+async fn enroll_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = post_two_factor(app.address(), "enroll", String::new(), &pat).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn confirm_with_valid_code_should_return_recovery_codes() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let (_, recovery_codes) = enable_two_factor(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(10, recovery_codes.len());
+
+    // Only the hashes of the codes are stored
+    let stored = sqlx::query!("SELECT code_hash FROM totp_recovery_codes")
+        .fetch_all(app.db_pool())
+        .await
+        .expect("Failed to fetch recovery codes.");
+    assert_eq!(10, stored.len());
+    assert!(stored
+        .iter()
+        .all(|s| !recovery_codes.contains(&s.code_hash)));
+}
+
+#[actix_rt::test]
+async fn confirm_with_invalid_code_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    post_two_factor(app.address(), "enroll", String::new(), &token).await;
+
+    // Act
+    let response = post_two_factor(app.address(), "confirm", code_body("000000"), &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("is invalid", body["errors"]["code"][0]);
+}
+
+#[actix_rt::test]
+async fn confirm_without_enrollment_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_two_factor(app.address(), "confirm", code_body("000000"), &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn enroll_when_enabled_should_return_409() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    enable_two_factor(app.address(), &token).await;
+
+    // Act
+    let response = post_two_factor(app.address(), "enroll", String::new(), &token).await;
+
+    // Assert
+    assert_eq!(409, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn disable_with_valid_code_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let (secret, _) = enable_two_factor(app.address(), &token).await;
+
+    // Act
+    let response = post_two_factor(
+        app.address(),
+        "disable",
+        code_body(&code(&secret, 1)),
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let stored = sqlx::query!("SELECT COUNT(*) AS count FROM user_totp")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count secrets.");
+    assert_eq!(Some(0), stored.count);
+}
+
+#[actix_rt::test]
+async fn disable_with_invalid_code_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    enable_two_factor(app.address(), &token).await;
+
+    // Act
+    let response = post_two_factor(app.address(), "disable", code_body("000000"), &token).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn repeated_invalid_codes_on_disable_should_lock_the_account_with_429() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.account_threshold = 3;
+        c.app.login_lockout.base_lock_duration = 60;
+    })
+    .await;
+    let token = app.insert_user("jake").await;
+    let (secret, _) = enable_two_factor(app.address(), &token).await;
+
+    for _ in 0..3 {
+        let response = post_two_factor(app.address(), "disable", code_body("000000"), &token).await;
+        assert_eq!(403, response.status().as_u16());
+    }
+
+    // Act
+
+    // Even the valid code is refused
+    let response = post_two_factor(
+        app.address(),
+        "disable",
+        code_body(&code(&secret, 1)),
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(429, response.status().as_u16());
+    assert!(response.headers().contains_key("Retry-After"));
+}
+
+#[actix_rt::test]
+async fn disable_when_not_enabled_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_two_factor(app.address(), "disable", code_body("000000"), &token).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+}
@@ -0,0 +1,195 @@
+use serde_json::Value;
+
+use crate::{
+    helpers::{spawn_app, spawn_app_with, TestApp},
+    two_factor::{code, enable_two_factor},
+    users::register::post_register_with_body,
+};
+
+async fn post_login(app: &TestApp) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/login", app.address()))
+        .header("Content-Type", "application/json")
+        .body(r#"{"user":{"email":"jake@jake.com","password":"jake1234"}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn post_login_2fa(app: &TestApp, challenge_token: &str, code: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/login/2fa", app.address()))
+        .header("Content-Type", "application/json")
+        .body(format!(
+            r#"{{"user":{{"challengeToken":"{challenge_token}","code":"{code}"}}}}"#
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Registers jake with two-factor authentication enabled, and logs him in.
+/// Returns his secret, his recovery codes and the challenge token.
+async fn login_with_two_factor(
+    app: &TestApp,
+) -> (conduit::domain::auth::totp::TotpSecret, Vec<String>, String) {
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jake","email":"jake@jake.com","password":"jake1234"}}"#,
+    )
+    .await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let token = body["user"]["token"].as_str().unwrap();
+
+    let (secret, recovery_codes) = enable_two_factor(app.address(), token).await;
+
+    let response = post_login(app).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let challenge_token = body["twoFactor"]["challengeToken"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+
+    (secret, recovery_codes, challenge_token)
+}
+
+#[actix_rt::test]
+async fn login_with_two_factor_should_return_a_challenge() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let (_, _, challenge_token) = login_with_two_factor(&app).await;
+
+    // Assert
+    assert!(!challenge_token.is_empty());
+
+    // No session is opened yet
+    let response = post_login(&app).await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Null, body["user"]);
+    assert_ne!(Value::Null, body["twoFactor"]["expiresAt"]);
+}
+
+#[actix_rt::test]
+async fn login_2fa_with_valid_code_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let (secret, _, challenge_token) = login_with_two_factor(&app).await;
+
+    // Act
+    let response = post_login_2fa(&app, &challenge_token, &code(&secret, 1)).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("jake", body["user"]["username"]);
+    assert_ne!(Value::Null, body["user"]["token"]);
+    assert_ne!(Value::Null, body["user"]["refreshToken"]);
+
+    // The challenge cannot be used twice
+    let response = post_login_2fa(&app, &challenge_token, &code(&secret, 1)).await;
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn login_2fa_with_replayed_code_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    // The current code has been used for the confirmation
+    let (secret, _, challenge_token) = login_with_two_factor(&app).await;
+
+    // Act
+    let response = post_login_2fa(&app, &challenge_token, &code(&secret, 0)).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn login_2fa_with_recovery_code_should_return_200_once() {
+    // Arrange
+    let app = spawn_app().await;
+    let (_, recovery_codes, challenge_token) = login_with_two_factor(&app).await;
+
+    // Act
+    let response = post_login_2fa(&app, &challenge_token, &recovery_codes[0]).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    // A recovery code cannot be used twice
+    let response = post_login(&app).await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let challenge_token = body["twoFactor"]["challengeToken"].as_str().unwrap();
+
+    let response = post_login_2fa(&app, challenge_token, &recovery_codes[0]).await;
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn login_2fa_with_unknown_challenge_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    let (secret, _, _) = login_with_two_factor(&app).await;
+
+    // Act
+    let response = post_login_2fa(&app, "unknown", &code(&secret, 1)).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn login_2fa_challenge_should_be_revoked_after_too_many_invalid_codes() {
+    // Arrange
+    let app = spawn_app().await;
+    let (secret, _, challenge_token) = login_with_two_factor(&app).await;
+
+    for _ in 0..5 {
+        let response = post_login_2fa(&app, &challenge_token, "000000").await;
+        assert_eq!(403, response.status().as_u16());
+    }
+
+    // Act
+    let response = post_login_2fa(&app, &challenge_token, &code(&secret, 1)).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn invalid_codes_should_lock_the_account_across_challenges() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.account_threshold = 3;
+        c.app.login_lockout.base_lock_duration = 60;
+    })
+    .await;
+    let (secret, _, challenge_token) = login_with_two_factor(&app).await;
+
+    for _ in 0..2 {
+        let response = post_login_2fa(&app, &challenge_token, "000000").await;
+        assert_eq!(403, response.status().as_u16());
+    }
+
+    // The valid password does not forget the invalid codes
+    let response = post_login(&app).await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let challenge_token = body["twoFactor"]["challengeToken"].as_str().unwrap();
+
+    let response = post_login_2fa(&app, challenge_token, "000000").await;
+    assert_eq!(403, response.status().as_u16());
+
+    // Act
+
+    // Even the valid code is refused
+    let response = post_login_2fa(&app, challenge_token, &code(&secret, 1)).await;
+
+    // Assert
+    assert_eq!(429, response.status().as_u16());
+    assert_eq!(429, post_login(&app).await.status().as_u16());
+}
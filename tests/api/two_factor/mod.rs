@@ -0,0 +1,58 @@
+use conduit::domain::auth::totp::TotpSecret;
+use serde_json::Value;
+use time::{Duration, OffsetDateTime};
+
+mod enrollment;
+mod login;
+
+pub(crate) async fn post_two_factor(
+    address: &str,
+    action: &str,
+    body: String,
+    token: &str,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{address}/api/user/2fa/{action}"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Returns the JSON body to send the given code.
+pub(crate) fn code_body(code: &str) -> String {
+    format!(r#"{{"twoFactor":{{"code":"{code}"}}}}"#)
+}
+
+/// Returns the code of the authenticator app, `steps` time steps from now.
+/// Each code can only be used once, so successive steps are used by the
+/// tests (up to one step of drift is accepted): the confirmation uses the
+/// current one.
+pub(crate) fn code(secret: &TotpSecret, steps: i64) -> String {
+    secret.code_at(OffsetDateTime::now_utc() + Duration::seconds(30 * steps))
+}
+
+/// Enables two-factor authentication for the user with the given token.
+/// Returns its secret and its recovery codes.
+pub(crate) async fn enable_two_factor(address: &str, token: &str) -> (TotpSecret, Vec<String>) {
+    let response = post_two_factor(address, "enroll", String::new(), token).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let secret = TotpSecret::from_base32(body["twoFactor"]["secret"].as_str().unwrap()).unwrap();
+
+    let response = post_two_factor(address, "confirm", code_body(&code(&secret, 0)), token).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let recovery_codes = body["twoFactor"]["recoveryCodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c.as_str().unwrap().to_owned())
+        .collect();
+
+    (secret, recovery_codes)
+}
@@ -0,0 +1,61 @@
+use serde_json::Value;
+
+use crate::{helpers::spawn_app, profiles::block::block_user};
+
+async fn get_blocks(address: &str, query: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/user/blocks{query}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_blocks_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user/blocks", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn blocks_should_be_paginated_most_recent_first() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    for username in ["john", "jane", "joe"] {
+        app.insert_user(username).await;
+        block_user(app.address(), username, &token).await;
+    }
+    // Blocked by someone else
+    let john_token = app.create_token("john").await;
+    block_user(app.address(), "jane", &john_token).await;
+
+    // Act
+    let response = get_blocks(app.address(), "?limit=2", &token).await;
+    let first_page: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+
+    let cursor = first_page["nextCursor"].as_str().unwrap();
+    let response = get_blocks(app.address(), &format!("?limit=2&cursor={cursor}"), &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let second_page: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("joe", first_page["profiles"][0]["username"]);
+    assert_eq!("jane", first_page["profiles"][1]["username"]);
+    assert_eq!(1, second_page["profiles"].as_array().unwrap().len());
+    assert_eq!("john", second_page["profiles"][0]["username"]);
+    assert_eq!(Value::Bool(false), second_page["profiles"][0]["following"]);
+    assert_eq!(Value::Null, second_page["nextCursor"]);
+}
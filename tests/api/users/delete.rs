@@ -0,0 +1,241 @@
+use serde_json::Value;
+
+use crate::{
+    helpers::{spawn_app, spawn_app_with, TestApp},
+    tokens::create::create_personal_access_token,
+    two_factor::{code, enable_two_factor},
+    users::register::post_register_with_body,
+};
+
+async fn delete_account_with_body(address: &str, body: String, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .delete(format!("{address}/api/user"))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Registers jake (password "jake1234") and returns its access token.
+async fn register(app: &TestApp) -> String {
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jake","email":"jake@jake.com","password":"jake1234"}}"#,
+    )
+    .await;
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn user_exists(app: &TestApp) -> bool {
+    sqlx::query!(r#"SELECT EXISTS(SELECT 1 FROM users WHERE username = 'jake') AS "exists!""#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user.")
+        .exists
+}
+
+#[actix_rt::test]
+async fn not_authenticated_delete_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .delete(format!("{}/api/user", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_with_incorrect_password_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+
+    // Act
+    let response = delete_account_with_body(
+        app.address(),
+        r#"{"user":{"password":"jake4321"}}"#.into(),
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+    assert!(user_exists(&app).await);
+}
+
+#[actix_rt::test]
+async fn delete_should_remove_the_user_and_its_content() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+    app.insert_user("john").await;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT u1.id, u2.id
+        FROM (VALUES ('john', 'jake'), ('jake', 'john')) AS pairs (follower, followed)
+        JOIN users u1 ON u1.username = pairs.follower
+        JOIN users u2 ON u2.username = pairs.followed
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert followers.");
+
+    // Act
+    let response = delete_account_with_body(
+        app.address(),
+        r#"{"user":{"password":"jake1234"}}"#.into(),
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+    assert!(!user_exists(&app).await);
+
+    let followers = sqlx::query!(r#"SELECT COUNT(*) AS "count!" FROM followers"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count followers.");
+    assert_eq!(0, followers.count);
+
+    // The token no longer authenticates anyone
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn token_of_a_deleted_user_should_not_authenticate_a_new_user_with_its_username() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+
+    let response = delete_account_with_body(
+        app.address(),
+        r#"{"user":{"password":"jake1234"}}"#.into(),
+        &token,
+    )
+    .await;
+    assert_eq!(200, response.status().as_u16());
+
+    // Someone else registers with the same username
+    app.insert_user("jake").await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn delete_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+    let personal_access_token =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = delete_account_with_body(
+        app.address(),
+        r#"{"user":{"password":"jake1234"}}"#.into(),
+        &personal_access_token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+    assert!(user_exists(&app).await);
+}
+
+#[actix_rt::test]
+async fn delete_with_two_factor_should_require_a_code() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+    let (secret, _) = enable_two_factor(app.address(), &token).await;
+
+    let response = delete_account_with_body(
+        app.address(),
+        r#"{"user":{"password":"jake1234"}}"#.into(),
+        &token,
+    )
+    .await;
+    assert_eq!(403, response.status().as_u16());
+    assert!(user_exists(&app).await);
+
+    // Act
+    let response = delete_account_with_body(
+        app.address(),
+        format!(
+            r#"{{"user":{{"password":"jake1234","twoFactorCode":"{}"}}}}"#,
+            code(&secret, 1)
+        ),
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+    assert!(!user_exists(&app).await);
+}
+
+#[actix_rt::test]
+async fn repeated_incorrect_passwords_should_lock_the_account_with_429() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.account_threshold = 3;
+        c.app.login_lockout.base_lock_duration = 60;
+    })
+    .await;
+    let token = register(&app).await;
+
+    for _ in 0..3 {
+        let response = delete_account_with_body(
+            app.address(),
+            r#"{"user":{"password":"jake4321"}}"#.into(),
+            &token,
+        )
+        .await;
+        assert_eq!(403, response.status().as_u16());
+    }
+
+    // Act
+
+    // Even the correct password is refused
+    let response = delete_account_with_body(
+        app.address(),
+        r#"{"user":{"password":"jake1234"}}"#.into(),
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(429, response.status().as_u16());
+    assert!(response.headers().contains_key("Retry-After"));
+    assert!(user_exists(&app).await);
+}
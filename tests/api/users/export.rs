@@ -0,0 +1,203 @@
+use serde_json::Value;
+
+use crate::{
+    articles::{create::post_article_with_body, favorite::favorite_article},
+    comments::add::post_comment_with_body,
+    helpers::spawn_app,
+    tokens::create::create_personal_access_token,
+    users::register::post_register_with_body,
+};
+
+async fn get_export(address: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/user/export"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn not_authenticated_export_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user/export", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn export_should_return_everything_about_the_user() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+    let john_token = app.insert_user("john").await;
+    app.insert_user("jane").await;
+    app.insert_user("joe").await;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO followers (follower_id, followed_id)
+        SELECT u1.id, u2.id
+        FROM (VALUES ('jake', 'john'), ('jane', 'jake')) AS pairs (follower, followed)
+        JOIN users u1 ON u1.username = pairs.follower
+        JOIN users u2 ON u2.username = pairs.followed
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert followers.");
+    sqlx::query!(
+        r#"
+        INSERT INTO blocks (blocker_id, blocked_id)
+        SELECT u1.id, u2.id
+        FROM (VALUES ('jake', 'joe')) AS pairs (blocker, blocked)
+        JOIN users u1 ON u1.username = pairs.blocker
+        JOIN users u2 ON u2.username = pairs.blocked
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert block.");
+    sqlx::query!(
+        r#"
+        INSERT INTO follow_requests (requester_id, requested_id)
+        SELECT u1.id, u2.id
+        FROM (VALUES ('john', 'jake')) AS pairs (requester, requested)
+        JOIN users u1 ON u1.username = pairs.requester
+        JOIN users u2 ON u2.username = pairs.requested
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert follow request.");
+
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"Jake article","description":"Desc","body":"Body","tagList":["rust"]}}"#,
+        &token,
+    )
+    .await;
+    post_article_with_body(
+        app.address(),
+        r#"{"article":{"title":"John article","description":"Desc","body":"Body"}}"#,
+        &john_token,
+    )
+    .await;
+    post_comment_with_body(
+        app.address(),
+        "john-article",
+        r#"{"comment":{"body":"Nice!"}}"#,
+        &token,
+    )
+    .await;
+    favorite_article(app.address(), "john-article", &token).await;
+    create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = get_export(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(
+        r#"attachment; filename="conduit-jake.json""#,
+        response.headers()["Content-Disposition"]
+    );
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let export = &body["export"];
+
+    assert_eq!("jake", export["user"]["username"]);
+    assert_eq!("jake@jake.com", export["user"]["email"]);
+    assert_eq!(Value::Null, export["user"]["pendingEmail"]);
+    assert_eq!(false, export["user"]["twoFactorEnabled"]);
+    assert_eq!(false, export["user"]["private"]);
+    assert_eq!(Value::Null, export["user"]["password"]);
+
+    assert_eq!(serde_json::json!(["john"]), export["following"]);
+    assert_eq!(serde_json::json!(["jane"]), export["followers"]);
+    assert_eq!(serde_json::json!(["joe"]), export["blocks"]);
+    assert_eq!(serde_json::json!([]), export["followRequestsSent"]);
+    assert_eq!(
+        serde_json::json!(["john"]),
+        export["followRequestsReceived"]
+    );
+
+    assert_eq!(1, export["articles"].as_array().unwrap().len());
+    assert_eq!("jake-article", export["articles"][0]["slug"]);
+    assert_eq!(
+        serde_json::json!(["rust"]),
+        export["articles"][0]["tagList"]
+    );
+
+    assert_eq!(1, export["comments"].as_array().unwrap().len());
+    assert_eq!("john-article", export["comments"][0]["articleSlug"]);
+    assert_eq!("Nice!", export["comments"][0]["body"]);
+
+    assert_eq!(serde_json::json!(["john-article"]), export["favorites"]);
+
+    assert_eq!(1, export["personalAccessTokens"].as_array().unwrap().len());
+    assert_eq!("ci", export["personalAccessTokens"][0]["name"]);
+    assert_eq!(Value::Null, export["personalAccessTokens"][0]["token"]);
+}
+
+#[actix_rt::test]
+async fn export_should_return_the_sessions_and_the_login_attempts() {
+    // Arrange
+    let app = spawn_app().await;
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jake","email":"jake@jake.com","password":"jake1234"}}"#,
+    )
+    .await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let token = body["user"]["token"].as_str().unwrap().to_owned();
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/users/login", app.address()))
+        .header("Content-Type", "application/json")
+        .body(r#"{"user":{"email":"jake@jake.com","password":"jake4321"}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(403, response.status().as_u16());
+
+    let response = reqwest::Client::new()
+        .put(format!("{}/api/user", app.address()))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Token {token}"))
+        .body(r#"{"user":{"email":"jake@conduit.com"}}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(200, response.status().as_u16());
+
+    // Act
+    let response = get_export(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let export = &body["export"];
+
+    assert_eq!("jake@jake.com", export["user"]["email"]);
+    assert_eq!("jake@conduit.com", export["user"]["pendingEmail"]);
+
+    // The session opened upon registration
+    assert_eq!(1, export["sessions"].as_array().unwrap().len());
+    assert_ne!(Value::Null, export["sessions"][0]["createdAt"]);
+    assert_ne!(Value::Null, export["sessions"][0]["expiresAt"]);
+    assert_eq!(Value::Null, export["sessions"][0]["usedAt"]);
+    assert_eq!(Value::Null, export["sessions"][0]["token"]);
+
+    assert_eq!(1, export["loginAttempts"]["failedAttempts"]);
+    assert_ne!(Value::Null, export["loginAttempts"]["lastFailureAt"]);
+}
@@ -0,0 +1,167 @@
+use serde_json::Value;
+
+use crate::helpers::{spawn_app, TestApp};
+
+async fn get_follow_requests(address: &str, query: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{address}/api/user/follow-requests{query}"))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn post_follow_request_action(
+    address: &str,
+    username: &str,
+    action: &str,
+    token: &str,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!(
+            "{address}/api/user/follow-requests/{username}/{action}"
+        ))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Makes `requester` request to follow `requested`, `minutes_ago` minutes
+/// ago.
+async fn insert_follow_request(app: &TestApp, requester: &str, requested: &str, minutes_ago: i32) {
+    sqlx::query!(
+        r#"
+        INSERT INTO follow_requests (requester_id, requested_id, created_at)
+        SELECT requester.id, requested.id, now() - make_interval(mins => $3)
+        FROM users requester, users requested
+        WHERE requester.username = $1 AND requested.username = $2
+        "#,
+        requester,
+        requested,
+        minutes_ago
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert follow request.");
+}
+
+#[actix_rt::test]
+async fn not_authenticated_follow_requests_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user/follow-requests", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn follow_requests_should_be_listed_most_recent_first() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    for username in ["john", "jane", "joe"] {
+        app.insert_user(username).await;
+    }
+    insert_follow_request(&app, "john", "jack", 20).await;
+    insert_follow_request(&app, "jane", "jack", 10).await;
+    // Not a request to follow jack
+    insert_follow_request(&app, "joe", "john", 5).await;
+
+    // Act
+    let response = get_follow_requests(app.address(), "", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(2, body["profiles"].as_array().unwrap().len());
+    assert_eq!("jane", body["profiles"][0]["username"]);
+    assert_eq!("john", body["profiles"][1]["username"]);
+    assert_eq!(Value::Bool(false), body["profiles"][0]["following"]);
+    assert_eq!(Value::Null, body["nextCursor"]);
+}
+
+#[actix_rt::test]
+async fn approve_follow_request_should_make_the_requester_follow() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    insert_follow_request(&app, "john", "jack", 10).await;
+
+    // Act
+    let response = post_follow_request_action(app.address(), "john", "approve", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let followers = sqlx::query_scalar!(
+        r#"
+        SELECT follower.username
+        FROM followers f
+        JOIN users follower ON follower.id = f.follower_id
+        JOIN users followed ON followed.id = f.followed_id
+        WHERE followed.username = 'jack'
+        "#
+    )
+    .fetch_all(app.db_pool())
+    .await
+    .expect("Failed to fetch followers");
+    assert_eq!(vec!["john".to_string()], followers);
+
+    let response = get_follow_requests(app.address(), "", &token).await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(0, body["profiles"].as_array().unwrap().len());
+}
+
+#[actix_rt::test]
+async fn reject_follow_request_should_not_make_the_requester_follow() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    insert_follow_request(&app, "john", "jack", 10).await;
+
+    // Act
+    let response = post_follow_request_action(app.address(), "john", "reject", &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let follows = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM followers"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count followers");
+    assert_eq!(0, follows);
+
+    let response = get_follow_requests(app.address(), "", &token).await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(0, body["profiles"].as_array().unwrap().len());
+}
+
+#[actix_rt::test]
+async fn unknown_follow_request_should_return_404() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    for action in ["approve", "reject"] {
+        // Act
+        let response = post_follow_request_action(app.address(), "john", action, &token).await;
+
+        // Assert
+        assert_eq!(404, response.status().as_u16());
+    }
+}
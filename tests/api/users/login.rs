@@ -1,10 +1,14 @@
+use conduit::domain::users::password::{verify_password, PasswordVerification};
 use serde_json::Value;
 
-use crate::{helpers::spawn_app, users::register::post_register_with_body};
+use crate::{
+    helpers::{spawn_app, spawn_app_with},
+    users::register::post_register_with_body,
+};
 
 async fn post_login_with_body(address: &str, body: &'static str) -> reqwest::Response {
     reqwest::Client::new()
-        .post(&format!("{}/api/users/login", address))
+        .post(format!("{}/api/users/login", address))
         .header("Content-Type", "application/json")
         .body(body)
         .send()
@@ -20,7 +24,7 @@ async fn login_with_no_body_should_return_422() {
 
     // Act
     let response = client
-        .post(&format!("{}/api/users/login", app.address()))
+        .post(format!("{}/api/users/login", app.address()))
         .send()
         .await
         .expect("Failed to execute request.");
@@ -37,7 +41,7 @@ async fn login_with_no_content_type_should_return_422() {
 
     // Act
     let response = client
-        .post(&format!("{}/api/users/login", app.address()))
+        .post(format!("{}/api/users/login", app.address()))
         .body(r#"{"user":{"email":"jake@jake.com","password":"jack"}}"#)
         .send()
         .await
@@ -137,6 +141,9 @@ async fn invalid_password_should_return_403() {
 
     // Assert
     assert_eq!(403, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("Incorrect email or password.", body["errors"]["body"][0]);
 }
 
 #[actix_rt::test]
@@ -167,7 +174,272 @@ async fn valid_login_should_return_200() {
     assert_ne!(Value::Null, body["user"]["username"]);
     assert_ne!(Value::Null, body["user"]["email"]);
     assert_ne!(Value::Null, body["user"]["token"]);
+    assert_ne!(Value::Null, body["user"]["refreshToken"]);
 
     assert_eq!(Value::Null, body["user"]["bio"]);
     assert_eq!(Value::Null, body["user"]["image"]);
 }
+
+#[actix_rt::test]
+async fn valid_login_with_legacy_password_should_rehash_it() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // SHA3-512 hash of "jack1234"
+    sqlx::query!(
+        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "jack",
+        "jake@jake.com",
+        "d7f17e85cf8d2a63fa41e5e65f4f016ca89cc705f4ca49802e6e0a070bd41d79889968876261e1ddee91dfae38d868f7ad7d232da2207a06affa53341b89e7eb"
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert user.");
+
+    // Act
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let saved = sqlx::query!("SELECT password FROM users WHERE username = 'jack'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user");
+
+    assert_eq!(
+        PasswordVerification::Valid,
+        verify_password("jack1234", &saved.password)
+    );
+}
+
+#[actix_rt::test]
+async fn invalid_login_with_legacy_password_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // SHA3-512 hash of "jack1234"
+    sqlx::query!(
+        "INSERT INTO users (username, email, password) VALUES ($1, $2, $3)",
+        "jack",
+        "jake@jake.com",
+        "d7f17e85cf8d2a63fa41e5e65f4f016ca89cc705f4ca49802e6e0a070bd41d79889968876261e1ddee91dfae38d868f7ad7d232da2207a06affa53341b89e7eb"
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert user.");
+
+    // Act
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn repeated_invalid_passwords_should_lock_the_account_with_429() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.account_threshold = 3;
+        c.app.login_lockout.base_lock_duration = 60;
+    })
+    .await;
+
+    post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    for _ in 0..3 {
+        let response = post_login_with_body(
+            app.address(),
+            r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+        )
+        .await;
+        assert_eq!(403, response.status().as_u16());
+    }
+
+    // Act
+
+    // Even the correct password is refused
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(429, response.status().as_u16());
+
+    let retry_after: u64 = response.headers()["Retry-After"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after > 0 && retry_after <= 60);
+}
+
+#[actix_rt::test]
+async fn lock_duration_should_grow_with_each_failed_attempt() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.account_threshold = 1;
+        c.app.login_lockout.base_lock_duration = 60;
+    })
+    .await;
+
+    sqlx::query!(
+        "INSERT INTO login_attempts (key, failed_attempts) VALUES ($1, $2)",
+        "account:jake@jake.com",
+        2
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert login attempts.");
+
+    // Act
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+    )
+    .await;
+    assert_eq!(403, response.status().as_u16());
+
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+    )
+    .await;
+
+    // Assert
+
+    // Third failed attempt with a threshold of 1: locked for 4 times 60 seconds
+    assert_eq!(429, response.status().as_u16());
+
+    let retry_after: u64 = response.headers()["Retry-After"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after > 180 && retry_after <= 240);
+}
+
+#[actix_rt::test]
+async fn repeated_failures_from_the_same_ip_should_return_429() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.ip_threshold = 2;
+    })
+    .await;
+
+    // Unknown accounts
+    for body in [
+        r#"{"user":{"email":"jake@jake.com","password":"jake1234"}}"#,
+        r#"{"user":{"email":"john@john.com","password":"john1234"}}"#,
+    ] {
+        let response = post_login_with_body(app.address(), body).await;
+        assert_eq!(403, response.status().as_u16());
+    }
+
+    // Act
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jane@jane.com","password":"jane1234"}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(429, response.status().as_u16());
+    assert!(response.headers().contains_key("Retry-After"));
+}
+
+#[actix_rt::test]
+async fn valid_login_should_reset_the_failed_attempts() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.account_threshold = 3;
+    })
+    .await;
+
+    post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    for body in [
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+        r#"{"user":{"email":"jake@jake.com","password":"jack1234"}}"#,
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+        r#"{"user":{"email":"jake@jake.com","password":"jack4321"}}"#,
+    ] {
+        post_login_with_body(app.address(), body).await;
+    }
+
+    // Act
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn valid_login_should_not_reset_the_failed_attempts_of_the_ip() {
+    // Arrange
+    let app = spawn_app_with(|c| {
+        c.app.login_lockout.ip_threshold = 3;
+    })
+    .await;
+
+    post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    for (body, status) in [
+        (
+            r#"{"user":{"email":"john@john.com","password":"john1234"}}"#,
+            403,
+        ),
+        (
+            r#"{"user":{"email":"jane@jane.com","password":"jane1234"}}"#,
+            403,
+        ),
+        (
+            r#"{"user":{"email":"jake@jake.com","password":"jack1234"}}"#,
+            200,
+        ),
+        (
+            r#"{"user":{"email":"jim@jim.com","password":"jim12345"}}"#,
+            403,
+        ),
+    ] {
+        let response = post_login_with_body(app.address(), body).await;
+        assert_eq!(status, response.status().as_u16());
+    }
+
+    // Act
+    let response = post_login_with_body(
+        app.address(),
+        r#"{"user":{"email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(429, response.status().as_u16());
+}
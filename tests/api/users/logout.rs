@@ -0,0 +1,182 @@
+use serde_json::Value;
+
+use crate::{
+    helpers::spawn_app,
+    tokens::create::create_personal_access_token,
+    users::{refresh::post_refresh, register::post_register_with_body},
+};
+
+async fn post_logout(address: &str, path: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/user/{}", address, path))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn get_user_info(address: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{}/api/user", address))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Registers `jack` and returns its access token and refresh token.
+async fn register_jack(address: &str) -> (String, String) {
+    let response = post_register_with_body(
+        address,
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    (
+        body["user"]["token"].as_str().unwrap().to_owned(),
+        body["user"]["refreshToken"].as_str().unwrap().to_owned(),
+    )
+}
+
+#[actix_rt::test]
+async fn logout_without_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/user/logout", app.address()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn logout_should_revoke_the_access_token() {
+    // Arrange
+    let app = spawn_app().await;
+    let jack_token = app.insert_user("jack").await;
+    let other_token = app.create_token("jack").await;
+
+    // Act
+    let response = post_logout(app.address(), "logout", &jack_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let response = get_user_info(app.address(), &jack_token).await;
+    assert_eq!(401, response.status().as_u16());
+
+    // The other tokens are still valid
+    let response = get_user_info(app.address(), &other_token).await;
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn logout_should_revoke_a_token_expired_within_the_leeway() {
+    // Arrange
+    let app = spawn_app().await;
+    register_jack(app.address()).await;
+    app.insert_user("john").await;
+
+    // Expired, but still accepted within the leeway
+    let token = app
+        .create_token_with_ttl("jack", time::Duration::seconds(-10))
+        .await;
+    assert_eq!(
+        200,
+        get_user_info(app.address(), &token).await.status().as_u16()
+    );
+
+    // Act
+    let response = post_logout(app.address(), "logout", &token).await;
+    assert_eq!(200, response.status().as_u16());
+
+    // Another logout cleans up the expired revoked tokens
+    let other_token = app.create_token("john").await;
+    post_logout(app.address(), "logout", &other_token).await;
+
+    // Assert
+    let response = get_user_info(app.address(), &token).await;
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn logout_with_refresh_token_should_revoke_it() {
+    // Arrange
+    let app = spawn_app().await;
+    let (token, refresh_token) = register_jack(app.address()).await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/user/logout", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .header("Content-Type", "application/json")
+        .body(format!(
+            r#"{{"user":{{"refreshToken":"{refresh_token}"}}}}"#
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let response = post_refresh(app.address(), &refresh_token).await;
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn logout_everywhere_should_revoke_every_token() {
+    // Arrange
+    let app = spawn_app().await;
+    let (token, refresh_token) = register_jack(app.address()).await;
+    let other_token = app.create_token("jack").await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = post_logout(app.address(), "logout/all", &other_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    for token in [&token, &other_token, &pat] {
+        let response = get_user_info(app.address(), token).await;
+        assert_eq!(401, response.status().as_u16());
+    }
+
+    let response = post_refresh(app.address(), &refresh_token).await;
+    assert_eq!(401, response.status().as_u16());
+
+    // Tokens issued right afterwards are valid
+    let response = get_user_info(app.address(), &app.create_token("jack").await).await;
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn logout_everywhere_with_personal_access_token_should_return_403() {
+    // Arrange
+    let app = spawn_app().await;
+    let (token, _) = register_jack(app.address()).await;
+    let pat =
+        create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &token).await;
+
+    // Act
+    let response = post_logout(app.address(), "logout/all", &pat).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+
+    // Nothing has been revoked
+    for token in [&token, &pat] {
+        let response = get_user_info(app.address(), token).await;
+        assert_eq!(200, response.status().as_u16());
+    }
+}
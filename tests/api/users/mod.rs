@@ -1,4 +1,12 @@
+mod blocks;
+mod delete;
+mod export;
+mod follow_requests;
 mod login;
-mod register;
-mod update;
+mod logout;
+mod password_reset;
+pub(crate) mod refresh;
+pub(crate) mod register;
+pub(crate) mod update;
 mod user_info;
+mod verify_email;
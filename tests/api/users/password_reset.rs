@@ -0,0 +1,265 @@
+use serde_json::Value;
+
+use crate::{
+    helpers::{spawn_app, token_in_email, TestApp},
+    tokens::create::create_personal_access_token,
+};
+
+async fn post_forgot_with_body(address: &str, body: &'static str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/password/forgot", address))
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn post_reset(address: &str, token: &str, password: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/password/reset", address))
+        .header("Content-Type", "application/json")
+        .body(format!(
+            r#"{{"user":{{"token":"{token}","password":"{password}"}}}}"#
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn post_login(address: &str, password: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/login", address))
+        .header("Content-Type", "application/json")
+        .body(format!(
+            r#"{{"user":{{"email":"jake@jake.com","password":"{password}"}}}}"#
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Registers jake (jake@jake.com, password "jake1234"), requests a password
+/// reset for it and returns the emailed token.
+async fn request_reset_token(app: &TestApp) -> String {
+    crate::users::register::post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jake","email":"jake@jake.com","password":"jake1234"}}"#,
+    )
+    .await;
+
+    let response =
+        post_forgot_with_body(app.address(), r#"{"user":{"email":"jake@jake.com"}}"#).await;
+    assert_eq!(202, response.status().as_u16());
+
+    // After the email verification sent upon registration
+    token_in_email(app.wait_for_emails(2).await.last().unwrap())
+}
+
+#[actix_rt::test]
+async fn forgot_with_invalid_email_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = post_forgot_with_body(app.address(), r#"{"user":{"email":"@jake.com"}}"#).await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_ne!(Value::Null, body["errors"]["email"]);
+}
+
+#[actix_rt::test]
+async fn forgot_with_unknown_email_should_return_202_without_email() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response =
+        post_forgot_with_body(app.address(), r#"{"user":{"email":"jake@jake.com"}}"#).await;
+
+    // Assert
+    assert_eq!(202, response.status().as_u16());
+    assert!(app.sent_emails().is_empty());
+}
+
+#[actix_rt::test]
+async fn forgot_should_email_a_reset_token() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    request_reset_token(&app).await;
+
+    // Assert
+    // After the email verification sent upon registration
+    let emails = app.wait_for_emails(2).await;
+    assert_eq!(2, emails.len());
+    assert!(emails[1].contains("To: jake@jake.com"));
+    assert!(emails[1].contains("Reset your Conduit password"));
+
+    // Only a hash of the token is stored
+    let stored = sqlx::query!("SELECT token_hash FROM password_reset_tokens")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch reset token.");
+    assert!(!emails[1].contains(&stored.token_hash));
+}
+
+#[actix_rt::test]
+async fn reset_should_change_the_password() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = request_reset_token(&app).await;
+
+    // Act
+    let response = post_reset(app.address(), &token, "jake4321").await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    assert_eq!(
+        403,
+        post_login(app.address(), "jake1234")
+            .await
+            .status()
+            .as_u16()
+    );
+    assert_eq!(
+        200,
+        post_login(app.address(), "jake4321")
+            .await
+            .status()
+            .as_u16()
+    );
+}
+
+#[actix_rt::test]
+async fn reset_token_should_be_single_use() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = request_reset_token(&app).await;
+
+    let response = post_reset(app.address(), &token, "jake4321").await;
+    assert_eq!(200, response.status().as_u16());
+
+    // Act
+    let response = post_reset(app.address(), &token, "jake0000").await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        200,
+        post_login(app.address(), "jake4321")
+            .await
+            .status()
+            .as_u16()
+    );
+}
+
+#[actix_rt::test]
+async fn expired_reset_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = request_reset_token(&app).await;
+
+    sqlx::query!("UPDATE password_reset_tokens SET expires_at = now() - interval '1 minute'")
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to expire reset token.");
+
+    // Act
+    let response = post_reset(app.address(), &token, "jake4321").await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn new_reset_request_should_replace_the_previous_token() {
+    // Arrange
+    let app = spawn_app().await;
+    let first_token = request_reset_token(&app).await;
+
+    post_forgot_with_body(app.address(), r#"{"user":{"email":"jake@jake.com"}}"#).await;
+    let second_token = token_in_email(app.wait_for_emails(3).await.last().unwrap());
+
+    // Act
+    let response = post_reset(app.address(), &first_token, "jake4321").await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        200,
+        post_reset(app.address(), &second_token, "jake4321")
+            .await
+            .status()
+            .as_u16()
+    );
+}
+
+#[actix_rt::test]
+async fn reset_should_revoke_the_existing_tokens() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = request_reset_token(&app).await;
+
+    let response = post_login(app.address(), "jake1234").await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let jwt = body["user"]["token"].as_str().unwrap().to_string();
+    let pat = create_personal_access_token(app.address(), r#"{"token":{"name":"ci"}}"#, &jwt).await;
+
+    // Act
+    let response = post_reset(app.address(), &token, "jake4321").await;
+    assert_eq!(200, response.status().as_u16());
+
+    // Assert
+    for token in [&jwt, &pat] {
+        let response = reqwest::Client::new()
+            .get(format!("{}/api/user", app.address()))
+            .header("Authorization", format!("Token {token}"))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(401, response.status().as_u16());
+    }
+
+    // Logging in right afterwards gives a valid token
+    let response = post_login(app.address(), "jake4321").await;
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let jwt = body["user"]["token"].as_str().unwrap();
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Token {jwt}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn reset_with_empty_password_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = request_reset_token(&app).await;
+
+    // Act
+    let response = post_reset(app.address(), &token, "").await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    // The token was not consumed
+    assert_eq!(
+        200,
+        post_reset(app.address(), &token, "jake4321")
+            .await
+            .status()
+            .as_u16()
+    );
+}
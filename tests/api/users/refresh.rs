@@ -0,0 +1,117 @@
+use serde_json::Value;
+
+use crate::{helpers::spawn_app, users::register::post_register_with_body};
+
+pub(crate) async fn post_refresh(address: &str, refresh_token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/token/refresh", address))
+        .header("Content-Type", "application/json")
+        .body(format!(
+            r#"{{"user":{{"refreshToken":"{refresh_token}"}}}}"#
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Registers `jack` and returns its refresh token.
+async fn register_jack(address: &str) -> String {
+    let response = post_register_with_body(
+        address,
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    body["user"]["refreshToken"].as_str().unwrap().to_owned()
+}
+
+#[actix_rt::test]
+async fn refresh_with_valid_token_should_return_200_with_new_tokens() {
+    // Arrange
+    let app = spawn_app().await;
+    let refresh_token = register_jack(app.address()).await;
+
+    // Act
+    let response = post_refresh(app.address(), &refresh_token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("jack", body["user"]["username"]);
+    assert_ne!(
+        refresh_token,
+        body["user"]["refreshToken"].as_str().unwrap()
+    );
+
+    // The new access token is valid
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header(
+            "Authorization",
+            format!("Token {}", body["user"]["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn refresh_with_unknown_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = post_refresh(app.address(), "unknown").await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn refresh_with_expired_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    let refresh_token = register_jack(app.address()).await;
+
+    sqlx::query!("UPDATE refresh_tokens SET expires_at = now() - INTERVAL '1 second'")
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to expire the refresh tokens.");
+
+    // Act
+    let response = post_refresh(app.address(), &refresh_token).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn reusing_a_refresh_token_should_revoke_its_family() {
+    // Arrange
+    let app = spawn_app().await;
+    let refresh_token = register_jack(app.address()).await;
+
+    let response = post_refresh(app.address(), &refresh_token).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let rotated_token = body["user"]["refreshToken"].as_str().unwrap();
+
+    // Act
+
+    // The first token is used again!
+    let response = post_refresh(app.address(), &refresh_token).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+
+    // The token obtained by rotation has been revoked too
+    let response = post_refresh(app.address(), rotated_token).await;
+    assert_eq!(401, response.status().as_u16());
+}
@@ -1,4 +1,5 @@
 use claim::assert_none;
+use conduit::domain::users::password::{verify_password, PasswordVerification};
 use serde_json::Value;
 
 use crate::helpers::spawn_app;
@@ -8,7 +9,7 @@ pub(crate) async fn post_register_with_body(
     body: &'static str,
 ) -> reqwest::Response {
     reqwest::Client::new()
-        .post(&format!("{}/api/users", address))
+        .post(format!("{}/api/users", address))
         .header("Content-Type", "application/json")
         .body(body)
         .send()
@@ -24,7 +25,7 @@ async fn register_with_no_body_should_return_422() {
 
     // Act
     let response = client
-        .post(&format!("{}/api/users", app.address()))
+        .post(format!("{}/api/users", app.address()))
         .send()
         .await
         .expect("Failed to execute request.");
@@ -41,7 +42,7 @@ async fn register_with_no_content_type_should_return_422() {
 
     // Act
     let response = client
-        .post(&format!("{}/api/users", app.address()))
+        .post(format!("{}/api/users", app.address()))
         .body(r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack"}}"#)
         .send()
         .await
@@ -144,8 +145,10 @@ async fn register_with_valid_body_persists_in_database() {
 
     assert_eq!("jack", saved.username);
     assert_eq!("jake@jake.com", saved.email);
-    // SHA3-512 hash of "jack"
-    assert_eq!("d309fd6af59c2018f41b3b2285b1570a2ac2fc3d3bbb467f2e74ba5196fa9bde15834ff7eac93de3e8fbf83249d767c0e8d90cdc22fcb6d2785ff91bfbcd79c4", saved.password);
+    assert_eq!(
+        PasswordVerification::Valid,
+        verify_password("jack", &saved.password)
+    );
     assert_none!(saved.bio);
     assert_none!(saved.image);
 }
@@ -175,6 +178,9 @@ async fn register_with_already_used_username_or_email_should_return_422() {
 
     assert_eq!(422, response.status().as_u16());
 
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["username"][0]);
+
     // Third insertion, the email address is the same
     let response = post_register_with_body(
         app.address(),
@@ -183,4 +189,49 @@ async fn register_with_already_used_username_or_email_should_return_422() {
     .await;
 
     assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["email"][0]);
+}
+
+#[actix_rt::test]
+async fn register_with_several_invalid_fields_should_report_all_of_them() {
+    // Arrange
+    let app = spawn_app().await;
+    app.insert_user("jack").await;
+
+    // Act
+
+    // Invalid username, invalid email address and empty password!
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"not valid","email":"@jake.com","password":""}}"#,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let errors = body["errors"].as_object().unwrap();
+    assert_eq!(3, errors.len());
+    assert!(errors.contains_key("username"));
+    assert!(errors.contains_key("email"));
+    assert!(errors.contains_key("password"));
+
+    // Taken username, taken email address and empty password!
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jack","email":"jack@jack.com","password":""}}"#,
+    )
+    .await;
+
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let errors = body["errors"].as_object().unwrap();
+    assert_eq!(3, errors.len());
+    assert_eq!("has already been taken", errors["username"][0]);
+    assert_eq!("has already been taken", errors["email"][0]);
+    assert!(errors.contains_key("password"));
 }
@@ -1,10 +1,15 @@
+use conduit::domain::users::password::{verify_password, PasswordVerification};
 use serde_json::Value;
 
 use crate::{helpers::spawn_app, users::register::post_register_with_body};
 
-async fn put_update_with_body(address: &str, body: &'static str, token: &str) -> reqwest::Response {
+pub(crate) async fn put_update_with_body(
+    address: &str,
+    body: &'static str,
+    token: &str,
+) -> reqwest::Response {
     reqwest::Client::new()
-        .put(&format!("{}/api/user", address))
+        .put(format!("{}/api/user", address))
         .header("Content-Type", "application/json")
         .header("Authorization", format!("Token {token}"))
         .body(body)
@@ -96,9 +101,12 @@ async fn update_everything_should_return_200() {
     .expect("Failed to fetch user");
 
     assert_eq!("new_username", saved.username);
-    assert_eq!("username@username.com", saved.email);
-    // SHA3-512 of "new_passord1234"
-    assert_eq!("768e355beb99d533c26f794ece10bedc114021ffa3721cb5c87307f1e6f78b2f854b71b08511a0d943e4b7ec49603446d79b7c53b44ed51861aa1f9624f3b29e", saved.password);
+    // The new email is only set once verified
+    assert_eq!("jake@jake.com", saved.email);
+    assert_eq!(
+        PasswordVerification::Valid,
+        verify_password("new_passord1234", &saved.password)
+    );
     assert_eq!("This is my bio", saved.bio.unwrap());
     assert_eq!("https://image.com", saved.image.unwrap());
 }
@@ -260,3 +268,276 @@ async fn update_invalid_email_should_return_422() {
     // Assert
     assert_eq!(422, response.status().as_u16());
 }
+
+#[actix_rt::test]
+async fn update_bio_with_quotes_and_backslashes_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let token = body["user"]["token"].as_str().unwrap();
+
+    // Act
+    let response = put_update_with_body(
+        app.address(),
+        r#"{"user":{"bio":"It's a \"quoted\" bio with a \\ backslash and ''double'' quotes"}}"#,
+        token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let saved = sqlx::query!("SELECT bio FROM users WHERE username = 'jack'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user");
+
+    assert_eq!(
+        r#"It's a "quoted" bio with a \ backslash and ''double'' quotes"#,
+        saved.bio.unwrap()
+    );
+}
+
+#[actix_rt::test]
+async fn update_with_sql_fragments_should_not_be_interpreted() {
+    // Arrange
+    let app = spawn_app().await;
+
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jack","email":"jake@jake.com","password":"jack1234"}}"#,
+    )
+    .await;
+
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let token = body["user"]["token"].as_str().unwrap();
+
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"john","email":"john@john.com","password":"john1234"}}"#,
+    )
+    .await;
+
+    assert_eq!(201, response.status().as_u16());
+
+    // Act
+    let response = put_update_with_body(
+        app.address(),
+        r#"
+        {
+            "user": {
+                "bio": "', bio = 'pwned' WHERE username = 'john'; --",
+                "image": "https://image.com/o'brien.png?q=1'%20OR%20'1'='1"
+            }
+        }"#,
+        token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let jack = sqlx::query!("SELECT bio, image FROM users WHERE username = 'jack'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user");
+
+    assert_eq!(
+        "', bio = 'pwned' WHERE username = 'john'; --",
+        jack.bio.unwrap()
+    );
+    assert_eq!(
+        "https://image.com/o'brien.png?q=1'%20OR%20'1'='1",
+        jack.image.unwrap()
+    );
+
+    let john = sqlx::query!("SELECT bio, image FROM users WHERE username = 'john'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user");
+
+    assert_eq!(None, john.bio);
+    assert_eq!(None, john.image);
+}
+
+#[actix_rt::test]
+async fn update_with_already_used_username_or_email_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    // Act & Assert
+
+    // The username is already taken
+    let response =
+        put_update_with_body(app.address(), r#"{"user":{"username":"john"}}"#, &token).await;
+
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["username"][0]);
+
+    // The email address is already taken
+    let response = put_update_with_body(
+        app.address(),
+        r#"{"user":{"email":"john@john.com"}}"#,
+        &token,
+    )
+    .await;
+
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["email"][0]);
+
+    // Both are taken, and the image is invalid
+    let response = put_update_with_body(
+        app.address(),
+        r#"{"user":{"username":"john","email":"john@john.com","image":"not an URI"}}"#,
+        &token,
+    )
+    .await;
+
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["username"][0]);
+    assert_eq!("has already been taken", body["errors"]["email"][0]);
+    assert_ne!(Value::Null, body["errors"]["image"]);
+}
+
+#[actix_rt::test]
+async fn update_username_should_keep_the_tokens_and_follows() {
+    // Arrange
+    let app = spawn_app().await;
+
+    let token = app.insert_user("jack").await;
+    let john_token = app.insert_user("john").await;
+
+    reqwest::Client::new()
+        .post(format!("{}/api/profiles/jack/follow", app.address()))
+        .header("Authorization", format!("Token {john_token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response =
+        put_update_with_body(app.address(), r#"{"user":{"username":"jacky"}}"#, &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    // The former token still authenticates the user, under its new username
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("jacky", body["user"]["username"]);
+
+    // The follow relationships are kept
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/profiles/jacky", app.address()))
+        .header("Authorization", format!("Token {john_token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(true), body["profile"]["following"]);
+    assert_eq!(1, body["profile"]["followersCount"]);
+}
+
+#[actix_rt::test]
+async fn update_private_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+
+    // Act
+    let response =
+        put_update_with_body(app.address(), r#"{"user":{"private":true}}"#, &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(Value::Bool(true), body["user"]["private"]);
+
+    let saved = sqlx::query!("SELECT private FROM users WHERE username = 'jack'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user");
+    assert!(saved.private);
+}
+
+#[actix_rt::test]
+async fn update_to_public_should_approve_the_follow_requests() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jack").await;
+    app.insert_user("john").await;
+
+    sqlx::query!("UPDATE users SET private = true WHERE username = 'jack'")
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to update user.");
+    sqlx::query!(
+        r#"
+        INSERT INTO follow_requests (requester_id, requested_id)
+        SELECT u1.id, u2.id
+        FROM (VALUES ('john', 'jack')) AS pairs (requester, requested)
+        JOIN users u1 ON u1.username = pairs.requester
+        JOIN users u2 ON u2.username = pairs.requested
+        "#
+    )
+    .execute(app.db_pool())
+    .await
+    .expect("Failed to insert follow request.");
+
+    // Act
+    let response =
+        put_update_with_body(app.address(), r#"{"user":{"private":false}}"#, &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    let followers = sqlx::query_scalar!(
+        r#"
+        SELECT follower.username
+        FROM followers f
+        JOIN users follower ON follower.id = f.follower_id
+        JOIN users followed ON followed.id = f.followed_id
+        WHERE followed.username = 'jack'
+        "#
+    )
+    .fetch_all(app.db_pool())
+    .await
+    .expect("Failed to fetch followers");
+    assert_eq!(vec!["john".to_string()], followers);
+
+    let requests = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM follow_requests"#)
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to count follow requests");
+    assert_eq!(0, requests);
+}
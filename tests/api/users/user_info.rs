@@ -1,6 +1,9 @@
 use serde_json::Value;
 
-use crate::{helpers::spawn_app, users::register::post_register_with_body};
+use crate::{
+    helpers::{spawn_app, TestApp},
+    users::register::post_register_with_body,
+};
 
 #[actix_rt::test]
 async fn get_user_info_without_token_should_return_401() {
@@ -10,13 +13,20 @@ async fn get_user_info_without_token_should_return_401() {
 
     // Act
     let response = client
-        .get(&format!("{}/api/user", app.address()))
+        .get(format!("{}/api/user", app.address()))
         .send()
         .await
         .expect("Failed to execute request.");
 
     // Assert
     assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        r#"Bearer realm="conduit""#,
+        response.headers()["WWW-Authenticate"]
+    );
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("You're not authenticated.", body["errors"]["body"][0]);
 }
 
 #[actix_rt::test]
@@ -27,7 +37,7 @@ async fn get_user_info_with_bad_header_should_return_401() {
 
     // Act
     let response = client
-        .get(&format!("{}/api/user", app.address()))
+        .get(format!("{}/api/user", app.address()))
         .header("Authorization", "Basic token.goes.here")
         .send()
         .await
@@ -35,6 +45,10 @@ async fn get_user_info_with_bad_header_should_return_401() {
 
     // Assert
     assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        r#"Bearer realm="conduit""#,
+        response.headers()["WWW-Authenticate"]
+    );
 }
 
 #[actix_rt::test]
@@ -45,7 +59,7 @@ async fn get_user_info_with_invalid_token_should_return_401() {
 
     // Act
     let response = client
-        .get(&format!("{}/api/user", app.address()))
+        .get(format!("{}/api/user", app.address()))
         .header("Authorization", "Token invalid_token")
         .send()
         .await
@@ -53,6 +67,10 @@ async fn get_user_info_with_invalid_token_should_return_401() {
 
     // Assert
     assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        r#"Bearer realm="conduit", error="invalid_token", error_description="Invalid token.""#,
+        response.headers()["WWW-Authenticate"]
+    );
 }
 
 #[actix_rt::test]
@@ -74,7 +92,7 @@ async fn get_user_info_after_insertion_should_return_200() {
 
     // Act
     let response = client
-        .get(&format!("{}/api/user", app.address()))
+        .get(format!("{}/api/user", app.address()))
         .header("Authorization", format!("Token {}", token))
         .send()
         .await
@@ -92,3 +110,66 @@ async fn get_user_info_after_insertion_should_return_200() {
     assert_eq!(Value::Null, body["user"]["bio"]);
     assert_eq!(Value::Null, body["user"]["image"]);
 }
+
+async fn get_user_info_with_authorization(app: &TestApp, authorization: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", authorization)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[actix_rt::test]
+async fn get_user_info_with_bearer_token_should_return_200() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    for authorization in [format!("Bearer {token}"), format!("bearer  {token}")] {
+        // Act
+        let response = get_user_info_with_authorization(&app, &authorization).await;
+
+        // Assert
+        assert_eq!(200, response.status().as_u16());
+    }
+}
+
+#[actix_rt::test]
+async fn get_user_info_with_unknown_scheme_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+
+    // No separator between the scheme and the token!
+    let response = get_user_info_with_authorization(&app, &format!("Token{token}")).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        r#"Bearer realm="conduit""#,
+        response.headers()["WWW-Authenticate"]
+    );
+}
+
+#[actix_rt::test]
+async fn get_user_info_with_malformed_header_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = get_user_info_with_authorization(&app, &format!("Bearer {token} {token}")).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        r#"Bearer realm="conduit", error="invalid_request", error_description="Malformed Authorization header.""#,
+        response.headers()["WWW-Authenticate"]
+    );
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("Malformed Authorization header.", body["errors"]["body"][0]);
+}
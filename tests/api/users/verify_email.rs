@@ -0,0 +1,280 @@
+use conduit::domain::users::email_verification::UnverifiedEmailPolicy;
+use serde_json::Value;
+
+use crate::{
+    articles::create::post_article_with_body,
+    helpers::{spawn_app, spawn_app_with, token_in_email, TestApp},
+    users::{register::post_register_with_body, update::put_update_with_body},
+};
+
+const ARTICLE: &str = r#"{"article":{"title":"Title","description":"Desc","body":"Body"}}"#;
+
+async fn post_verify_email(address: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/users/verify-email", address))
+        .header("Content-Type", "application/json")
+        .body(format!(r#"{{"user":{{"token":"{token}"}}}}"#))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn post_resend(address: &str, token: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/user/verify-email/resend", address))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+/// Registers jake (jake@jake.com) and returns its access token.
+async fn register(app: &TestApp) -> String {
+    let response = post_register_with_body(
+        app.address(),
+        r#"{"user":{"username":"jake","email":"jake@jake.com","password":"jake1234"}}"#,
+    )
+    .await;
+    assert_eq!(201, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    body["user"]["token"].as_str().unwrap().to_string()
+}
+
+async fn saved_email(app: &TestApp) -> (String, bool) {
+    let saved = sqlx::query!("SELECT email, email_verified FROM users WHERE username = 'jake'")
+        .fetch_one(app.db_pool())
+        .await
+        .expect("Failed to fetch user.");
+
+    (saved.email, saved.email_verified)
+}
+
+#[actix_rt::test]
+async fn registration_should_email_a_verification_token() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    register(&app).await;
+
+    // Assert
+    let emails = app.sent_emails();
+    assert_eq!(1, emails.len());
+    assert!(emails[0].contains("To: jake@jake.com"));
+    token_in_email(&emails[0]);
+
+    assert_eq!(
+        ("jake@jake.com".to_string(), false),
+        saved_email(&app).await
+    );
+}
+
+#[actix_rt::test]
+async fn valid_token_should_verify_the_email() {
+    // Arrange
+    let app = spawn_app().await;
+    register(&app).await;
+    let token = token_in_email(&app.sent_emails()[0]);
+
+    // Act
+    let response = post_verify_email(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(("jake@jake.com".to_string(), true), saved_email(&app).await);
+
+    // The token is single-use
+    let response = post_verify_email(app.address(), &token).await;
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn unknown_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = post_verify_email(app.address(), "unknown").await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn expired_token_should_return_401() {
+    // Arrange
+    let app = spawn_app().await;
+    register(&app).await;
+    let token = token_in_email(&app.sent_emails()[0]);
+
+    sqlx::query!("UPDATE email_verifications SET expires_at = now() - interval '1 minute'")
+        .execute(app.db_pool())
+        .await
+        .expect("Failed to expire verification.");
+
+    // Act
+    let response = post_verify_email(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(401, response.status().as_u16());
+    assert_eq!(
+        ("jake@jake.com".to_string(), false),
+        saved_email(&app).await
+    );
+}
+
+#[actix_rt::test]
+async fn unverified_user_should_only_read_by_default() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+
+    // Act
+    let response = post_article_with_body(app.address(), ARTICLE, &token).await;
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+
+    // The account can still be managed
+    let response =
+        put_update_with_body(app.address(), r#"{"user":{"bio":"My bio"}}"#, &token).await;
+    assert_eq!(200, response.status().as_u16());
+
+    // Once verified, everything is allowed
+    post_verify_email(app.address(), &token_in_email(&app.sent_emails()[0])).await;
+
+    let response = post_article_with_body(app.address(), ARTICLE, &token).await;
+    assert_eq!(201, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn allow_policy_should_not_restrict_unverified_users() {
+    // Arrange
+    let app =
+        spawn_app_with(|c| c.app.unverified_email_policy = UnverifiedEmailPolicy::Allow).await;
+    let token = register(&app).await;
+
+    // Act
+    let response = post_article_with_body(app.address(), ARTICLE, &token).await;
+
+    // Assert
+    assert_eq!(201, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn account_only_policy_should_refuse_reads() {
+    // Arrange
+    let app =
+        spawn_app_with(|c| c.app.unverified_email_policy = UnverifiedEmailPolicy::AccountOnly)
+            .await;
+    let token = register(&app).await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/articles/feed", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(403, response.status().as_u16());
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/api/user", app.address()))
+        .header("Authorization", format!("Token {token}"))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn email_change_should_be_applied_once_verified() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+    post_verify_email(app.address(), &token_in_email(&app.sent_emails()[0])).await;
+
+    // Act
+    let response = put_update_with_body(
+        app.address(),
+        r#"{"user":{"email":"new@jake.com"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+
+    // The current email is kept until the new one is verified
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("jake@jake.com", body["user"]["email"]);
+    assert_eq!(("jake@jake.com".to_string(), true), saved_email(&app).await);
+
+    let email = app.sent_emails().pop().unwrap();
+    assert!(email.contains("To: new@jake.com"));
+
+    let response = post_verify_email(app.address(), &token_in_email(&email)).await;
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(("new@jake.com".to_string(), true), saved_email(&app).await);
+}
+
+#[actix_rt::test]
+async fn email_change_to_a_taken_email_should_return_422() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+    app.insert_user("john").await;
+
+    // Act
+    let response = put_update_with_body(
+        app.address(),
+        r#"{"user":{"email":"john@john.com"}}"#,
+        &token,
+    )
+    .await;
+
+    // Assert
+    assert_eq!(422, response.status().as_u16());
+
+    let body: Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!("has already been taken", body["errors"]["email"][0]);
+}
+
+#[actix_rt::test]
+async fn resend_should_email_a_new_token() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = register(&app).await;
+    let first_token = token_in_email(&app.sent_emails()[0]);
+
+    // Act
+    let response = post_resend(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(202, response.status().as_u16());
+
+    let emails = app.sent_emails();
+    assert_eq!(2, emails.len());
+
+    // Only the last token is valid
+    let response = post_verify_email(app.address(), &first_token).await;
+    assert_eq!(401, response.status().as_u16());
+    let response = post_verify_email(app.address(), &token_in_email(&emails[1])).await;
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[actix_rt::test]
+async fn resend_when_verified_should_return_409() {
+    // Arrange
+    let app = spawn_app().await;
+    let token = app.insert_user("jake").await;
+
+    // Act
+    let response = post_resend(app.address(), &token).await;
+
+    // Assert
+    assert_eq!(409, response.status().as_u16());
+}